@@ -0,0 +1,225 @@
+use std::{
+    str::FromStr,
+    sync::{Arc, PoisonError, RwLock},
+    time::{Duration, Instant},
+};
+
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    nonblocking::rpc_client::RpcClient,
+    rpc_request::RpcRequest,
+    rpc_response::{Response, RpcBlockhash},
+};
+use solana_sdk::hash::Hash;
+use tokio::{
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
+};
+
+use crate::adapters::rpc_retry::with_rpc_timeout;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CachedBlockhash {
+    pub blockhash: Hash,
+    pub slot: u64,
+    pub last_valid_block_height: u64,
+    pub fetched_at: Instant,
+}
+
+/// Latest RPC blockhash kept warm off the snipe path.
+///
+/// Entries older than `max_age` are never served, so a stalled refresher
+/// degrades to live RPC fetches instead of signing with an expired hash.
+#[derive(Debug)]
+pub struct BlockhashCache {
+    max_age: Duration,
+    latest: RwLock<Option<CachedBlockhash>>,
+}
+
+impl BlockhashCache {
+    pub const fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            latest: RwLock::new(None),
+        }
+    }
+
+    #[inline(always)]
+    pub fn latest(&self) -> Option<CachedBlockhash> {
+        self.latest_at(Instant::now())
+    }
+
+    fn latest_at(&self, now: Instant) -> Option<CachedBlockhash> {
+        let latest = *self.latest.read().unwrap_or_else(PoisonError::into_inner);
+        latest.filter(|entry| now.saturating_duration_since(entry.fetched_at) <= self.max_age)
+    }
+
+    pub fn store(&self, entry: CachedBlockhash) {
+        *self.latest.write().unwrap_or_else(PoisonError::into_inner) = Some(entry);
+    }
+
+    pub async fn refresh(&self, rpc: &RpcClient) -> Result<CachedBlockhash, ClientError> {
+        let response = rpc
+            .send::<Response<RpcBlockhash>>(
+                RpcRequest::GetLatestBlockhash,
+                serde_json::json!([rpc.commitment()]),
+            )
+            .await?;
+        let blockhash = Hash::from_str(&response.value.blockhash).map_err(|_source| {
+            ClientError::from(ClientErrorKind::Custom(format!(
+                "RPC returned invalid blockhash '{}'",
+                response.value.blockhash
+            )))
+        })?;
+
+        let entry = CachedBlockhash {
+            blockhash,
+            slot: response.context.slot,
+            last_valid_block_height: response.value.last_valid_block_height,
+            fetched_at: Instant::now(),
+        };
+        self.store(entry);
+        Ok(entry)
+    }
+
+    /// Refreshes every `period`, giving up on a fetch after `call_timeout` so a hung RPC
+    /// cannot stall the loop.
+    #[must_use]
+    pub fn spawn_refresher(
+        self: Arc<Self>,
+        rpc: Arc<RpcClient>,
+        period: Duration,
+        call_timeout: Option<Duration>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if let Err(error) = with_rpc_timeout(call_timeout, self.refresh(&rpc)).await {
+                    log::warn!("Blockhash cache > refresh failed: {}", error);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_sdk::hash::Hash;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::{BlockhashCache, CachedBlockhash};
+
+    #[test]
+    fn serves_fresh_entries_only() {
+        let cache = BlockhashCache::new(Duration::from_secs(5));
+        assert!(cache.latest().is_none());
+
+        let fetched_at = Instant::now();
+        cache.store(entry(10, fetched_at));
+
+        assert_eq!(
+            cache.latest_at(fetched_at).map(|entry| entry.slot),
+            Some(10)
+        );
+        let stale_at = fetched_at.checked_add(Duration::from_secs(6));
+        assert!(stale_at.is_some());
+        if let Some(stale_at) = stale_at {
+            assert!(cache.latest_at(stale_at).is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn refresher_times_out_a_hung_fetch_and_retries() {
+        let listener = TcpListener::bind("127.0.0.1:0").await;
+        assert!(listener.is_ok());
+        let Ok(listener) = listener else {
+            return;
+        };
+        let local_addr = listener.local_addr();
+        assert!(local_addr.is_ok());
+        let Ok(local_addr) = local_addr else {
+            return;
+        };
+        let expected = Hash::new_from_array([3_u8; 32]);
+        let server = tokio::spawn(async move {
+            // The first request is read but never answered.
+            let mut hung = Vec::new();
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let mut buffer = [0_u8; 4_096];
+                if stream.read(&mut buffer).await.is_err() {
+                    return;
+                }
+                if hung.is_empty() {
+                    hung.push(stream);
+                    continue;
+                }
+
+                let body = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "result": {
+                        "context": { "slot": 77_u64 },
+                        "value": {
+                            "blockhash": expected.to_string(),
+                            "lastValidBlockHeight": 99_u64
+                        }
+                    },
+                    "id": 1_u64
+                })
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _written = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        let cache = Arc::new(BlockhashCache::new(Duration::from_secs(10)));
+        let refresher = Arc::clone(&cache).spawn_refresher(
+            Arc::new(RpcClient::new(format!("http://{local_addr}"))),
+            Duration::from_millis(10),
+            Some(Duration::from_millis(100)),
+        );
+        let refreshed = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(entry) = cache.latest() {
+                    return entry;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await;
+        refresher.abort();
+        server.abort();
+
+        assert!(refreshed.is_ok());
+        if let Ok(entry) = refreshed {
+            assert_eq!(entry.blockhash, expected);
+            assert_eq!(entry.slot, 77);
+        }
+    }
+
+    fn entry(slot: u64, fetched_at: Instant) -> CachedBlockhash {
+        CachedBlockhash {
+            blockhash: Hash::new_unique(),
+            slot,
+            last_valid_block_height: 0,
+            fetched_at,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::{
+    io,
+    net::SocketAddr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+
+const MAX_REQUEST_BYTES: usize = 1_024;
+const REQUEST_READ_TIMEOUT: Duration = Duration::from_secs(5);
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Default)]
+pub struct HealthState {
+    rulebook_loaded: AtomicBool,
+    ingress_ready: AtomicBool,
+}
+
+impl HealthState {
+    pub fn mark_rulebook_loaded(&self) {
+        self.rulebook_loaded.store(true, Ordering::Release);
+    }
+
+    /// Called for every ingress event, so the flag is only written on the first one.
+    pub fn mark_ingress_ready(&self) {
+        if !self.ingress_ready.load(Ordering::Acquire) {
+            self.ingress_ready.store(true, Ordering::Release);
+        }
+    }
+
+    pub fn mark_ingress_stopped(&self) {
+        self.ingress_ready.store(false, Ordering::Release);
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.rulebook_loaded.load(Ordering::Acquire) && self.ingress_ready.load(Ordering::Acquire)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum HealthRoute {
+    Liveness,
+    Readiness,
+    NotFound,
+}
+
+pub async fn spawn_health_server(
+    listen_addr: SocketAddr,
+    state: Arc<HealthState>,
+    liveness: fn() -> bool,
+) -> Result<(SocketAddr, JoinHandle<()>), io::Error> {
+    let listener = TcpListener::bind(listen_addr).await?;
+    let local_addr = listener.local_addr()?;
+
+    let server = tokio::spawn(async move {
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _peer_addr)) => stream,
+                Err(error) => {
+                    log::warn!("Health endpoint > accept failed: {}", error);
+                    tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                    continue;
+                }
+            };
+
+            let connection_state = Arc::clone(&state);
+            tokio::spawn(async move {
+                if let Err(error) = serve_connection(stream, &connection_state, liveness).await {
+                    log::debug!("Health endpoint > connection failed: {}", error);
+                }
+            });
+        }
+    });
+
+    Ok((local_addr, server))
+}
+
+async fn serve_connection(
+    mut stream: TcpStream,
+    state: &HealthState,
+    liveness: fn() -> bool,
+) -> Result<(), io::Error> {
+    let mut buffer = [0_u8; MAX_REQUEST_BYTES];
+    let read_len = tokio::time::timeout(REQUEST_READ_TIMEOUT, stream.read(&mut buffer))
+        .await
+        .map_err(|_elapsed| io::Error::from(io::ErrorKind::TimedOut))??;
+    let request = String::from_utf8_lossy(buffer.get(..read_len).unwrap_or_default());
+
+    let (status, body) = health_response(route_request(&request), state, liveness());
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+fn route_request(request: &str) -> HealthRoute {
+    let path = request
+        .lines()
+        .next()
+        .and_then(|request_line| request_line.split_whitespace().nth(1))
+        .unwrap_or_default();
+
+    match path {
+        "/healthz" => HealthRoute::Liveness,
+        "/readyz" => HealthRoute::Readiness,
+        _ => HealthRoute::NotFound,
+    }
+}
+
+fn health_response(
+    route: HealthRoute,
+    state: &HealthState,
+    live: bool,
+) -> (&'static str, &'static str) {
+    match route {
+        HealthRoute::Liveness if live => ("200 OK", "ok"),
+        HealthRoute::Liveness => ("503 Service Unavailable", "logger stopped"),
+        HealthRoute::Readiness if live && state.is_ready() => ("200 OK", "ready"),
+        HealthRoute::Readiness => ("503 Service Unavailable", "not ready"),
+        HealthRoute::NotFound => ("404 Not Found", "not found"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HealthRoute, HealthState, health_response, route_request, spawn_health_server};
+    use std::{net::SocketAddr, sync::Arc};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    };
+
+    #[test]
+    fn routes_known_probe_paths() {
+        assert_eq!(
+            route_request("GET /healthz HTTP/1.1\r\n\r\n"),
+            HealthRoute::Liveness
+        );
+        assert_eq!(
+            route_request("GET /readyz HTTP/1.1\r\n\r\n"),
+            HealthRoute::Readiness
+        );
+        assert_eq!(
+            route_request("GET /metrics HTTP/1.1\r\n\r\n"),
+            HealthRoute::NotFound
+        );
+        assert_eq!(route_request(""), HealthRoute::NotFound);
+    }
+
+    #[test]
+    fn readiness_requires_rulebook_and_ingress() {
+        let state = HealthState::default();
+        assert_eq!(
+            health_response(HealthRoute::Readiness, &state, true).0,
+            "503 Service Unavailable"
+        );
+
+        state.mark_rulebook_loaded();
+        assert!(!state.is_ready());
+
+        state.mark_ingress_ready();
+        assert_eq!(
+            health_response(HealthRoute::Readiness, &state, true).0,
+            "200 OK"
+        );
+        state.mark_ingress_stopped();
+        assert!(!state.is_ready());
+        state.mark_ingress_ready();
+        assert_eq!(
+            health_response(HealthRoute::Liveness, &state, false).0,
+            "503 Service Unavailable"
+        );
+    }
+
+    #[tokio::test]
+    async fn serves_probe_responses_over_tcp() {
+        let state = Arc::new(HealthState::default());
+        let server = spawn_health_server(
+            SocketAddr::from(([127, 0, 0, 1], 0)),
+            Arc::clone(&state),
+            || true,
+        )
+        .await;
+        assert!(server.is_ok());
+
+        if let Ok((local_addr, server)) = server {
+            let stream = TcpStream::connect(local_addr).await;
+            assert!(stream.is_ok());
+            if let Ok(mut stream) = stream {
+                let write_result = stream.write_all(b"GET /healthz HTTP/1.1\r\n\r\n").await;
+                assert!(write_result.is_ok());
+
+                let mut response = String::new();
+                let read_result = stream.read_to_string(&mut response).await;
+                assert!(read_result.is_ok());
+                assert!(response.starts_with("HTTP/1.1 200 OK"));
+            }
+            server.abort();
+        }
+    }
+}
@@ -1,2 +1,9 @@
+pub mod blockhash_cache;
+pub mod health_http;
 pub mod raydium;
+pub mod read_rpc;
+pub mod rpc_retry;
+#[cfg(any(test, feature = "testing"))]
+pub mod scripted_ingress;
 pub mod toml_rules;
+pub mod tx_submitters;
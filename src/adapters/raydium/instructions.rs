@@ -1,10 +1,10 @@
 use std::{
     collections::HashMap,
     sync::{Arc, OnceLock},
+    time::Duration,
 };
 
 use solana_address_lookup_table_interface::state::AddressLookupTable;
-use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_message::{
     AccountKeys, VersionedMessage, v0::LoadedAddresses, v0::MessageAddressTableLookup,
 };
@@ -14,16 +14,15 @@ use solana_sdk::{
 };
 use tokio::sync::RwLock;
 
+use crate::{
+    adapters::{read_rpc::ReadRpcPool, rpc_retry::with_rpc_timeout},
+    domain::events::RaydiumCandidateKind,
+};
+
 use super::constants::{
     RAYDIUM_V4_PROGRAM_ID, STANDARD_AMM_INITIALIZE, STANDARD_AMM_INITIALIZE_WITH_PERMISSION,
 };
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum RaydiumStructuredCandidateKind {
-    Cpmm,
-    OpenBook,
-}
-
 pub const RAYDIUM_V4_INITIALIZE_TAG: u8 = 0;
 pub const RAYDIUM_V4_INITIALIZE2_TAG: u8 = 1;
 pub const RAYDIUM_V4_SWAP_BASE_IN_TAG: u8 = 9;
@@ -31,9 +30,28 @@ pub const RAYDIUM_V4_SWAP_BASE_OUT_TAG: u8 = 11;
 
 type LookupTableCache = RwLock<HashMap<Pubkey, Arc<[Pubkey]>>>;
 
+/// Where a CPMM creation's deployer address was read from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeployerSource {
+    /// The initialize instruction's creator account, which signed the transaction.
+    CreatorAccount,
+    /// The transaction's fee payer, used when the creator account did not sign.
+    FeePayer,
+}
+
+impl DeployerSource {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::CreatorAccount => "creator account",
+            Self::FeePayer => "fee payer",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct ParsedCpmmCreation {
     pub deployer_address: Pubkey,
+    pub deployer_source: DeployerSource,
     pub amm_config: Pubkey,
     pub authority: Pubkey,
     pub pool_state: Pubkey,
@@ -73,6 +91,15 @@ impl ParsedCpmmCreation {
         self.mint_a != wsol_pubkey()
     }
 
+    #[inline(always)]
+    pub fn init_sol_amount(self) -> u64 {
+        if self.token_is_vault_zero() {
+            self.init_amount_1
+        } else {
+            self.init_amount_0
+        }
+    }
+
     #[inline(always)]
     pub fn input_vault(self) -> Pubkey {
         if self.token_is_vault_zero() {
@@ -94,6 +121,8 @@ impl ParsedCpmmCreation {
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct ParsedOpenbookCreation {
+    /// Token program the pool was initialized with; owns the token mint's accounts.
+    pub token_program: Pubkey,
     pub id: Pubkey,
     pub authority: Pubkey,
     pub open_orders: Pubkey,
@@ -124,15 +153,26 @@ impl ParsedOpenbookCreation {
     pub fn token_is_coin_mint(self) -> bool {
         self.mint_a != wsol_pubkey()
     }
+
+    #[inline(always)]
+    pub fn init_sol_amount(self) -> u64 {
+        if self.token_is_coin_mint() {
+            self.init_pc_amount
+        } else {
+            self.init_coin_amount
+        }
+    }
 }
 
+/// The strategy for a transaction's first Raydium pool-creation instruction. The SOF ingress
+/// plugin, the replay benchmark and `--classify` all classify through here.
 #[inline(always)]
 pub fn classify_raydium_creation_instructions(
     static_account_keys: &[Pubkey],
     instructions: &[CompiledInstruction],
     cpmm_program: Pubkey,
     openbook_program: Pubkey,
-) -> Option<RaydiumStructuredCandidateKind> {
+) -> Option<RaydiumCandidateKind> {
     for instruction in instructions {
         let Some(program_id) = static_account_keys.get(usize::from(instruction.program_id_index))
         else {
@@ -140,17 +180,52 @@ pub fn classify_raydium_creation_instructions(
         };
 
         if *program_id == cpmm_program && is_cpmm_creation_instruction(&instruction.data) {
-            return Some(RaydiumStructuredCandidateKind::Cpmm);
+            return Some(RaydiumCandidateKind::Cpmm);
         }
 
         if *program_id == openbook_program && is_openbook_creation_instruction(&instruction.data) {
-            return Some(RaydiumStructuredCandidateKind::OpenBook);
+            return Some(RaydiumCandidateKind::OpenBook);
         }
     }
 
     None
 }
 
+/// [`classify_raydium_creation_instructions`] over a whole message. Only static account keys
+/// are checked, so a program invoked through a lookup table is not seen.
+#[inline(always)]
+pub fn classify_raydium_creation_message(
+    message: &VersionedMessage,
+    cpmm_program: Pubkey,
+    openbook_program: Pubkey,
+) -> Option<RaydiumCandidateKind> {
+    classify_raydium_creation_instructions(
+        message.static_account_keys(),
+        message.instructions(),
+        cpmm_program,
+        openbook_program,
+    )
+}
+
+/// Whether a message carries a `kind` pool-creation instruction for `program`, wherever it
+/// sits among the message's instructions. Only static account keys are checked.
+#[inline(always)]
+pub fn has_raydium_creation_instruction(
+    message: &VersionedMessage,
+    kind: RaydiumCandidateKind,
+    program: Pubkey,
+) -> bool {
+    let is_creation = match kind {
+        RaydiumCandidateKind::Cpmm => is_cpmm_creation_instruction,
+        RaydiumCandidateKind::OpenBook => is_openbook_creation_instruction,
+    };
+    let account_keys = message.static_account_keys();
+    message.instructions().iter().any(|instruction| {
+        account_keys.get(usize::from(instruction.program_id_index)) == Some(&program)
+            && is_creation(&instruction.data)
+    })
+}
+
 #[inline(always)]
 pub fn is_cpmm_creation_instruction(data: &[u8]) -> bool {
     let Some(discriminator) = data.get(..STANDARD_AMM_INITIALIZE.len()) else {
@@ -171,11 +246,12 @@ pub const fn raydium_v4_program_pubkey() -> Pubkey {
 }
 
 pub async fn parse_cpmm_creation_transaction(
-    rpc: &RpcClient,
+    rpcs: &ReadRpcPool,
+    call_timeout: Option<Duration>,
     tx: &VersionedTransaction,
     cpmm_program: Pubkey,
 ) -> Option<ParsedCpmmCreation> {
-    let resolved_keys = resolve_account_keys(rpc, tx).await?;
+    let resolved_keys = resolve_account_keys(rpcs, call_timeout, tx).await?;
 
     for instruction in tx.message.instructions() {
         let program_id = resolved_keys.get(usize::from(instruction.program_id_index))?;
@@ -186,9 +262,12 @@ pub async fn parse_cpmm_creation_transaction(
         let accounts = resolve_instruction_accounts(&resolved_keys, instruction)?;
         let (init_amount_0, init_amount_1, open_time) =
             parse_cpmm_creation_data(&instruction.data)?;
+        let (deployer_address, deployer_source) =
+            cpmm_deployer(&tx.message, &resolved_keys, instruction)?;
 
         return Some(ParsedCpmmCreation {
-            deployer_address: *accounts.first()?,
+            deployer_address,
+            deployer_source,
             amm_config: *accounts.get(1)?,
             authority: *accounts.get(2)?,
             pool_state: *accounts.get(3)?,
@@ -208,12 +287,34 @@ pub async fn parse_cpmm_creation_transaction(
     None
 }
 
+/// The creator account when it signed the transaction; a creation composed by another
+/// program or relayer may pass an unsigned creator, so fall back to the fee payer.
+fn cpmm_deployer(
+    message: &VersionedMessage,
+    resolved_keys: &[Pubkey],
+    instruction: &CompiledInstruction,
+) -> Option<(Pubkey, DeployerSource)> {
+    let creator_index = usize::from(*instruction.accounts.first()?);
+    if message.is_signer(creator_index) {
+        return Some((
+            *resolved_keys.get(creator_index)?,
+            DeployerSource::CreatorAccount,
+        ));
+    }
+
+    Some((
+        *message.static_account_keys().first()?,
+        DeployerSource::FeePayer,
+    ))
+}
+
 pub async fn parse_openbook_creation_transaction(
-    rpc: &RpcClient,
+    rpcs: &ReadRpcPool,
+    call_timeout: Option<Duration>,
     tx: &VersionedTransaction,
     openbook_program: Pubkey,
 ) -> Option<ParsedOpenbookCreation> {
-    let resolved_keys = resolve_account_keys(rpc, tx).await?;
+    let resolved_keys = resolve_account_keys(rpcs, call_timeout, tx).await?;
 
     for instruction in tx.message.instructions() {
         let program_id = resolved_keys.get(usize::from(instruction.program_id_index))?;
@@ -226,6 +327,7 @@ pub async fn parse_openbook_creation_transaction(
             parse_openbook_creation_data(&instruction.data)?;
 
         return Some(ParsedOpenbookCreation {
+            token_program: *accounts.first()?,
             id: *accounts.get(4)?,
             authority: *accounts.get(5)?,
             open_orders: *accounts.get(6)?,
@@ -246,20 +348,28 @@ pub async fn parse_openbook_creation_transaction(
     None
 }
 
-async fn resolve_account_keys(rpc: &RpcClient, tx: &VersionedTransaction) -> Option<Vec<Pubkey>> {
+async fn resolve_account_keys(
+    rpcs: &ReadRpcPool,
+    call_timeout: Option<Duration>,
+    tx: &VersionedTransaction,
+) -> Option<Vec<Pubkey>> {
     match &tx.message {
         VersionedMessage::Legacy(message) => Some(message.account_keys.clone()),
         VersionedMessage::V0(message) => {
             let loaded_addresses =
-                load_lookup_table_addresses(rpc, &message.address_table_lookups).await?;
+                load_lookup_table_addresses(rpcs, call_timeout, &message.address_table_lookups)
+                    .await?;
             let account_keys = AccountKeys::new(&message.account_keys, Some(&loaded_addresses));
             Some(account_keys.iter().copied().collect())
         }
     }
 }
 
+/// Fetches uncached lookup tables from the active read endpoint and reports the result to
+/// `rpcs`, so repeated failures fail over like the other strategy reads.
 async fn load_lookup_table_addresses(
-    rpc: &RpcClient,
+    rpcs: &ReadRpcPool,
+    call_timeout: Option<Duration>,
     lookups: &[MessageAddressTableLookup],
 ) -> Option<LoadedAddresses> {
     if lookups.is_empty() {
@@ -289,7 +399,19 @@ async fn load_lookup_table_addresses(
     }
 
     if !missing_keys.is_empty() {
-        let accounts = rpc.get_multiple_accounts(&missing_keys).await.ok()?;
+        let (rpc_index, rpc) = rpcs.active();
+        let accounts =
+            match with_rpc_timeout(call_timeout, rpc.get_multiple_accounts(&missing_keys)).await {
+                Ok(accounts) => {
+                    rpcs.record_success(rpc_index);
+                    accounts
+                }
+                Err(error) => {
+                    log::debug!("Error loading address lookup tables: {}", error);
+                    rpcs.record_failure(rpc_index);
+                    return None;
+                }
+            };
         if accounts.len() != missing_keys.len() {
             return None;
         }
@@ -297,8 +419,8 @@ async fn load_lookup_table_addresses(
         let mut cache = lookup_table_cache().write().await;
         for ((position, lookup_key), account) in missing_positions
             .into_iter()
-            .zip(missing_keys.into_iter())
-            .zip(accounts.into_iter())
+            .zip(missing_keys)
+            .zip(accounts)
         {
             let account = account?;
             let table = AddressLookupTable::deserialize(&account.data).ok()?;
@@ -311,7 +433,7 @@ async fn load_lookup_table_addresses(
     let mut writable = Vec::new();
     let mut readonly = Vec::new();
 
-    for (lookup, addresses) in lookups.iter().zip(resolved_tables.into_iter()) {
+    for (lookup, addresses) in lookups.iter().zip(resolved_tables) {
         let addresses = addresses?;
         for &index in &lookup.writable_indexes {
             writable.push(*addresses.get(usize::from(index))?);
@@ -389,17 +511,33 @@ const fn wsol_pubkey() -> Pubkey {
 
 #[cfg(test)]
 mod tests {
-    use solana_sdk::{message::compiled_instruction::CompiledInstruction, pubkey::Pubkey};
+    use std::sync::Arc;
+
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_commitment_config::CommitmentConfig;
+    use solana_message::{Message, VersionedMessage, v0::MessageAddressTableLookup};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        message::compiled_instruction::CompiledInstruction,
+        pubkey::Pubkey,
+        transaction::{Transaction, VersionedTransaction},
+    };
 
     use super::{
-        RAYDIUM_V4_INITIALIZE2_TAG, RAYDIUM_V4_SWAP_BASE_IN_TAG, RaydiumStructuredCandidateKind,
-        classify_raydium_creation_instructions, is_cpmm_creation_instruction,
-        is_openbook_creation_instruction, parse_cpmm_creation_data, parse_openbook_creation_data,
-        raydium_v4_program_pubkey,
+        DeployerSource, RAYDIUM_V4_INITIALIZE2_TAG, RAYDIUM_V4_SWAP_BASE_IN_TAG,
+        classify_raydium_creation_instructions, classify_raydium_creation_message, cpmm_deployer,
+        is_cpmm_creation_instruction, is_openbook_creation_instruction,
+        load_lookup_table_addresses, parse_cpmm_creation_data, parse_openbook_creation_data,
+        parse_openbook_creation_transaction, raydium_v4_program_pubkey,
     };
-    use crate::adapters::raydium::{
-        RAYDIUM_STANDARD_AMM_PROGRAM_ID, STANDARD_AMM_INITIALIZE, STANDARD_AMM_SWAP_BASE_INPUT,
-        STANDARD_AMM_SWAP_BASE_OUTPUT,
+    use crate::{
+        adapters::raydium::{
+            RAYDIUM_STANDARD_AMM_PROGRAM_ID, STANDARD_AMM_INITIALIZE,
+            STANDARD_AMM_INITIALIZE_WITH_PERMISSION, STANDARD_AMM_SWAP_BASE_INPUT,
+            STANDARD_AMM_SWAP_BASE_OUTPUT,
+        },
+        adapters::read_rpc::{READ_RPC_FAILOVER_THRESHOLD, ReadRpcPool},
+        domain::events::RaydiumCandidateKind,
     };
 
     #[test]
@@ -450,21 +588,77 @@ mod tests {
     }
 
     #[test]
-    fn structured_classifier_detects_creation_instructions() {
+    fn structured_classifier_maps_instruction_sets_to_strategies() {
         let cpmm_program = Pubkey::from_str_const(RAYDIUM_STANDARD_AMM_PROGRAM_ID);
         let openbook_program = raydium_v4_program_pubkey();
-        let account_keys = vec![cpmm_program, openbook_program];
-        let openbook_init =
-            CompiledInstruction::new_from_raw_parts(1, vec![RAYDIUM_V4_INITIALIZE2_TAG], vec![]);
+        let unrelated_program = Pubkey::new_unique();
+        let account_keys = vec![cpmm_program, openbook_program, unrelated_program];
+        let call = |program_index: u8, data: &[u8]| {
+            CompiledInstruction::new_from_raw_parts(program_index, data.to_vec(), vec![])
+        };
 
-        assert_eq!(
-            classify_raydium_creation_instructions(
-                &account_keys,
-                &[openbook_init],
-                cpmm_program,
-                openbook_program,
+        for (instructions, expected) in [
+            (
+                vec![call(0, &STANDARD_AMM_INITIALIZE)],
+                Some(RaydiumCandidateKind::Cpmm),
+            ),
+            (
+                vec![call(0, &STANDARD_AMM_INITIALIZE_WITH_PERMISSION)],
+                Some(RaydiumCandidateKind::Cpmm),
+            ),
+            (
+                vec![call(1, &[RAYDIUM_V4_INITIALIZE2_TAG])],
+                Some(RaydiumCandidateKind::OpenBook),
+            ),
+            // Creation data sent to the wrong program, or to an unrelated one, is not a pool.
+            (vec![call(1, &STANDARD_AMM_INITIALIZE)], None),
+            (vec![call(2, &[RAYDIUM_V4_INITIALIZE2_TAG])], None),
+            // The first creation instruction decides the strategy.
+            (
+                vec![
+                    call(0, &STANDARD_AMM_SWAP_BASE_INPUT),
+                    call(1, &[RAYDIUM_V4_INITIALIZE2_TAG]),
+                    call(0, &STANDARD_AMM_INITIALIZE),
+                ],
+                Some(RaydiumCandidateKind::OpenBook),
             ),
-            Some(RaydiumStructuredCandidateKind::OpenBook)
+            (vec![call(9, &STANDARD_AMM_INITIALIZE)], None),
+            (Vec::new(), None),
+        ] {
+            assert_eq!(
+                classify_raydium_creation_instructions(
+                    &account_keys,
+                    &instructions,
+                    cpmm_program,
+                    openbook_program,
+                ),
+                expected,
+                "{instructions:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn message_classifier_matches_the_instruction_classifier() {
+        let cpmm_program = Pubkey::from_str_const(RAYDIUM_STANDARD_AMM_PROGRAM_ID);
+        let openbook_program = raydium_v4_program_pubkey();
+        let payer = Pubkey::new_unique();
+        let message = VersionedMessage::Legacy(Message::new(
+            &[Instruction::new_with_bytes(
+                cpmm_program,
+                &STANDARD_AMM_INITIALIZE,
+                Vec::new(),
+            )],
+            Some(&payer),
+        ));
+
+        assert_eq!(
+            classify_raydium_creation_message(&message, cpmm_program, openbook_program),
+            Some(RaydiumCandidateKind::Cpmm)
+        );
+        assert_eq!(
+            classify_raydium_creation_message(&message, openbook_program, cpmm_program),
+            None
         );
     }
 
@@ -487,4 +681,83 @@ mod tests {
 
         assert_eq!(parse_openbook_creation_data(&data), Some((55, 66, 44)));
     }
+
+    #[test]
+    fn cpmm_deployer_prefers_a_signing_creator_and_falls_back_to_the_fee_payer() {
+        let cpmm_program = Pubkey::from_str_const(RAYDIUM_STANDARD_AMM_PROGRAM_ID);
+        let payer = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        for (creator_signs, expected) in [
+            (true, (creator, DeployerSource::CreatorAccount)),
+            (false, (payer, DeployerSource::FeePayer)),
+        ] {
+            let initialize = Instruction::new_with_bytes(
+                cpmm_program,
+                &STANDARD_AMM_INITIALIZE,
+                vec![AccountMeta::new(creator, creator_signs)],
+            );
+            let message = VersionedMessage::Legacy(Message::new(&[initialize], Some(&payer)));
+            let resolved_keys = message.static_account_keys().to_vec();
+            let compiled = message.instructions().first();
+            assert!(compiled.is_some());
+            if let Some(compiled) = compiled {
+                assert_eq!(
+                    cpmm_deployer(&message, &resolved_keys, compiled),
+                    Some(expected)
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn openbook_creation_keeps_a_token_2022_program() {
+        let openbook_program = raydium_v4_program_pubkey();
+        let token_2022_program =
+            Pubkey::from_str_const("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+        let payer = Pubkey::new_unique();
+        let mut accounts = vec![AccountMeta::new_readonly(token_2022_program, false)];
+        accounts.extend((1..18).map(|_| AccountMeta::new(Pubkey::new_unique(), false)));
+        let mut data = vec![RAYDIUM_V4_INITIALIZE2_TAG, 7];
+        data.extend_from_slice(&[0_u8; 24]);
+        let initialize = Instruction::new_with_bytes(openbook_program, &data, accounts);
+        let transaction = VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+            &[initialize],
+            Some(&payer),
+        )));
+
+        // Legacy messages resolve their keys without touching the RPC.
+        let rpcs = ReadRpcPool::new(Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())));
+        let creation =
+            parse_openbook_creation_transaction(&rpcs, None, &transaction, openbook_program).await;
+
+        assert_eq!(
+            creation.map(|creation| creation.token_program),
+            Some(token_2022_program)
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_lookup_table_loads_fail_over_the_read_endpoint() {
+        let rpcs = ReadRpcPool::new(Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())))
+            .with_fallback_urls(
+                &["http://127.0.0.1:2".to_owned()],
+                CommitmentConfig::confirmed(),
+            );
+        let lookups = [MessageAddressTableLookup {
+            account_key: Pubkey::new_unique(),
+            writable_indexes: vec![0],
+            readonly_indexes: Vec::new(),
+        }];
+
+        for _ in 0..READ_RPC_FAILOVER_THRESHOLD {
+            assert!(
+                load_lookup_table_addresses(&rpcs, None, &lookups)
+                    .await
+                    .is_none()
+            );
+        }
+
+        assert_eq!(rpcs.active().0, 1);
+    }
 }
@@ -1,27 +1,89 @@
-use std::sync::Arc;
+use std::time::Duration;
 
-use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_commitment_config::CommitmentConfig;
-use solana_sdk::{program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::pubkey::Pubkey;
+use thiserror::Error;
 
-use crate::MAX_RETRIES;
+use crate::{
+    MAX_RETRIES,
+    adapters::{read_rpc::ReadRpcPool, rpc_retry::with_rpc_timeout},
+};
+
+pub const DEFAULT_MARKET_AUTHORITY_MAX_NONCE: u64 = 100;
 
 const MARKET_STATE_LAYOUT_V3_LEN: usize = 388;
-const OWN_ADDRESS_START: usize = 13;
-const BASE_VAULT_START: usize = 117;
-const QUOTE_VAULT_START: usize = 165;
-const EVENT_QUEUE_START: usize = 253;
-const BIDS_START: usize = 285;
-const ASKS_START: usize = 317;
+const MARKET_STATE_LAYOUT_V4_LEN: usize = 1_476;
+
+const MARKET_STATE_V3_OFFSETS: MarketStateOffsets = MarketStateOffsets {
+    own_address: 13,
+    base_vault: 117,
+    quote_vault: 165,
+    event_queue: 253,
+    bids: 285,
+    asks: 317,
+};
+// The permissioned layout appends authorities and padding after the V3 fields.
+const MARKET_STATE_V4_OFFSETS: MarketStateOffsets = MARKET_STATE_V3_OFFSETS;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct MarketStateOffsets {
+    own_address: usize,
+    base_vault: usize,
+    quote_vault: usize,
+    event_queue: usize,
+    bids: usize,
+    asks: usize,
+}
+
+/// On-chain OpenBook/Serum market account layouts, detected by account length.
+///
+/// `V4` is the permissioned market state (serum-dex `MarketStateV2`), which
+/// extends the V3 account with open-orders/prune/consume-events authorities.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MarketStateLayout {
+    V3,
+    V4,
+}
+
+impl MarketStateLayout {
+    pub const fn detect(len: usize) -> Option<Self> {
+        match len {
+            MARKET_STATE_LAYOUT_V3_LEN => Some(Self::V3),
+            MARKET_STATE_LAYOUT_V4_LEN => Some(Self::V4),
+            _ => None,
+        }
+    }
+
+    const fn offsets(self) -> MarketStateOffsets {
+        match self {
+            Self::V3 => MARKET_STATE_V3_OFFSETS,
+            Self::V4 => MARKET_STATE_V4_OFFSETS,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Error, Eq, PartialEq)]
+pub enum MarketDecodeError {
+    #[error("unsupported market state layout ({len} bytes)")]
+    UnsupportedLayout { len: usize },
+    #[error("market state is truncated at offset {offset}")]
+    Truncated { offset: usize },
+}
+
+#[derive(Clone, Copy, Debug, Error, Eq, PartialEq)]
+pub enum AuthorityDerivationError {
+    #[error("no valid market authority nonce below {max_nonce} for market {market_id}")]
+    NoValidNonce { market_id: Pubkey, max_nonce: u64 },
+}
 
 #[derive(Debug, Clone)]
 pub struct Market {
     pub program_id: Pubkey,
-    pub state: MarketStateLayoutV3,
+    pub state: MarketState,
 }
 
 #[derive(Debug, Clone)]
-pub struct MarketStateLayoutV3 {
+pub struct MarketState {
+    pub layout: MarketStateLayout,
     pub own_address: Pubkey,
     pub base_vault: Pubkey,
     pub quote_vault: Pubkey,
@@ -30,76 +92,247 @@ pub struct MarketStateLayoutV3 {
     pub asks: Pubkey,
 }
 
-impl MarketStateLayoutV3 {
-    fn read_pubkey(bytes: &[u8], start: usize) -> Option<Pubkey> {
-        let end = start.checked_add(32)?;
-        let key_bytes: [u8; 32] = bytes.get(start..end)?.try_into().ok()?;
-        Some(Pubkey::new_from_array(key_bytes))
+impl MarketState {
+    fn read_pubkey(bytes: &[u8], start: usize) -> Result<Pubkey, MarketDecodeError> {
+        let truncated = MarketDecodeError::Truncated { offset: start };
+        let end = start.checked_add(32).ok_or(truncated)?;
+        let key_bytes: [u8; 32] = bytes
+            .get(start..end)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(truncated)?;
+        Ok(Pubkey::new_from_array(key_bytes))
     }
 
-    pub fn decode(bytes: &[u8]) -> Option<Self> {
-        if bytes.len() != MARKET_STATE_LAYOUT_V3_LEN {
-            return None;
-        }
+    pub fn decode(bytes: &[u8]) -> Result<Self, MarketDecodeError> {
+        let layout = MarketStateLayout::detect(bytes.len())
+            .ok_or(MarketDecodeError::UnsupportedLayout { len: bytes.len() })?;
+        let offsets = layout.offsets();
 
-        Some(Self {
-            own_address: Self::read_pubkey(bytes, OWN_ADDRESS_START)?,
-            base_vault: Self::read_pubkey(bytes, BASE_VAULT_START)?,
-            quote_vault: Self::read_pubkey(bytes, QUOTE_VAULT_START)?,
-            event_queue: Self::read_pubkey(bytes, EVENT_QUEUE_START)?,
-            bids: Self::read_pubkey(bytes, BIDS_START)?,
-            asks: Self::read_pubkey(bytes, ASKS_START)?,
+        Ok(Self {
+            layout,
+            own_address: Self::read_pubkey(bytes, offsets.own_address)?,
+            base_vault: Self::read_pubkey(bytes, offsets.base_vault)?,
+            quote_vault: Self::read_pubkey(bytes, offsets.quote_vault)?,
+            event_queue: Self::read_pubkey(bytes, offsets.event_queue)?,
+            bids: Self::read_pubkey(bytes, offsets.bids)?,
+            asks: Self::read_pubkey(bytes, offsets.asks)?,
         })
     }
 }
 
-pub async fn get_market_accounts(rpc: &Arc<RpcClient>, market_id: &Pubkey) -> Option<Market> {
+/// Retries failed and timed-out fetches once a second, up to `MAX_RETRIES` times. Repeated
+/// failures move `rpcs` to its next endpoint, which is retried without the wait.
+pub async fn get_market_accounts(
+    rpcs: &ReadRpcPool,
+    call_timeout: Option<Duration>,
+    market_id: &Pubkey,
+) -> Option<Market> {
     let mut attempts = 0_usize;
 
     loop {
-        let market_account_info = rpc
-            .get_account_with_commitment(market_id, CommitmentConfig::confirmed())
-            .await;
+        let (rpc_index, rpc) = rpcs.active();
+        let market_account_info = with_rpc_timeout(
+            call_timeout,
+            rpc.get_account_with_commitment(market_id, rpc.commitment()),
+        )
+        .await;
 
         match market_account_info {
             Ok(response) => {
+                rpcs.record_success(rpc_index);
                 let account = response.value?;
-                let state = MarketStateLayoutV3::decode(&account.data)?;
-                return Some(Market {
-                    program_id: account.owner,
-                    state,
-                });
+                return match MarketState::decode(&account.data) {
+                    Ok(state) => Some(Market {
+                        program_id: account.owner,
+                        state,
+                    }),
+                    Err(error) => {
+                        log::error!("OpenBook market {} > {}", market_id, error);
+                        None
+                    }
+                };
             }
             Err(error) => {
                 log::debug!("Error getting market accounts: {}", error);
                 if attempts >= MAX_RETRIES {
                     return None;
                 }
+                attempts = attempts.saturating_add(1);
+                if rpcs.record_failure(rpc_index) {
+                    continue;
+                }
             }
         }
 
         tokio::time::sleep(tokio::time::Duration::from_millis(1_000)).await;
-        attempts = attempts.saturating_add(1);
     }
 }
 
 pub fn get_associated_authority(
     program_id: &Pubkey,
     market_id: &Pubkey,
-) -> Result<(Pubkey, u64), ProgramError> {
+    max_nonce: u64,
+) -> Result<(Pubkey, u64), AuthorityDerivationError> {
     let market_bytes = market_id.to_bytes();
-    let mut nonce = 0_u64;
 
-    while nonce < 100_u64 {
-        let nonce_bytes = nonce.to_le_bytes();
-        let seeds_with_nonce: [&[u8]; 3] = [&market_bytes, &nonce_bytes, &[0_u8; 7]];
+    // Vault signer seeds are the market address plus the nonce as a little-endian u64.
+    (0..max_nonce)
+        .find_map(|nonce| {
+            let nonce_bytes = nonce.to_le_bytes();
+            let seeds: [&[u8]; 2] = [&market_bytes, &nonce_bytes];
+            Pubkey::create_program_address(&seeds, program_id)
+                .ok()
+                .map(|pubkey| (pubkey, nonce))
+        })
+        .ok_or(AuthorityDerivationError::NoValidNonce {
+            market_id: *market_id,
+            max_nonce,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_commitment_config::CommitmentConfig;
+    use solana_sdk::pubkey::Pubkey;
+    use tokio::{
+        io::{AsyncReadExt as _, AsyncWriteExt as _},
+        net::TcpListener,
+    };
+
+    use super::{
+        AuthorityDerivationError, DEFAULT_MARKET_AUTHORITY_MAX_NONCE, MARKET_STATE_LAYOUT_V3_LEN,
+        MARKET_STATE_LAYOUT_V4_LEN, MARKET_STATE_V3_OFFSETS, MarketDecodeError, MarketState,
+        MarketStateLayout, get_associated_authority, get_market_accounts,
+    };
+    use crate::adapters::read_rpc::ReadRpcPool;
+
+    /// Answers one `getAccountInfo` with a zeroed V3 market owned by `owner`.
+    async fn spawn_mock_market_rpc(
+        owner: Pubkey,
+    ) -> Result<(String, tokio::task::JoinHandle<()>), String> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|error| format!("failed to bind test rpc listener: {error}"))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|error| format!("failed to read test rpc listener addr: {error}"))?;
+
+        let server = tokio::spawn(async move {
+            let accept_result = listener.accept().await;
+            assert!(accept_result.is_ok());
+            let Ok((mut stream, _)) = accept_result else {
+                return;
+            };
+
+            let mut buffer = [0_u8; 4_096];
+            let read_result = stream.read(&mut buffer).await;
+            assert!(read_result.is_ok());
+
+            let body = serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "context": { "slot": 321_u64 },
+                    "value": {
+                        "data": [BASE64_STANDARD.encode([0_u8; MARKET_STATE_LAYOUT_V3_LEN]), "base64"],
+                        "executable": false,
+                        "lamports": 1_000_000_u64,
+                        "owner": owner.to_string(),
+                        "rentEpoch": 0_u64,
+                        "space": MARKET_STATE_LAYOUT_V3_LEN
+                    }
+                },
+                "id": 1_u64
+            })
+            .to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+
+            let write_result = stream.write_all(response.as_bytes()).await;
+            assert!(write_result.is_ok());
+        });
+
+        Ok((format!("http://{local_addr}"), server))
+    }
+
+    #[tokio::test]
+    async fn fails_over_to_a_fallback_rpc_after_repeated_errors() {
+        let owner = Pubkey::new_unique();
+        let server = spawn_mock_market_rpc(owner).await;
+        assert!(server.is_ok());
+        let Ok((fallback_url, server)) = server else {
+            return;
+        };
+        let rpcs = ReadRpcPool::new(Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())))
+            .with_fallback_urls(&[fallback_url], CommitmentConfig::confirmed());
+
+        let market = get_market_accounts(&rpcs, None, &Pubkey::new_unique()).await;
+
+        assert_eq!(market.map(|market| market.program_id), Some(owner));
+        assert_eq!(rpcs.active().0, 1);
+        assert!(server.await.is_ok());
+    }
+
+    #[test]
+    fn decodes_v3_and_v4_market_states() {
+        let bids = Pubkey::new_unique();
+        for (len, layout) in [
+            (MARKET_STATE_LAYOUT_V3_LEN, MarketStateLayout::V3),
+            (MARKET_STATE_LAYOUT_V4_LEN, MarketStateLayout::V4),
+        ] {
+            let mut bytes = vec![0_u8; len];
+            let written = bytes
+                .get_mut(MARKET_STATE_V3_OFFSETS.bids..MARKET_STATE_V3_OFFSETS.asks)
+                .map(|slot| slot.copy_from_slice(&bids.to_bytes()));
+            assert!(written.is_some());
 
-        if let Some((pubkey, _)) = Pubkey::try_find_program_address(&seeds_with_nonce, program_id) {
-            return Ok((pubkey, nonce));
+            let decoded = MarketState::decode(&bytes);
+            assert!(decoded.is_ok());
+            if let Ok(decoded) = decoded {
+                assert_eq!(decoded.layout, layout);
+                assert_eq!(decoded.bids, bids);
+            }
         }
+    }
 
-        nonce = nonce.saturating_add(1);
+    #[test]
+    fn rejects_unknown_layout_length() {
+        assert_eq!(
+            MarketState::decode(&[0_u8; 400]).err(),
+            Some(MarketDecodeError::UnsupportedLayout { len: 400 })
+        );
     }
 
-    Err(ProgramError::Custom(1))
+    #[test]
+    fn derives_market_authority_as_vault_signer() {
+        let program_id = Pubkey::new_unique();
+        let market_id = Pubkey::new_unique();
+
+        let derived =
+            get_associated_authority(&program_id, &market_id, DEFAULT_MARKET_AUTHORITY_MAX_NONCE);
+        assert!(derived.is_ok());
+        if let Ok((authority, nonce)) = derived {
+            let market_bytes = market_id.to_bytes();
+            let nonce_bytes = nonce.to_le_bytes();
+            let seeds: [&[u8]; 2] = [&market_bytes, &nonce_bytes];
+            assert_eq!(
+                Pubkey::create_program_address(&seeds, &program_id).ok(),
+                Some(authority)
+            );
+        }
+
+        assert_eq!(
+            get_associated_authority(&program_id, &market_id, 0),
+            Err(AuthorityDerivationError::NoValidNonce {
+                market_id,
+                max_nonce: 0,
+            })
+        );
+    }
 }
@@ -9,12 +9,15 @@ pub use constants::{
     STANDARD_AMM_SWAP_BASE_OUTPUT, SwapInstructionBaseIn, TOKEN_PROGRAM_ID, WSOL_ADDRESS,
 };
 pub use instructions::{
-    ParsedCpmmCreation, ParsedOpenbookCreation, RAYDIUM_V4_INITIALIZE_TAG,
+    DeployerSource, ParsedCpmmCreation, ParsedOpenbookCreation, RAYDIUM_V4_INITIALIZE_TAG,
     RAYDIUM_V4_INITIALIZE2_TAG, RAYDIUM_V4_SWAP_BASE_IN_TAG, RAYDIUM_V4_SWAP_BASE_OUT_TAG,
-    RaydiumStructuredCandidateKind, classify_raydium_creation_instructions,
-    is_cpmm_creation_instruction, is_openbook_creation_instruction,
-    parse_cpmm_creation_transaction, parse_openbook_creation_transaction,
-    raydium_v4_program_pubkey,
+    classify_raydium_creation_instructions, classify_raydium_creation_message,
+    has_raydium_creation_instruction, is_cpmm_creation_instruction,
+    is_openbook_creation_instruction, parse_cpmm_creation_transaction,
+    parse_openbook_creation_transaction, raydium_v4_program_pubkey,
+};
+pub use market::{
+    AuthorityDerivationError, DEFAULT_MARKET_AUTHORITY_MAX_NONCE, get_associated_authority,
+    get_market_accounts,
 };
-pub use market::{get_associated_authority, get_market_accounts};
 pub use pool::pool_open_time;
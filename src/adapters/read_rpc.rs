@@ -0,0 +1,143 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_commitment_config::CommitmentConfig;
+
+/// Consecutive failed reads against the active endpoint before moving to the next one.
+pub const READ_RPC_FAILOVER_THRESHOLD: usize = 2;
+
+/// Read endpoints for the strategy fetch paths: `runtime.rpc_url` first, then each
+/// `runtime.fallback_rpc_urls` entry. Retry loops report their results so a degraded
+/// endpoint hands over to the next one; with a single endpoint it never rotates.
+pub struct ReadRpcPool {
+    primary: Arc<RpcClient>,
+    fallbacks: Vec<Arc<RpcClient>>,
+    active: AtomicUsize,
+    failures: AtomicUsize,
+}
+
+impl ReadRpcPool {
+    pub const fn new(primary: Arc<RpcClient>) -> Self {
+        Self {
+            primary,
+            fallbacks: Vec::new(),
+            active: AtomicUsize::new(0),
+            failures: AtomicUsize::new(0),
+        }
+    }
+
+    #[must_use]
+    pub fn with_fallback_urls(mut self, urls: &[String], commitment: CommitmentConfig) -> Self {
+        self.fallbacks.extend(
+            urls.iter()
+                .map(|url| Arc::new(RpcClient::new_with_commitment(url.clone(), commitment))),
+        );
+        self
+    }
+
+    pub const fn endpoint_count(&self) -> usize {
+        self.fallbacks.len().saturating_add(1)
+    }
+
+    /// The active client with its index, to hand back to `record_success`/`record_failure`.
+    pub fn active(&self) -> (usize, &Arc<RpcClient>) {
+        let index = self.active.load(Ordering::Acquire);
+        index
+            .checked_sub(1)
+            .and_then(|fallback| self.fallbacks.get(fallback))
+            .map_or((0, &self.primary), |client| (index, client))
+    }
+
+    pub fn client(&self) -> &Arc<RpcClient> {
+        self.active().1
+    }
+
+    pub fn record_success(&self, index: usize) {
+        if self.active.load(Ordering::Acquire) == index {
+            self.failures.store(0, Ordering::Release);
+        }
+    }
+
+    /// Counts a failed read against endpoint `index`. Returns `true` when a different endpoint
+    /// is now active, so the caller can retry straight away instead of waiting out its backoff.
+    pub fn record_failure(&self, index: usize) -> bool {
+        if self.fallbacks.is_empty() {
+            return false;
+        }
+        if self.active.load(Ordering::Acquire) != index {
+            return true;
+        }
+
+        let failures = self
+            .failures
+            .fetch_add(1, Ordering::AcqRel)
+            .saturating_add(1);
+        if failures < READ_RPC_FAILOVER_THRESHOLD {
+            return false;
+        }
+
+        let next = index.saturating_add(1);
+        let next = if next > self.fallbacks.len() { 0 } else { next };
+        if self
+            .active
+            .compare_exchange(index, next, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            self.failures.store(0, Ordering::Release);
+            log::warn!(
+                "Read RPC > {} consecutive failures on endpoint {}; failing over to endpoint {}",
+                failures,
+                index,
+                next
+            );
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_commitment_config::CommitmentConfig;
+
+    use super::{READ_RPC_FAILOVER_THRESHOLD, ReadRpcPool};
+
+    #[test]
+    fn rotates_after_repeated_failures_and_wraps_around() {
+        let pool = ReadRpcPool::new(Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())))
+            .with_fallback_urls(
+                &["http://127.0.0.1:2".to_owned()],
+                CommitmentConfig::confirmed(),
+            );
+        assert_eq!(pool.endpoint_count(), 2);
+
+        assert!(!pool.record_failure(0));
+        pool.record_success(0);
+        assert!(!pool.record_failure(0));
+        assert!(pool.record_failure(0));
+        assert_eq!(pool.active().0, 1);
+        assert_eq!(pool.client().url(), "http://127.0.0.1:2");
+
+        // A stale report against the old endpoint does not rotate again.
+        assert!(pool.record_failure(0));
+        assert_eq!(pool.active().0, 1);
+
+        assert!(!pool.record_failure(1));
+        assert!(pool.record_failure(1));
+        assert_eq!(pool.active().0, 0);
+    }
+
+    #[test]
+    fn single_endpoint_never_rotates() {
+        let pool = ReadRpcPool::new(Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())));
+        for _ in 0..READ_RPC_FAILOVER_THRESHOLD.saturating_mul(2) {
+            assert!(!pool.record_failure(0));
+        }
+        assert_eq!(pool.active().0, 0);
+    }
+}
@@ -0,0 +1,136 @@
+use std::{future::Future, time::Duration};
+
+use solana_client::client_error::ClientError;
+use thiserror::Error;
+
+const RATE_LIMITED_MARKERS: &[&str] = &["too many requests", "rate limit", "rate-limit"];
+const UNRECOVERABLE_MARKERS: &[&str] = &["method not found", "-32601", "unauthorized", "forbidden"];
+
+/// How an RPC polling loop reacts to a failed request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RetryDecision {
+    /// Transient failure such as a timeout, dropped connection or null result; retry on the usual cadence.
+    RetryFast,
+    /// The endpoint is shedding load; back off before the next request.
+    RetrySlow,
+    /// Retrying cannot succeed, for example an unsupported method or rejected credentials.
+    Abort,
+}
+
+pub fn classify_rpc_error(error: &ClientError) -> RetryDecision {
+    classify_rpc_error_message(&error.to_string())
+}
+
+/// String form of [`classify_rpc_error`], for loops that already flattened the error.
+pub fn classify_rpc_error_message(message: &str) -> RetryDecision {
+    let message = message.to_ascii_lowercase();
+    let contains_any = |markers: &[&str]| markers.iter().any(|marker| message.contains(marker));
+
+    if contains_any(UNRECOVERABLE_MARKERS) {
+        RetryDecision::Abort
+    } else if contains_any(RATE_LIMITED_MARKERS) {
+        RetryDecision::RetrySlow
+    } else {
+        RetryDecision::RetryFast
+    }
+}
+
+/// Failure of an RPC call bounded by [`with_rpc_timeout`].
+#[derive(Debug, Error)]
+pub enum RpcCallError {
+    #[error("RPC call timed out after {}ms", timeout.as_millis())]
+    TimedOut { timeout: Duration },
+    #[error(transparent)]
+    Client(#[from] ClientError),
+}
+
+impl RpcCallError {
+    /// A timed-out call is retried like any other transient failure.
+    pub fn retry_decision(&self) -> RetryDecision {
+        match self {
+            Self::TimedOut { .. } => RetryDecision::RetryFast,
+            Self::Client(error) => classify_rpc_error(error),
+        }
+    }
+}
+
+/// Awaits `call` for at most `timeout` (`None` waits indefinitely), so a hung RPC cannot stall
+/// the caller between retries.
+pub async fn with_rpc_timeout<T>(
+    timeout: Option<Duration>,
+    call: impl Future<Output = Result<T, ClientError>>,
+) -> Result<T, RpcCallError> {
+    let Some(timeout) = timeout else {
+        return call.await.map_err(RpcCallError::from);
+    };
+
+    match tokio::time::timeout(timeout, call).await {
+        Ok(result) => result.map_err(RpcCallError::from),
+        Err(_elapsed) => Err(RpcCallError::TimedOut { timeout }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use solana_client::client_error::ClientError;
+
+    use super::{RetryDecision, RpcCallError, classify_rpc_error_message, with_rpc_timeout};
+
+    #[test]
+    fn classifies_representative_rpc_errors() {
+        for (message, expected) in [
+            (
+                "HTTP status client error (429 Too Many Requests) for url (https://rpc.example/)",
+                RetryDecision::RetrySlow,
+            ),
+            (
+                "RPC response error -32005: Rate limit exceeded",
+                RetryDecision::RetrySlow,
+            ),
+            (
+                "RPC response error -32601: Method not found",
+                RetryDecision::Abort,
+            ),
+            (
+                "HTTP status client error (401 Unauthorized) for url (https://rpc.example/)",
+                RetryDecision::Abort,
+            ),
+            (
+                "invalid type: null, expected struct UiConfirmedBlock",
+                RetryDecision::RetryFast,
+            ),
+            (
+                "error sending request for url (https://rpc.example/): operation timed out",
+                RetryDecision::RetryFast,
+            ),
+            (
+                "RPC response error -32004: Block not available for slot 429",
+                RetryDecision::RetryFast,
+            ),
+        ] {
+            assert_eq!(classify_rpc_error_message(message), expected, "{message}");
+        }
+    }
+
+    #[tokio::test]
+    async fn stalled_calls_time_out_into_a_fast_retry() {
+        let stalled = with_rpc_timeout(
+            Some(Duration::from_millis(20)),
+            std::future::pending::<Result<u64, ClientError>>(),
+        )
+        .await;
+        assert!(matches!(stalled, Err(RpcCallError::TimedOut { .. })));
+        assert_eq!(
+            stalled.err().map(|error| error.retry_decision()),
+            Some(RetryDecision::RetryFast)
+        );
+
+        let answered = with_rpc_timeout(Some(Duration::from_millis(20)), async {
+            Ok::<_, ClientError>(7_u64)
+        })
+        .await;
+        assert_eq!(answered.ok(), Some(7));
+    }
+}
@@ -0,0 +1,122 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::task::JoinHandle;
+
+use crate::{
+    domain::events::SniperInputEvent,
+    slices::sniper::queue::{QueueCloseReason, QueuePushOutcome, SniperEventQueue},
+};
+
+/// Stands in for SOF ingress: pushes a fixed list of events into the engine queue, then
+/// closes it as a clean shutdown. Built for tests only; integration tests enable it with the
+/// `testing` feature.
+#[derive(Debug)]
+pub struct ScriptedIngress {
+    events: Vec<SniperInputEvent>,
+    interval: Duration,
+}
+
+impl ScriptedIngress {
+    pub const fn new(events: Vec<SniperInputEvent>) -> Self {
+        Self {
+            events,
+            interval: Duration::ZERO,
+        }
+    }
+
+    /// Waits `interval` between pushes instead of pushing the whole script at once.
+    #[must_use]
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Spawns the replay. The handle resolves to one push outcome per scripted event.
+    pub fn spawn(self, queue: Arc<SniperEventQueue>) -> JoinHandle<Vec<QueuePushOutcome>> {
+        tokio::spawn(async move {
+            let mut outcomes = Vec::with_capacity(self.events.len());
+            for event in self.events {
+                if !self.interval.is_zero() && !outcomes.is_empty() {
+                    tokio::time::sleep(self.interval).await;
+                }
+                outcomes.push(queue.push(event));
+            }
+            queue.close_with(QueueCloseReason::Shutdown);
+            outcomes
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use solana_sdk::{
+        message::Message,
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::{Transaction, VersionedTransaction},
+    };
+
+    use super::ScriptedIngress;
+    use crate::{
+        domain::{
+            events::{
+                IngressMetadata, IngressSource, RaydiumCandidateEvent, RaydiumCandidateKind,
+                SniperInputEvent,
+            },
+            value_objects::EventQueueDropPolicy,
+        },
+        slices::sniper::{
+            queue::{QueueCloseReason, QueuePushOutcome, SniperEventQueue},
+            telemetry::LatencyTelemetry,
+        },
+    };
+
+    fn candidate_event(signature: Signature) -> SniperInputEvent {
+        let payer = Pubkey::new_unique();
+        let mut transaction =
+            VersionedTransaction::from(Transaction::new_unsigned(Message::new(&[], Some(&payer))));
+        transaction.signatures = vec![signature];
+
+        SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+            kind: RaydiumCandidateKind::Cpmm,
+            transaction: Arc::new(transaction),
+            ingress: IngressMetadata::from_receive_clock(IngressSource::Websocket, 1),
+        })
+    }
+
+    fn first_signature(event: &SniperInputEvent) -> Option<Signature> {
+        match event {
+            SniperInputEvent::RaydiumCandidate(candidate) => {
+                candidate.transaction.signatures.first().copied()
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_events_in_order_then_shuts_the_queue() {
+        let signatures = [Signature::new_unique(), Signature::new_unique()];
+        let queue = Arc::new(SniperEventQueue::new(
+            8,
+            EventQueueDropPolicy::DropNewest,
+            Arc::new(LatencyTelemetry::disabled()),
+        ));
+
+        let replay =
+            ScriptedIngress::new(signatures.iter().copied().map(candidate_event).collect())
+                .spawn(Arc::clone(&queue))
+                .await;
+        assert!(replay.is_ok());
+        if let Ok(outcomes) = replay {
+            assert_eq!(outcomes, vec![QueuePushOutcome::Enqueued; 2]);
+        }
+
+        for expected in signatures {
+            let event = queue.recv().await;
+            assert_eq!(event.as_ref().and_then(first_signature), Some(expected));
+        }
+        assert!(queue.recv().await.is_none());
+        assert_eq!(queue.close_reason(), Some(QueueCloseReason::Shutdown));
+    }
+}
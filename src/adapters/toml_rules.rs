@@ -1,13 +1,18 @@
-use std::{collections::HashSet, io, str::FromStr};
+use std::{collections::HashSet, io, path::PathBuf, str::FromStr};
 
 use solana_sdk::pubkey::Pubkey;
 
 use crate::{
     domain::{
-        config::{RuleKind, load_sniper_config_file},
+        config::{
+            ConfigError, RuleConfigEntry, RuleKind, SniperConfigFile, load_sniper_config_file,
+        },
         entities::SnipeRule,
-        value_objects::sol_amount::parse_positive_sol_str_to_lamports,
-        value_objects::{RuleAddress, RuleSlippageBps, RuleSolAmount},
+        value_objects::sol_amount::{Lamports, parse_positive_sol_str_to_lamports},
+        value_objects::{
+            RuleActiveWindow, RuleAddress, RuleFeeMultiplier, RuleSlippageBps, RuleSolAmount,
+            RuleTipPct,
+        },
     },
     ports::rule_repository::RuleRepository,
 };
@@ -15,11 +20,32 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct TomlRuleRepository {
     config_path: String,
+    override_path: Option<String>,
 }
 
 impl TomlRuleRepository {
     pub const fn new(config_path: String) -> Self {
-        Self { config_path }
+        Self {
+            config_path,
+            override_path: None,
+        }
+    }
+
+    pub fn with_override_path(mut self, override_path: Option<String>) -> Self {
+        self.override_path = override_path;
+        self
+    }
+
+    /// Files the rules are read from: the config and, when set, its override.
+    pub fn source_paths(&self) -> Vec<PathBuf> {
+        std::iter::once(&self.config_path)
+            .chain(self.override_path.as_ref())
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    fn load_config(&self) -> Result<SniperConfigFile, ConfigError> {
+        load_sniper_config_file(&self.config_path, self.override_path.as_deref())
     }
 
     fn report_invalid(message: &str, initial: bool) {
@@ -29,84 +55,353 @@ impl TomlRuleRepository {
         }
     }
 
-    fn parse_rule_entry(
-        kind: RuleKind,
-        address: &str,
-        snipe_height_sol: &str,
-        tip_budget_sol: &str,
-        slippage_pct: &str,
-        initial: bool,
-    ) -> Option<SnipeRule> {
-        let file_type = match kind {
-            RuleKind::Mint => "MINTS",
-            RuleKind::Deployer => "DEPLOYERS",
-        };
+    fn parse_rule_entry(entry: &RuleConfigEntry) -> Result<SnipeRule, String> {
+        let RuleConfigEntry {
+            kind,
+            address,
+            snipe_height_sol,
+            target_token_amount,
+            max_snipe_height_sol,
+            tip_budget_sol,
+            tip_budget_pct,
+            tip_budget_min_sol,
+            tip_budget_max_sol,
+            slippage_pct,
+            max_slippage_pct,
+            active_from,
+            active_until,
+            fee_multiplier,
+            priority,
+        } = entry;
+
+        let file_type = rule_file_type(*kind);
 
         let address = address.trim().to_owned();
         if address.is_empty() {
-            Self::report_invalid(&format!("{} > Empty address", file_type), initial);
-            return None;
-        }
-
-        let snipe_height = match parse_positive_sol_str_to_lamports(snipe_height_sol) {
-            Some(value) => RuleSolAmount::new(value),
-            None => {
-                Self::report_invalid(
-                    &format!(
-                        "{} > Invalid snipe height '{}' on address {}",
-                        file_type, snipe_height_sol, address
-                    ),
-                    initial,
-                );
-                return None;
+            return Err(format!("{} > Empty address", file_type));
+        }
+
+        let parse_snipe_height = |key: &str, value: &str| {
+            parse_positive_sol_str_to_lamports(value)
+                .map(RuleSolAmount::new)
+                .ok_or_else(|| {
+                    format!(
+                        "{} > Invalid {} '{}' on address {}",
+                        file_type, key, value, address
+                    )
+                })
+        };
+        // A token-amount rule keeps its SOL ceiling as the snipe height, so balance checks
+        // and rule diffs report what it can spend.
+        let snipe_height = match (
+            snipe_height_sol.as_deref(),
+            *target_token_amount,
+            max_snipe_height_sol.as_deref(),
+        ) {
+            (Some(snipe_height_sol), None, None) => {
+                parse_snipe_height("snipe height", snipe_height_sol)?
+            }
+            (None, Some(0), _) => {
+                return Err(format!(
+                    "{} > target_token_amount must be positive on address {}",
+                    file_type, address
+                ));
+            }
+            (None, Some(_tokens), Some(max_snipe_height_sol)) => {
+                parse_snipe_height("max snipe height", max_snipe_height_sol)?
+            }
+            (None, Some(_tokens), None) => {
+                return Err(format!(
+                    "{} > target_token_amount needs max_snipe_height_sol on address {}",
+                    file_type, address
+                ));
+            }
+            (Some(_), None, Some(_)) => {
+                return Err(format!(
+                    "{} > max_snipe_height_sol only applies to target_token_amount on address {}",
+                    file_type, address
+                ));
+            }
+            (Some(_), Some(_), _) | (None, None, _) => {
+                return Err(format!(
+                    "{} > Set exactly one of snipe_height_sol or target_token_amount on address {}",
+                    file_type, address
+                ));
             }
         };
 
-        let jito_tip = match parse_positive_sol_str_to_lamports(tip_budget_sol) {
-            Some(value) => RuleSolAmount::new(value),
-            None => {
-                Self::report_invalid(
-                    &format!(
-                        "{} > Invalid tip budget '{}' on address {}",
-                        file_type, tip_budget_sol, address
-                    ),
-                    initial,
-                );
-                return None;
+        let parse_tip = |key: &str, value: &str| {
+            parse_positive_sol_str_to_lamports(value)
+                .map(RuleSolAmount::new)
+                .ok_or_else(|| {
+                    format!(
+                        "{} > Invalid {} '{}' on address {}",
+                        file_type, key, value, address
+                    )
+                })
+        };
+        let (jito_tip, jito_tip_pct, jito_tip_floor) = match (
+            tip_budget_sol.as_deref(),
+            tip_budget_pct.as_deref(),
+            tip_budget_min_sol.as_deref(),
+            tip_budget_max_sol.as_deref(),
+        ) {
+            (Some(tip_budget_sol), None, None, None) => (
+                parse_tip("tip budget", tip_budget_sol)?,
+                None,
+                RuleSolAmount::new(Lamports::new(0)),
+            ),
+            (None, Some(tip_budget_pct), min_sol, Some(max_sol)) => {
+                let pct = RuleTipPct::from_pct_str(tip_budget_pct).map_err(|error| {
+                    format!(
+                        "{} > Invalid tip_budget_pct '{}' on address {}: {}",
+                        file_type, tip_budget_pct, address, error
+                    )
+                })?;
+                let ceiling = parse_tip("tip_budget_max_sol", max_sol)?;
+                let floor = match min_sol {
+                    Some(min_sol) => parse_tip("tip_budget_min_sol", min_sol)?,
+                    None => RuleSolAmount::new(Lamports::new(0)),
+                };
+                if floor > ceiling {
+                    return Err(format!(
+                        "{} > tip_budget_min_sol exceeds tip_budget_max_sol on address {}",
+                        file_type, address
+                    ));
+                }
+                (ceiling, Some(pct), floor)
+            }
+            (None, Some(_), _, None) => {
+                return Err(format!(
+                    "{} > tip_budget_pct requires tip_budget_max_sol on address {}",
+                    file_type, address
+                ));
+            }
+            (Some(_), _, _, _) | (None, None, _, _) => {
+                return Err(format!(
+                    "{} > Set exactly one of tip_budget_sol or tip_budget_pct (with its min/max bounds) on address {}",
+                    file_type, address
+                ));
             }
         };
 
-        let slippage = match RuleSlippageBps::from_pct_str(slippage_pct) {
-            Ok(value) => value,
-            Err(error) => {
-                Self::report_invalid(
-                    &format!(
-                        "{} > Invalid slippage '{}' on address {}: {}",
-                        file_type, slippage_pct, address, error
-                    ),
-                    initial,
-                );
-                return None;
+        let active_window = match (active_from.as_deref(), active_until.as_deref()) {
+            (Some(active_from), Some(active_until)) => Some(
+                RuleActiveWindow::from_hhmm_strs(active_from, active_until).map_err(|error| {
+                    format!(
+                        "{} > Invalid active window '{}'-'{}' on address {}: {}",
+                        file_type, active_from, active_until, address, error
+                    )
+                })?,
+            ),
+            (None, None) => None,
+            _ => {
+                return Err(format!(
+                    "{} > active_from and active_until must be set together on address {}",
+                    file_type, address
+                ));
             }
         };
 
-        if Pubkey::from_str(&address).is_err() {
-            Self::report_invalid(
-                &format!("{} > Invalid address {}", file_type, address),
-                initial,
-            );
-            return None;
+        let slippage = RuleSlippageBps::from_pct_str(slippage_pct).map_err(|error| {
+            format!(
+                "{} > Invalid slippage '{}' on address {}: {}",
+                file_type, slippage_pct, address, error
+            )
+        })?;
+        let max_slippage = match max_slippage_pct.as_deref() {
+            Some(max_slippage_pct) => {
+                let max_slippage =
+                    RuleSlippageBps::from_pct_str(max_slippage_pct).map_err(|error| {
+                        format!(
+                            "{} > Invalid max_slippage_pct '{}' on address {}: {}",
+                            file_type, max_slippage_pct, address, error
+                        )
+                    })?;
+                if max_slippage < slippage {
+                    return Err(format!(
+                        "{} > max_slippage_pct is below slippage_pct on address {}",
+                        file_type, address
+                    ));
+                }
+                Some(max_slippage)
+            }
+            None => None,
+        };
+
+        let fee_multiplier = match fee_multiplier.as_deref() {
+            Some(fee_multiplier) => {
+                RuleFeeMultiplier::from_decimal_str(fee_multiplier).map_err(|error| {
+                    format!(
+                        "{} > Invalid fee multiplier '{}' on address {}: {}",
+                        file_type, fee_multiplier, address, error
+                    )
+                })?
+            }
+            None => RuleFeeMultiplier::ONE,
+        };
+
+        let is_catch_all = address == RuleAddress::CATCH_ALL;
+        if is_catch_all && *kind != RuleKind::Deployer {
+            return Err(format!(
+                "{} > Catch-all address '{}' is only supported on deployer rules",
+                file_type,
+                RuleAddress::CATCH_ALL
+            ));
         }
 
-        let address = match RuleAddress::try_from(address) {
-            Ok(value) => value,
-            Err(error) => {
-                Self::report_invalid(&format!("{} > {}", file_type, error), initial);
-                return None;
+        if !is_catch_all && Pubkey::from_str(&address).is_err() {
+            return Err(format!("{} > Invalid address {}", file_type, address));
+        }
+
+        let address =
+            RuleAddress::try_from(address).map_err(|error| format!("{} > {}", file_type, error))?;
+
+        Ok(SnipeRule::new(address, snipe_height, jito_tip, slippage)
+            .with_active_window(active_window)
+            .with_fee_multiplier(fee_multiplier)
+            .with_priority(*priority)
+            .with_target_token_amount(*target_token_amount)
+            .with_jito_tip_pct(jito_tip_pct, jito_tip_floor)
+            .with_max_slippage(max_slippage))
+    }
+
+    fn collect_rules(
+        config: &SniperConfigFile,
+        expected_kind: RuleKind,
+    ) -> (Vec<SnipeRule>, Vec<String>) {
+        let file_type = rule_file_type(expected_kind);
+        let mut rules = Vec::new();
+        let mut problems = Vec::new();
+        let mut seen_addresses = HashSet::new();
+
+        for entry in config
+            .rules
+            .iter()
+            .filter(|rule| rule.kind == expected_kind)
+        {
+            let rule = match Self::parse_rule_entry(entry) {
+                Ok(rule) => rule,
+                Err(problem) => {
+                    problems.push(problem);
+                    continue;
+                }
+            };
+
+            if rule.address().is_catch_all() && seen_addresses.contains(rule.address()) {
+                problems.push(format!(
+                    "{} > Only one catch-all rule is allowed",
+                    file_type
+                ));
+                continue;
             }
+
+            if !seen_addresses.insert(rule.address().clone()) {
+                problems.push(format!(
+                    "{} > Same address used multiple times {}",
+                    file_type,
+                    rule.address()
+                ));
+                continue;
+            }
+
+            rules.push(rule);
+        }
+
+        (rules, problems)
+    }
+
+    fn collect_deployer_list(
+        config: &SniperConfigFile,
+        list_type: DeployerListType,
+    ) -> (Vec<RuleAddress>, Vec<String>) {
+        let list_name = list_type.as_str();
+        let entries = match list_type {
+            DeployerListType::Blocklist => &config.deployers.blocklist,
+            DeployerListType::Allowlist => &config.deployers.allowlist,
         };
+        let mut addresses = Vec::with_capacity(entries.len());
+        let mut problems = Vec::new();
+        let mut seen_addresses = HashSet::new();
 
-        Some(SnipeRule::new(address, snipe_height, jito_tip, slippage))
+        for entry in entries {
+            let address = entry.trim();
+            if Pubkey::from_str(address).is_err() {
+                problems.push(format!("{} > Invalid address {}", list_name, address));
+                continue;
+            }
+            let address = match RuleAddress::try_from(address) {
+                Ok(address) => address,
+                Err(error) => {
+                    problems.push(format!("{} > {}", list_name, error));
+                    continue;
+                }
+            };
+            if !seen_addresses.insert(address.clone()) {
+                problems.push(format!(
+                    "{} > Same address used multiple times {}",
+                    list_name, address
+                ));
+                continue;
+            }
+
+            addresses.push(address);
+        }
+
+        (addresses, problems)
+    }
+
+    pub fn validate_rules(&self) -> Result<RuleValidationSummary, ConfigError> {
+        let config = self.load_config()?;
+        let (mint_rules, mut problems) = Self::collect_rules(&config, RuleKind::Mint);
+        let (deployer_rules, deployer_problems) = Self::collect_rules(&config, RuleKind::Deployer);
+        problems.extend(deployer_problems);
+        for list_type in [DeployerListType::Blocklist, DeployerListType::Allowlist] {
+            problems.extend(Self::collect_deployer_list(&config, list_type).1);
+        }
+
+        Ok(RuleValidationSummary {
+            mint_rules: mint_rules.len(),
+            deployer_rules: deployer_rules.len(),
+            problems,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RuleValidationSummary {
+    pub mint_rules: usize,
+    pub deployer_rules: usize,
+    pub problems: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum DeployerListType {
+    Blocklist,
+    Allowlist,
+}
+
+impl DeployerListType {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "BLOCKLIST" => Some(Self::Blocklist),
+            "ALLOWLIST" => Some(Self::Allowlist),
+            _ => None,
+        }
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Blocklist => "BLOCKLIST",
+            Self::Allowlist => "ALLOWLIST",
+        }
+    }
+}
+
+const fn rule_file_type(kind: RuleKind) -> &'static str {
+    match kind {
+        RuleKind::Mint => "MINTS",
+        RuleKind::Deployer => "DEPLOYERS",
     }
 }
 
@@ -116,7 +411,8 @@ impl RuleRepository for TomlRuleRepository {
         file_type: &str,
         initial: bool,
     ) -> Result<Vec<SnipeRule>, io::Error> {
-        let config = load_sniper_config_file(&self.config_path)
+        let config = self
+            .load_config()
             .map_err(|source| io::Error::new(io::ErrorKind::InvalidData, source))?;
 
         let expected_kind = match file_type {
@@ -130,54 +426,52 @@ impl RuleRepository for TomlRuleRepository {
             }
         };
 
-        let mut rules = Vec::new();
-        let mut seen_addresses = HashSet::new();
+        let (rules, problems) = Self::collect_rules(&config, expected_kind);
+        for problem in &problems {
+            Self::report_invalid(problem, initial);
+        }
 
-        for entry in config
-            .rules
-            .iter()
-            .filter(|rule| rule.kind == expected_kind)
-        {
-            let parsed_rule = Self::parse_rule_entry(
-                expected_kind,
-                &entry.address,
-                &entry.snipe_height_sol,
-                &entry.tip_budget_sol,
-                &entry.slippage_pct,
-                initial,
-            );
+        Ok(rules)
+    }
 
-            if let Some(rule) = parsed_rule {
-                if !seen_addresses.insert(rule.address().clone()) {
-                    Self::report_invalid(
-                        &format!(
-                            "{} > Same address used multiple times {}",
-                            file_type,
-                            rule.address()
-                        ),
-                        initial,
-                    );
-                    continue;
-                }
+    async fn load_deployer_list(
+        &self,
+        list_type: &str,
+        initial: bool,
+    ) -> Result<Vec<RuleAddress>, io::Error> {
+        let config = self
+            .load_config()
+            .map_err(|source| io::Error::new(io::ErrorKind::InvalidData, source))?;
 
-                rules.push(rule);
-            }
+        let list_type = DeployerListType::parse(list_type).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported deployer list type '{}'", list_type),
+            )
+        })?;
+
+        let (addresses, problems) = Self::collect_deployer_list(&config, list_type);
+        for problem in &problems {
+            Self::report_invalid(problem, initial);
         }
 
-        Ok(rules)
+        Ok(addresses)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::TomlRuleRepository;
-    use crate::ports::rule_repository::RuleRepository;
-    use std::path::PathBuf;
+    use crate::{
+        domain::value_objects::{RuleTipPct, sol_amount::Lamports},
+        ports::rule_repository::RuleRepository,
+        test_support::temp_path,
+    };
     use tokio::fs;
 
     #[tokio::test]
     async fn loads_mint_and_deployer_rules_from_toml() {
-        let config_path = temp_config_path("toml_rules_load");
+        let config_path = temp_path("toml_rules_load", "toml");
         let write_result = fs::write(
             &config_path,
             r#"
@@ -224,12 +518,250 @@ slippage_pct = "1"
         assert!(cleanup_result.is_ok());
     }
 
-    fn temp_config_path(prefix: &str) -> PathBuf {
-        let file_name = format!(
-            "{}_{}.toml",
-            prefix,
-            crate::domain::events::unix_timestamp_now_ns()
-        );
-        std::env::temp_dir().join(file_name)
+    #[tokio::test]
+    async fn accepts_single_catch_all_deployer_rule() {
+        let config_path = temp_path("toml_rules_catch_all", "toml");
+        let write_result = fs::write(
+            &config_path,
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[[rules]]
+kind = "deployer"
+address = "*"
+snipe_height_sol = "0.01"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+
+[[rules]]
+kind = "deployer"
+address = "*"
+snipe_height_sol = "0.02"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+
+[[rules]]
+kind = "mint"
+address = "*"
+snipe_height_sol = "0.01"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+"#,
+        )
+        .await;
+        assert!(write_result.is_ok());
+
+        let repository = TomlRuleRepository::new(config_path.to_string_lossy().into_owned());
+        let mint_rules = repository.load_rules("MINTS", false).await;
+        let deployer_rules = repository.load_rules("DEPLOYERS", false).await;
+
+        assert!(mint_rules.is_ok());
+        assert!(deployer_rules.is_ok());
+        if let (Ok(mint_rules), Ok(deployer_rules)) = (mint_rules, deployer_rules) {
+            assert!(mint_rules.is_empty());
+            assert_eq!(deployer_rules.len(), 1);
+            assert!(
+                deployer_rules
+                    .first()
+                    .is_some_and(|rule| rule.address().is_catch_all())
+            );
+        }
+
+        let cleanup_result = fs::remove_file(&config_path).await;
+        assert!(cleanup_result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn loads_deployer_blocklist_and_allowlist() {
+        let config_path = temp_path("toml_rules_deployer_lists", "toml");
+        let write_result = fs::write(
+            &config_path,
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[deployers]
+blocklist = ["11111111111111111111111111111111", "not-a-pubkey"]
+allowlist = ["Vote111111111111111111111111111111111111111"]
+"#,
+        )
+        .await;
+        assert!(write_result.is_ok());
+
+        let repository = TomlRuleRepository::new(config_path.to_string_lossy().into_owned());
+        let blocklist = repository.load_deployer_list("BLOCKLIST", false).await;
+        let allowlist = repository.load_deployer_list("ALLOWLIST", false).await;
+
+        assert!(blocklist.is_ok());
+        assert!(allowlist.is_ok());
+        if let (Ok(blocklist), Ok(allowlist)) = (blocklist, allowlist) {
+            assert_eq!(blocklist.len(), 1);
+            assert_eq!(allowlist.len(), 1);
+        }
+        let summary = repository.validate_rules();
+        assert!(summary.is_ok_and(|summary| summary.problems.len() == 1));
+
+        let cleanup_result = fs::remove_file(&config_path).await;
+        assert!(cleanup_result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn requires_exactly_one_swap_amount_per_rule() {
+        let config_path = temp_path("toml_rules_swap_amount", "toml");
+        let write_result = fs::write(
+            &config_path,
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[[rules]]
+kind = "mint"
+address = "So11111111111111111111111111111111111111112"
+target_token_amount = 1000000
+max_snipe_height_sol = "0.05"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+
+[[rules]]
+kind = "mint"
+address = "11111111111111111111111111111111"
+snipe_height_sol = "0.01"
+target_token_amount = 1000000
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+
+[[rules]]
+kind = "mint"
+address = "Vote111111111111111111111111111111111111111"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+
+[[rules]]
+kind = "mint"
+address = "Stake11111111111111111111111111111111111111"
+target_token_amount = 1000000
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+"#,
+        )
+        .await;
+        assert!(write_result.is_ok());
+
+        let repository = TomlRuleRepository::new(config_path.to_string_lossy().into_owned());
+        let summary = repository.validate_rules();
+        assert!(summary.is_ok());
+        if let Ok(summary) = summary {
+            assert_eq!(summary.mint_rules, 1);
+            assert_eq!(summary.problems.len(), 3);
+            assert_eq!(
+                summary
+                    .problems
+                    .iter()
+                    .filter(|problem| problem.contains("exactly one of snipe_height_sol"))
+                    .count(),
+                2
+            );
+            assert!(
+                summary
+                    .problems
+                    .iter()
+                    .any(|problem| problem.contains("needs max_snipe_height_sol"))
+            );
+        }
+        let mint_rules = repository.load_rules("MINTS", false).await;
+        assert!(mint_rules.is_ok_and(|rules| {
+            rules.first().is_some_and(|rule| {
+                rule.target_token_amount() == Some(1_000_000)
+                    && rule.required_lamports() == Lamports::new(51_000_000)
+            })
+        }));
+
+        let cleanup_result = fs::remove_file(&config_path).await;
+        assert!(cleanup_result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn parses_percentage_tips_with_their_bounds() {
+        let config_path = temp_path("toml_rules_tip_pct", "toml");
+        let write_result = fs::write(
+            &config_path,
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[[rules]]
+kind = "mint"
+address = "So11111111111111111111111111111111111111112"
+snipe_height_sol = "1"
+tip_budget_pct = "0.5"
+tip_budget_min_sol = "0.001"
+tip_budget_max_sol = "0.01"
+slippage_pct = "1"
+
+[[rules]]
+kind = "mint"
+address = "11111111111111111111111111111111"
+snipe_height_sol = "1"
+tip_budget_sol = "0.001"
+tip_budget_pct = "0.5"
+tip_budget_max_sol = "0.01"
+slippage_pct = "1"
+
+[[rules]]
+kind = "mint"
+address = "Vote111111111111111111111111111111111111111"
+snipe_height_sol = "1"
+tip_budget_pct = "0.5"
+slippage_pct = "1"
+
+[[rules]]
+kind = "mint"
+address = "Stake11111111111111111111111111111111111111"
+snipe_height_sol = "1"
+tip_budget_pct = "0.5"
+tip_budget_min_sol = "0.1"
+tip_budget_max_sol = "0.01"
+slippage_pct = "1"
+"#,
+        )
+        .await;
+        assert!(write_result.is_ok());
+
+        let repository = TomlRuleRepository::new(config_path.to_string_lossy().into_owned());
+        let summary = repository.validate_rules();
+        assert!(summary.is_ok());
+        if let Ok(summary) = summary {
+            assert_eq!(summary.mint_rules, 1);
+            assert_eq!(summary.problems.len(), 3);
+        }
+        let mint_rules = repository.load_rules("MINTS", false).await;
+        assert!(mint_rules.is_ok_and(|rules| {
+            rules.first().is_some_and(|rule| {
+                rule.jito_tip_pct().map(RuleTipPct::as_bps) == Some(50)
+                    && rule.hot().jito_tip_for(1_000_000_000).as_u64() == 5_000_000
+                    && rule.hot().jito_tip_for(100_000_000).as_u64() == 1_000_000
+                    && rule.required_lamports().as_u64() == 1_010_000_000
+            })
+        }));
+
+        let cleanup_result = fs::remove_file(&config_path).await;
+        assert!(cleanup_result.is_ok());
     }
 }
@@ -0,0 +1,427 @@
+use std::{
+    sync::{Arc, Mutex as StdMutex, PoisonError},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use sof_tx::{SignedTx, SubmitPlan, TxSubmitClient};
+use solana_client::{
+    client_error::ClientErrorKind,
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::RpcSendTransactionConfig,
+    rpc_request::{RpcError, RpcResponseErrorData},
+};
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{signature::Signature, transaction::VersionedTransaction};
+use solana_transaction_status::UiTransactionEncoding;
+use tokio::sync::{Mutex, mpsc};
+use url::Url;
+
+use crate::{
+    adapters::rpc_retry::{RpcCallError, with_rpc_timeout},
+    domain::value_objects::TxSubmissionMode,
+    ports::transaction_submitter::{TransactionSubmitError, TransactionSubmitter},
+};
+
+struct SubmitEndpoint {
+    label: Arc<str>,
+    rpc: Arc<RpcClient>,
+}
+
+impl SubmitEndpoint {
+    fn from_url(url: &str, commitment: CommitmentConfig) -> Self {
+        Self {
+            label: endpoint_label(url),
+            rpc: Arc::new(RpcClient::new_with_commitment(url.to_owned(), commitment)),
+        }
+    }
+}
+
+/// Sends through the RPC node(s) in direct mode and through the Jito block engine otherwise.
+///
+/// With several direct endpoints the same signed transaction is broadcast to all of them
+/// concurrently and the first accepted signature is returned.
+pub struct RpcSubmitter {
+    direct: Vec<SubmitEndpoint>,
+    jito_rpc: RpcClient,
+    /// The read RPC's commitment, so preflight simulates against the same bank as the
+    /// fetched pool and blockhash rather than the client default of finalized.
+    commitment: CommitmentConfig,
+    skip_preflight: bool,
+    send_max_retries: usize,
+    call_timeout: Option<Duration>,
+}
+
+impl RpcSubmitter {
+    pub fn new(
+        rpc: Arc<RpcClient>,
+        jito_url: String,
+        skip_preflight: bool,
+        send_max_retries: usize,
+    ) -> Self {
+        let commitment = rpc.commitment();
+        Self {
+            direct: vec![SubmitEndpoint {
+                label: Arc::from("rpc"),
+                rpc,
+            }],
+            jito_rpc: RpcClient::new(jito_url),
+            commitment,
+            skip_preflight,
+            send_max_retries,
+            call_timeout: None,
+        }
+    }
+
+    /// Bounds each send by `runtime.rpc_call_timeout_ms`; `None` waits indefinitely.
+    #[must_use]
+    pub const fn with_call_timeout(mut self, call_timeout: Option<Duration>) -> Self {
+        self.call_timeout = call_timeout;
+        self
+    }
+
+    /// Replaces the read RPC with dedicated submit endpoints at the read RPC's commitment; an
+    /// empty list keeps the read RPC.
+    pub fn with_fan_out_urls(mut self, urls: &[String]) -> Self {
+        if !urls.is_empty() {
+            self.direct = urls
+                .iter()
+                .map(|url| SubmitEndpoint::from_url(url, self.commitment))
+                .collect();
+        }
+        self
+    }
+
+    pub fn send_config(&self) -> RpcSendTransactionConfig {
+        RpcSendTransactionConfig {
+            skip_preflight: self.skip_preflight,
+            preflight_commitment: Some(self.commitment.commitment),
+            encoding: Some(UiTransactionEncoding::Base58),
+            max_retries: Some(self.send_max_retries),
+            ..RpcSendTransactionConfig::default()
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionSubmitter for RpcSubmitter {
+    async fn submit(
+        &self,
+        transaction: &VersionedTransaction,
+        mode: TxSubmissionMode,
+    ) -> Result<Signature, TransactionSubmitError> {
+        let rpc = match (mode, self.direct.as_slice()) {
+            (TxSubmissionMode::Jito, _) => &self.jito_rpc,
+            (TxSubmissionMode::Direct, [endpoint]) => endpoint.rpc.as_ref(),
+            (TxSubmissionMode::Direct, _) => return self.fan_out(transaction).await,
+        };
+        with_rpc_timeout(
+            self.call_timeout,
+            rpc.send_transaction_with_config(transaction, self.send_config()),
+        )
+        .await
+        .map_err(|error| TransactionSubmitError::Rpc {
+            detail: describe_send_error(&error),
+        })
+    }
+}
+
+impl RpcSubmitter {
+    async fn fan_out(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> Result<Signature, TransactionSubmitError> {
+        let transaction = Arc::new(transaction.clone());
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+
+        // Sends run to completion on their own tasks so slower endpoints still broadcast
+        // after the first acceptance has been returned.
+        for endpoint in &self.direct {
+            let rpc = Arc::clone(&endpoint.rpc);
+            let label = Arc::clone(&endpoint.label);
+            let transaction = Arc::clone(&transaction);
+            let send_config = self.send_config();
+            let call_timeout = self.call_timeout;
+            let result_tx = result_tx.clone();
+            tokio::spawn(async move {
+                let result = with_rpc_timeout(
+                    call_timeout,
+                    rpc.send_transaction_with_config(transaction.as_ref(), send_config),
+                )
+                .await
+                .map_err(|error| describe_send_error(&error));
+                match &result {
+                    Ok(signature) => {
+                        log::debug!("RPC fan-out > {} > accepted {}", label, signature);
+                    }
+                    Err(detail) => {
+                        log::warn!("RPC fan-out > {} > send failed: {}", label, detail);
+                    }
+                }
+                let _sent = result_tx.send(result);
+            });
+        }
+        drop(result_tx);
+
+        let mut failures = Vec::new();
+        while let Some(result) = result_rx.recv().await {
+            match result {
+                Ok(signature) => return Ok(signature),
+                Err(detail) => failures.push(detail),
+            }
+        }
+
+        Err(TransactionSubmitError::Rpc {
+            detail: format!(
+                "all {} submit endpoints failed: {}",
+                self.direct.len(),
+                failures.join("; ")
+            ),
+        })
+    }
+}
+
+/// Sends through a `sof-tx` submit plan; routing is decided by the plan, not the mode.
+pub struct SofTxSubmitter {
+    client: Arc<Mutex<TxSubmitClient>>,
+    plan: SubmitPlan,
+}
+
+impl SofTxSubmitter {
+    pub const fn new(client: Arc<Mutex<TxSubmitClient>>, plan: SubmitPlan) -> Self {
+        Self { client, plan }
+    }
+}
+
+#[async_trait]
+impl TransactionSubmitter for SofTxSubmitter {
+    async fn submit(
+        &self,
+        transaction: &VersionedTransaction,
+        _mode: TxSubmissionMode,
+    ) -> Result<Signature, TransactionSubmitError> {
+        let tx_bytes =
+            bincode::serialize(transaction).map_err(|error| TransactionSubmitError::Serialize {
+                detail: error.to_string(),
+            })?;
+        let mut client = self.client.lock().await;
+        client
+            .submit_signed_via(
+                SignedTx::VersionedTransactionBytes(tx_bytes),
+                self.plan.clone(),
+            )
+            .await
+            .map_err(|error| TransactionSubmitError::SofTx {
+                detail: error.to_string(),
+            })?;
+        Ok(transaction.signatures.first().copied().unwrap_or_default())
+    }
+}
+
+/// Records submissions instead of sending them; for tests and offline harnesses.
+#[derive(Debug, Default)]
+pub struct FakeSubmitter {
+    failure: Option<String>,
+    submitted: StdMutex<Vec<(Signature, TxSubmissionMode)>>,
+}
+
+impl FakeSubmitter {
+    pub fn failing(detail: impl Into<String>) -> Self {
+        Self {
+            failure: Some(detail.into()),
+            submitted: StdMutex::default(),
+        }
+    }
+
+    pub fn submitted(&self) -> Vec<(Signature, TxSubmissionMode)> {
+        self.submitted
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+#[async_trait]
+impl TransactionSubmitter for FakeSubmitter {
+    async fn submit(
+        &self,
+        transaction: &VersionedTransaction,
+        mode: TxSubmissionMode,
+    ) -> Result<Signature, TransactionSubmitError> {
+        let signature = transaction.signatures.first().copied().unwrap_or_default();
+        self.submitted
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((signature, mode));
+
+        self.failure.as_ref().map_or(Ok(signature), |detail| {
+            Err(TransactionSubmitError::Rpc {
+                detail: detail.clone(),
+            })
+        })
+    }
+}
+
+/// Host-only label so API keys in submit URLs never reach the logs.
+fn endpoint_label(url: &str) -> Arc<str> {
+    Url::parse(url)
+        .ok()
+        .and_then(|parsed| parsed.host_str().map(Arc::from))
+        .unwrap_or_else(|| Arc::from("invalid-url"))
+}
+
+fn describe_send_error(error: &RpcCallError) -> String {
+    let RpcCallError::Client(error) = error else {
+        return error.to_string();
+    };
+    let ClientErrorKind::RpcError(RpcError::RpcResponseError {
+        message,
+        data: RpcResponseErrorData::SendTransactionPreflightFailure(simulation),
+        ..
+    }) = error.kind()
+    else {
+        return error.to_string();
+    };
+
+    let logs = simulation
+        .logs
+        .as_ref()
+        .map_or_else(String::new, |logs| logs.join(" | "));
+    format!(
+        "{message} (preflight error: {:?}, logs: [{}])",
+        simulation.err, logs
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_commitment_config::{CommitmentConfig, CommitmentLevel};
+    use solana_sdk::{
+        message::Message,
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::{Transaction, VersionedTransaction},
+    };
+
+    use super::{FakeSubmitter, RpcSubmitter, endpoint_label};
+    use crate::{
+        domain::value_objects::TxSubmissionMode, ports::transaction_submitter::TransactionSubmitter,
+    };
+
+    #[test]
+    fn send_config_follows_skip_preflight_setting() {
+        let rpc = Arc::new(RpcClient::new_with_commitment(
+            "http://127.0.0.1:1".to_owned(),
+            CommitmentConfig::processed(),
+        ));
+        let skipping =
+            RpcSubmitter::new(Arc::clone(&rpc), "https://jito.example".to_owned(), true, 0);
+        assert!(skipping.send_config().skip_preflight);
+        assert_eq!(skipping.send_config().max_retries, Some(0));
+
+        let preflighting = RpcSubmitter::new(rpc, "https://jito.example".to_owned(), false, 3);
+        let send_config = preflighting.send_config();
+        assert!(!send_config.skip_preflight);
+        assert_eq!(send_config.max_retries, Some(3));
+        assert_eq!(
+            send_config.preflight_commitment,
+            Some(CommitmentLevel::Processed)
+        );
+    }
+
+    #[test]
+    fn fan_out_endpoints_keep_the_read_commitment() {
+        let submitter = RpcSubmitter::new(
+            Arc::new(RpcClient::new_with_commitment(
+                "http://127.0.0.1:1".to_owned(),
+                CommitmentConfig::confirmed(),
+            )),
+            "https://jito.example".to_owned(),
+            false,
+            0,
+        )
+        .with_fan_out_urls(&[
+            "http://127.0.0.1:1".to_owned(),
+            "http://127.0.0.1:2".to_owned(),
+        ]);
+
+        assert_eq!(submitter.direct.len(), 2);
+        assert!(
+            submitter
+                .direct
+                .iter()
+                .all(|endpoint| endpoint.rpc.commitment() == CommitmentConfig::confirmed())
+        );
+        assert_eq!(
+            submitter.send_config().preflight_commitment,
+            Some(CommitmentLevel::Confirmed)
+        );
+    }
+
+    #[tokio::test]
+    async fn fan_out_reports_every_failed_endpoint() {
+        let submitter = RpcSubmitter::new(
+            Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())),
+            "https://jito.example".to_owned(),
+            true,
+            0,
+        )
+        .with_fan_out_urls(&[
+            "http://127.0.0.1:1".to_owned(),
+            "http://127.0.0.1:2/?api-key=secret".to_owned(),
+        ]);
+
+        let result = submitter
+            .submit(
+                &signed_transaction(Signature::from([6_u8; 64])),
+                TxSubmissionMode::Direct,
+            )
+            .await;
+
+        assert!(result.is_err());
+        if let Err(error) = result {
+            assert!(
+                error
+                    .to_string()
+                    .starts_with("all 2 submit endpoints failed")
+            );
+        }
+        assert_eq!(
+            endpoint_label("https://rpc.example/?api-key=secret").as_ref(),
+            "rpc.example"
+        );
+    }
+
+    #[tokio::test]
+    async fn fake_submitter_records_submissions() {
+        let signature = Signature::from([4_u8; 64]);
+        let transaction = signed_transaction(signature);
+
+        let submitter = FakeSubmitter::default();
+        let result = submitter.submit(&transaction, TxSubmissionMode::Jito).await;
+        assert!(matches!(result, Ok(sent) if sent == signature));
+        assert_eq!(
+            submitter.submitted(),
+            vec![(signature, TxSubmissionMode::Jito)]
+        );
+
+        let failing = FakeSubmitter::failing("blockhash not found");
+        let failed = failing.submit(&transaction, TxSubmissionMode::Direct).await;
+        assert!(failed.is_err());
+        if let Err(error) = failed {
+            assert_eq!(error.to_string(), "blockhash not found");
+        }
+        assert_eq!(failing.submitted().len(), 1);
+    }
+
+    fn signed_transaction(signature: Signature) -> VersionedTransaction {
+        let payer = Pubkey::new_unique();
+        let mut transaction =
+            VersionedTransaction::from(Transaction::new_unsigned(Message::new(&[], Some(&payer))));
+        transaction.signatures = vec![signature];
+        transaction
+    }
+}
@@ -1,38 +1,68 @@
-use std::{fmt::Write as _, io::IsTerminal, path::PathBuf, sync::Arc};
+use std::{
+    fmt::Write as _,
+    io::{IsTerminal, Read as _},
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
+use age::{DecryptError, secrecy::SecretString};
 use log::LevelFilter;
 use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_sdk::{signature::Keypair, signer::Signer};
-use tokio::{
-    fs::File,
-    io::AsyncReadExt,
-    sync::{mpsc, watch},
+use solana_sdk::{
+    signature::{Keypair, Signature},
+    signer::Signer,
 };
+use tokio::{fs::File, io::AsyncReadExt, sync::watch};
 
 use crate::{
-    adapters::toml_rules::TomlRuleRepository,
+    adapters::{
+        blockhash_cache::BlockhashCache,
+        health_http::{HealthState, spawn_health_server},
+        read_rpc::ReadRpcPool,
+        rpc_retry::with_rpc_timeout,
+        toml_rules::TomlRuleRepository,
+        tx_submitters::{RpcSubmitter, SofTxSubmitter},
+    },
     app::{
-        context::ExecutionContext,
-        errors::{AppError, KeypairLoadError, RulebookLoadError, WalletBalanceError},
-        logging::init_logging,
+        classify::maybe_handle_classify,
+        config_validation::{maybe_handle_print_effective_config, maybe_handle_validate_config},
+        context::{ExecutionContext, rpc_commitment_config},
+        errors::{
+            AppError, HealthEndpointError, KeypairLoadError, RulebookLoadError,
+            StrategyRuntimeError, WalletBalanceError,
+        },
+        logging::{color_disabled_by_env, init_logging, log_writer_alive},
         sof_runtime::SofRuntimeHarness,
         systemd::maybe_handle_service_command,
+        tasks::TaskRegistry,
     },
     domain::{
         settings::RuntimeSettings,
-        value_objects::{SofIngressSource, sol_amount::Lamports},
+        value_objects::{ExecutionMode, SofIngressSource, sol_amount::Lamports},
     },
+    ports::transaction_submitter::TransactionSubmitter,
     slices::{
         config_sync::service::{ConfigSyncService, load_rulebook},
         sniper::{
+            cache::init_addresses,
+            dedup::RecentSet,
+            dedup_store::SignatureStore,
             engine::SniperEngine,
+            ledger::SessionLedger,
+            outcome::SnipeOutcomeLog,
+            paper::PaperPortfolio,
+            queue::{QueueCloseReason, SniperEventQueue},
             replay::{log_replay_report, run_synthetic_replay},
-            telemetry::LatencyTelemetry,
+            rule_stats::RuleStats,
+            telemetry::{LatencyTelemetry, SampleWindowPolicy},
         },
     },
 };
 
-const EVENT_QUEUE_CAPACITY: usize = 4_096;
+const SIGNATURE_STORE_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+/// How long shutdown waits for cancelled background tasks before detaching them.
+const BACKGROUND_TASK_SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
 
 pub async fn run() {
     if let Err(error) = run_inner().await {
@@ -46,8 +76,17 @@ async fn run_inner() -> Result<(), AppError> {
     if maybe_handle_service_command(&args)? {
         return Ok(());
     }
+    if maybe_handle_validate_config(&args)? {
+        return Ok(());
+    }
+    if maybe_handle_print_effective_config(&args)? {
+        return Ok(());
+    }
+    if maybe_handle_classify(&args)? {
+        return Ok(());
+    }
 
-    maybe_print_startup_banner();
+    maybe_print_startup_banner(&args);
 
     init_logging(resolve_level_filter()).await?;
 
@@ -64,13 +103,53 @@ async fn run_inner() -> Result<(), AppError> {
         return Ok(());
     }
 
-    let keypair = Arc::new(load_keypair(&settings.keypair_path).await?);
-    let rpc = Arc::new(RpcClient::new(settings.rpc_url.clone()));
+    init_addresses(&settings.address_overrides)?;
+
+    let tasks = TaskRegistry::new();
+    let health_state = Arc::new(HealthState::default());
+    if let Some(listen_addr) = settings.health_listen_addr {
+        let (bound_addr, health_server) =
+            spawn_health_server(listen_addr, Arc::clone(&health_state), log_writer_alive)
+                .await
+                .map_err(|source| HealthEndpointError::Bind {
+                    addr: listen_addr,
+                    source,
+                })?;
+        tasks.register("health endpoint", health_server);
+        log::info!("Health endpoint listening on {}", bound_addr);
+    }
+
+    let keypair = load_keypair(&settings.keypair_path).await?;
+    if let Some(expected) = settings.expected_pubkey.as_deref() {
+        verify_keypair_pubkey(&keypair, expected)?;
+    }
+    let keypair = Arc::new(keypair);
+    let rpc = Arc::new(RpcClient::new_with_commitment(
+        settings.rpc_url.clone(),
+        rpc_commitment_config(settings.fetch_commitment),
+    ));
+    let read_rpc = Arc::new(ReadRpcPool::new(Arc::clone(&rpc)).with_fallback_urls(
+        &settings.fallback_rpc_urls,
+        rpc_commitment_config(settings.fetch_commitment),
+    ));
 
-    let repository = Arc::new(TomlRuleRepository::new(settings.config_path.clone()));
+    let repository = Arc::new(
+        TomlRuleRepository::new(settings.config_path.clone())
+            .with_override_path(settings.config_override_path.clone()),
+    );
     let initial_rulebook = load_rulebook(repository.as_ref(), true)
         .await
         .map_err(|source| RulebookLoadError::Read { source })?;
+    health_state.mark_rulebook_loaded();
+    if !initial_rulebook.blocked_deployers().is_empty()
+        || !initial_rulebook.allowed_deployers().is_empty()
+    {
+        log::info!(
+            "Deployer filter > {} blocklisted, {} allowlisted",
+            initial_rulebook.blocked_deployers().len(),
+            initial_rulebook.allowed_deployers().len()
+        );
+    }
 
     let (rulebook_tx, rulebook_rx) = watch::channel(Arc::clone(&initial_rulebook));
 
@@ -78,14 +157,28 @@ async fn run_inner() -> Result<(), AppError> {
         Arc::clone(&repository),
         rulebook_tx,
         Arc::clone(&initial_rulebook),
-    );
-    config_sync_service.spawn();
+        Duration::from_secs(settings.config_reload_secs),
+    )
+    .with_watch_paths(if settings.config_watch {
+        repository.source_paths()
+    } else {
+        Vec::new()
+    });
+    tasks.register("config sync", config_sync_service.spawn());
 
-    let balance = rpc
+    let balance_lamports = rpc
         .get_balance(&keypair.pubkey())
         .await
-        .map(|lamports| Lamports::new(lamports).as_sol_string())
         .map_err(|source| WalletBalanceError::Read { source })?;
+    let balance = Lamports::new(balance_lamports).as_sol_string();
+
+    for line in initial_rulebook.unfundable_rule_log_lines(Lamports::new(balance_lamports)) {
+        log::warn!(
+            "{}, but the wallet holds {} SOL; this rule cannot fire until the wallet is funded",
+            line,
+            balance
+        );
+    }
 
     let mint_rules = initial_rulebook.mint_log_lines();
     let deployer_rules = initial_rulebook.deployer_log_lines();
@@ -98,43 +191,310 @@ async fn run_inner() -> Result<(), AppError> {
     );
 
     let telemetry = Arc::new(if settings.telemetry_enabled {
-        LatencyTelemetry::new(settings.latency_sample_capacity, settings.latency_slo_ns)
+        LatencyTelemetry::new(
+            settings.latency_sample_capacity,
+            settings.latency_slo_ns,
+            SampleWindowPolicy::from_max_age_ms(settings.latency_sample_max_age_ms),
+        )
+        .with_slo_alert_repeat_periods(settings.latency_slo_alert_repeat_periods)
     } else {
         LatencyTelemetry::disabled()
     });
-    Arc::clone(&telemetry).spawn_reporter(std::time::Duration::from_secs(
-        settings.latency_report_period_secs,
+    if let Some(reporter) = Arc::clone(&telemetry)
+        .spawn_reporter(Duration::from_secs(settings.latency_report_period_secs))
+    {
+        tasks.register("latency telemetry reporter", reporter);
+    }
+    if let Some(expected) = settings.latency_sample_overrun() {
+        log::warn!(
+            "Latency telemetry > ~{} samples per hop expected each {}s report but sample_capacity is {}; reports will only cover the newest samples",
+            expected,
+            settings.latency_report_period_secs,
+            settings.latency_sample_capacity
+        );
+    }
+
+    let events = Arc::new(SniperEventQueue::new(
+        settings.event_queue_capacity,
+        settings.event_queue_drop_policy,
+        Arc::clone(&telemetry),
     ));
+    let sof_harness =
+        SofRuntimeHarness::build(&settings, Arc::clone(&events), Arc::clone(&health_state)).await?;
+    if settings.telemetry_enabled {
+        tasks.register(
+            "ingress counters reporter",
+            Arc::clone(&sof_harness.ingress_counters)
+                .spawn_reporter(Duration::from_secs(settings.latency_report_period_secs)),
+        );
+    }
 
-    let (events_tx, events_rx) = mpsc::channel(EVENT_QUEUE_CAPACITY);
-    let sof_harness = SofRuntimeHarness::build(&settings, events_tx.clone()).await?;
-
-    let context = Arc::new(ExecutionContext {
-        priority_fees: settings.priority_fees.as_u64(),
-        rpc,
-        keypair,
-        dry_run: settings.dry_run,
-        tx_submission_mode: settings.tx_submission_mode,
-        jito_url: Arc::new(settings.jito_url.clone()),
-        sof_tx_client: sof_harness.sof_tx_client.clone(),
-        sof_tx_plan: sof_harness.sof_tx_plan.clone(),
-        sof_tx_uses_jito: sof_harness.sof_tx_uses_jito,
-        sof_tx_blockhash_adapter: sof_harness.control_plane_adapter.clone(),
-        require_local_blockhash: settings.sof.source == SofIngressSource::PrivateShred,
+    let outcome_log = match settings.snipe_outcome_path.as_ref() {
+        Some(path) => Some(Arc::new(SnipeOutcomeLog::open(PathBuf::from(path)).await?)),
+        None => None,
+    };
+
+    let session_ledger = (settings.session_ledger_report_secs > 0).then(|| {
+        let ledger = Arc::new(SessionLedger::new(balance_lamports));
+        tasks.register(
+            "session ledger reporter",
+            Arc::clone(&ledger)
+                .spawn_reporter(Duration::from_secs(settings.session_ledger_report_secs)),
+        );
+        ledger
     });
 
-    let engine = SniperEngine::new(context, events_rx, rulebook_rx, telemetry);
-    drop(events_tx);
-    let engine_task = tokio::spawn(async move {
-        engine.run().await;
+    let rule_stats = (settings.rule_stats_report_secs > 0).then(|| {
+        let stats = Arc::new(RuleStats::new());
+        tasks.register(
+            "rule stats reporter",
+            Arc::clone(&stats).spawn_reporter(Duration::from_secs(settings.rule_stats_report_secs)),
+        );
+        stats
     });
+
+    let paper_portfolio = (settings.execution_mode() == ExecutionMode::Paper)
+        .then(|| Arc::new(PaperPortfolio::new(balance_lamports)));
+
+    let blockhash_cache = start_blockhash_cache(&settings, &rpc, &tasks).await;
+    let context = Arc::new(
+        ExecutionContext::builder(rpc, keypair)
+            .with_read_rpc(read_rpc)
+            .with_priority_fees(settings.priority_fees.as_u64(), settings.max_priority_fees)
+            .with_max_compute_unit_price(settings.max_compute_unit_price)
+            .with_rpc_call_timeout(
+                (settings.rpc_call_timeout_ms > 0)
+                    .then_some(Duration::from_millis(settings.rpc_call_timeout_ms)),
+            )
+            .with_execution_mode(settings.execution_mode())
+            .with_tx_submission_mode(settings.tx_submission_mode, Some(settings.jito_url.clone()))
+            .with_sof_tx_uses_jito(sof_harness.sof_tx_uses_jito)
+            .with_sof_tx_blockhash_adapter(
+                sof_harness.control_plane_adapter.clone(),
+                settings.sof.source == SofIngressSource::PrivateShred,
+            )
+            .with_blockhash_cache(blockhash_cache)
+            .with_confirmation_commitment(rpc_commitment_config(settings.confirmation_commitment))
+            .with_live_reserves(settings.use_live_reserves)
+            .with_wsol_handling(
+                settings.wsol_close_timing,
+                settings.wsol_wrap_buffer_lamports,
+                settings.reuse_wrapped_sol,
+            )
+            .with_allow_zero_min_amount_out(settings.allow_zero_min_amount_out)
+            .with_slippage_retry_attempts(settings.slippage_retry_attempts)
+            .with_init_liquidity_band(settings.init_liquidity_band)
+            .with_compute_unit_limit(settings.compute_unit_limit)
+            .with_pool_open_wait(
+                (settings.max_pool_open_wait_secs > 0)
+                    .then_some(Duration::from_secs(settings.max_pool_open_wait_secs)),
+                Duration::from_millis(settings.pre_open_offset_ms),
+            )
+            .with_outcome_log(outcome_log)
+            .with_session_ledger(session_ledger.clone())
+            .with_paper_portfolio(paper_portfolio.clone())
+            .with_rule_stats(rule_stats.clone())
+            .build(|context| build_submitter(&settings, &sof_harness, context))?,
+    );
+
+    let signature_store = load_signature_store(&settings, &tasks).await;
+
+    let strategy_runtime = build_strategy_runtime(settings.strategy_worker_threads)?;
+    let mut engine = SniperEngine::new(
+        context,
+        Arc::clone(&events),
+        rulebook_rx,
+        Arc::clone(&telemetry),
+        strategy_runtime
+            .as_ref()
+            .map(|runtime| runtime.handle().clone()),
+        settings.signature_dedup_capacity,
+    )
+    .with_worker_tasks(settings.strategy_worker_tasks)
+    .with_max_candidate_instructions(settings.max_candidate_instructions)
+    .with_snipe_deadline(
+        (settings.snipe_deadline_secs > 0)
+            .then_some(Duration::from_secs(settings.snipe_deadline_secs)),
+    )
+    .with_startup_warmup(Duration::from_secs(settings.startup_warmup_secs));
+    if let Some((_store, recent)) = &signature_store {
+        engine = engine.with_recent_signatures(Arc::clone(recent));
+    }
+    let engine_task = tokio::spawn(engine.run());
     let runtime_result = sof_harness.run().await;
-    if let Err(error) = engine_task.await {
-        log::warn!("sniper engine task join failed: {}", error);
+    health_state.mark_ingress_stopped();
+    events.close_with(if runtime_result.is_ok() {
+        QueueCloseReason::Shutdown
+    } else {
+        QueueCloseReason::IngressFailed
+    });
+    let stop_reason = match engine_task.await {
+        Ok(reason) => Some(reason),
+        Err(error) => {
+            log::warn!("sniper engine task join failed: {}", error);
+            None
+        }
+    };
+    if let Some(strategy_runtime) = strategy_runtime {
+        strategy_runtime.shutdown_background();
+    }
+    let task_report = tasks.shutdown(BACKGROUND_TASK_SHUTDOWN_GRACE).await;
+    log::debug!(
+        "Shutdown > background tasks joined={} detached={}",
+        task_report.joined,
+        task_report.detached
+    );
+    telemetry.final_report();
+    if let Some(ledger) = &session_ledger {
+        ledger.log_report();
+    }
+    if let Some(portfolio) = &paper_portfolio {
+        portfolio.log_report();
+    }
+    if let Some(stats) = &rule_stats {
+        stats.log_report();
+    }
+    if let Some((store, recent)) = &signature_store {
+        save_signature_store(store, recent).await;
     }
     runtime_result?;
 
-    Ok(())
+    match stop_reason {
+        Some(reason) if reason.is_clean() => {
+            log::info!("Sniper engine stopped > reason={}", reason);
+            Ok(())
+        }
+        Some(reason) => Err(AppError::EngineStopped { reason }),
+        None => Ok(()),
+    }
+}
+
+/// Reloads the dedup set saved by a previous run and keeps the file current while running.
+async fn load_signature_store(
+    settings: &RuntimeSettings,
+    tasks: &TaskRegistry,
+) -> Option<(Arc<SignatureStore>, Arc<RecentSet<Signature>>)> {
+    let path = settings.signature_dedup_path.as_ref()?;
+    if settings.signature_dedup_capacity == 0 {
+        log::warn!(
+            "Signature dedup > runtime.signature_dedup_path is ignored while runtime.signature_dedup_capacity = 0"
+        );
+        return None;
+    }
+
+    let store = Arc::new(SignatureStore::new(PathBuf::from(path)));
+    let signatures = match store.load(settings.signature_dedup_capacity).await {
+        Ok(value) => value,
+        Err(error) => {
+            log::warn!(
+                "Signature dedup > failed to load {}, starting empty: {}",
+                store.path().display(),
+                error
+            );
+            Vec::new()
+        }
+    };
+    log::info!(
+        "Signature dedup > loaded {} signatures from {}",
+        signatures.len(),
+        store.path().display()
+    );
+
+    let recent = Arc::new(RecentSet::with_keys(
+        settings.signature_dedup_capacity,
+        signatures,
+    ));
+    tasks.register(
+        "signature dedup flusher",
+        Arc::clone(&store).spawn_flusher(Arc::clone(&recent), SIGNATURE_STORE_FLUSH_INTERVAL),
+    );
+    Some((store, recent))
+}
+
+async fn save_signature_store(store: &SignatureStore, recent: &RecentSet<Signature>) {
+    if let Err(error) = store.save(&recent.snapshot()).await {
+        log::warn!(
+            "Signature dedup > failed to save {}: {}",
+            store.path().display(),
+            error
+        );
+    }
+}
+
+async fn start_blockhash_cache(
+    settings: &RuntimeSettings,
+    rpc: &Arc<RpcClient>,
+    tasks: &TaskRegistry,
+) -> Option<Arc<BlockhashCache>> {
+    if settings.blockhash_refresh_ms == 0 {
+        return None;
+    }
+
+    let cache = Arc::new(BlockhashCache::new(Duration::from_millis(
+        settings.blockhash_max_age_ms,
+    )));
+    let call_timeout = (settings.rpc_call_timeout_ms > 0)
+        .then_some(Duration::from_millis(settings.rpc_call_timeout_ms));
+    if let Err(error) = with_rpc_timeout(call_timeout, cache.refresh(rpc)).await {
+        log::warn!("Blockhash cache > initial refresh failed: {}", error);
+    }
+    tasks.register(
+        "blockhash refresher",
+        Arc::clone(&cache).spawn_refresher(
+            Arc::clone(rpc),
+            Duration::from_millis(settings.blockhash_refresh_ms),
+            call_timeout,
+        ),
+    );
+    Some(cache)
+}
+
+/// Sends through the SOF-TX plan when one is configured, otherwise through the context's
+/// RPC and its validated `jito_url`.
+fn build_submitter(
+    settings: &RuntimeSettings,
+    sof_harness: &SofRuntimeHarness,
+    context: &ExecutionContext,
+) -> Arc<dyn TransactionSubmitter> {
+    if let (Some(client), Some(plan)) = (&sof_harness.sof_tx_client, &sof_harness.sof_tx_plan) {
+        return Arc::new(SofTxSubmitter::new(Arc::clone(client), plan.clone()));
+    }
+
+    Arc::new(
+        RpcSubmitter::new(
+            Arc::clone(&context.rpc),
+            context
+                .jito_url
+                .clone()
+                .unwrap_or_else(|| context.rpc.url()),
+            settings.skip_preflight,
+            settings.send_max_retries,
+        )
+        .with_fan_out_urls(&settings.submit_rpc_urls)
+        .with_call_timeout(
+            (settings.rpc_call_timeout_ms > 0)
+                .then_some(Duration::from_millis(settings.rpc_call_timeout_ms)),
+        ),
+    )
+}
+
+fn build_strategy_runtime(
+    worker_threads: Option<usize>,
+) -> Result<Option<tokio::runtime::Runtime>, StrategyRuntimeError> {
+    let Some(worker_threads) = worker_threads else {
+        return Ok(None);
+    };
+
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(worker_threads)
+        .thread_name("slotstrike-strategy")
+        .enable_all()
+        .build()
+        .map(Some)
+        .map_err(|source| StrategyRuntimeError::Build {
+            worker_threads,
+            source,
+        })
 }
 
 fn log_runtime_settings(
@@ -155,7 +515,7 @@ fn log_runtime_settings(
 \t\t{}\
 \n\tDEPLOYERS:\
 \t\t{}\
-\n\tDRY_RUN: {}\
+\n\tEXECUTION_MODE: {}\
 \n\tTX_SUBMISSION_MODE: {}\
 \n\tJITO_URL: {}\
 \n\tRPC_URL: {}\
@@ -172,7 +532,7 @@ fn log_runtime_settings(
         settings.priority_fees.as_u64(),
         mints_string,
         deployers_string,
-        settings.dry_run,
+        settings.execution_mode(),
         settings.tx_submission_mode.as_str(),
         settings.jito_url,
         settings.rpc_url,
@@ -201,6 +561,12 @@ fn format_sof_tx_routes(settings: &RuntimeSettings) -> String {
         .join(", ")
 }
 
+/// Passphrase for an age-encrypted `runtime.keypair_path`; read only when the file is encrypted.
+const KEYPAIR_PASSPHRASE_ENV: &str = "SLOTSTRIKE_KEYPAIR_PASSPHRASE";
+
+/// First line of every binary age file.
+const AGE_MAGIC: &[u8] = b"age-encryption.org/v1\n";
+
 async fn load_keypair(path: &str) -> Result<Keypair, KeypairLoadError> {
     let keypair_path = PathBuf::from(path);
     let mut keypair_file = File::open(path)
@@ -210,16 +576,48 @@ async fn load_keypair(path: &str) -> Result<Keypair, KeypairLoadError> {
             source,
         })?;
 
-    let mut contents = String::new();
+    let mut contents = Vec::new();
     keypair_file
-        .read_to_string(&mut contents)
+        .read_to_end(&mut contents)
         .await
         .map_err(|source| KeypairLoadError::Read {
             path: keypair_path.clone(),
             source,
         })?;
 
-    let keypair_bytes = serde_json::from_str::<Vec<u8>>(&contents).map_err(|source| {
+    let contents = if contents.starts_with(AGE_MAGIC) {
+        let passphrase = std::env::var(KEYPAIR_PASSPHRASE_ENV)
+            .ok()
+            .filter(|passphrase| !passphrase.is_empty())
+            .ok_or_else(|| KeypairLoadError::MissingPassphrase {
+                path: keypair_path.clone(),
+                env: KEYPAIR_PASSPHRASE_ENV,
+            })?;
+        decrypt_keypair(&contents, SecretString::from(passphrase)).map_err(|source| {
+            KeypairLoadError::Decrypt {
+                path: keypair_path.clone(),
+                source,
+            }
+        })?
+    } else {
+        contents
+    };
+
+    parse_keypair_json(&contents, keypair_path)
+}
+
+/// Decrypts a passphrase-encrypted (scrypt) age file.
+fn decrypt_keypair(encrypted: &[u8], passphrase: SecretString) -> Result<Vec<u8>, DecryptError> {
+    let identity = age::scrypt::Identity::new(passphrase);
+    let mut reader = age::Decryptor::new(encrypted)?
+        .decrypt(std::iter::once(&identity as &dyn age::Identity))?;
+    let mut decrypted = Vec::new();
+    reader.read_to_end(&mut decrypted)?;
+    Ok(decrypted)
+}
+
+fn parse_keypair_json(contents: &[u8], keypair_path: PathBuf) -> Result<Keypair, KeypairLoadError> {
+    let keypair_bytes = serde_json::from_slice::<Vec<u8>>(contents).map_err(|source| {
         KeypairLoadError::ParseJson {
             path: keypair_path.clone(),
             source,
@@ -232,6 +630,18 @@ async fn load_keypair(path: &str) -> Result<Keypair, KeypairLoadError> {
     })
 }
 
+fn verify_keypair_pubkey(keypair: &Keypair, expected: &str) -> Result<(), KeypairLoadError> {
+    let found = keypair.pubkey().to_string();
+    if found == expected {
+        return Ok(());
+    }
+
+    Err(KeypairLoadError::PubkeyMismatch {
+        expected: expected.to_owned(),
+        found,
+    })
+}
+
 fn resolve_level_filter() -> LevelFilter {
     match std::env::var("RUST_LOG")
         .unwrap_or_else(|_| "info".to_owned())
@@ -263,20 +673,29 @@ const STARTUP_BANNER: &str = r#"
 ███████║███████╗╚██████╔╝   ██║   ███████║   ██║   ██║  ██║██║██║  ██╗███████╗
 ╚══════╝╚══════╝ ╚═════╝    ╚═╝   ╚══════╝   ╚═╝   ╚═╝  ╚═╝╚═╝╚═╝  ╚═╝╚══════╝"#;
 
-fn maybe_print_startup_banner() {
-    if !should_render_local_banner() {
+fn maybe_print_startup_banner(args: &[String]) {
+    let banner_disabled =
+        args.iter().any(|arg| arg == "--no-banner") || env_flag_enabled("SLOTSTRIKE_NO_BANNER");
+    if !should_render_local_banner_with(std::io::stdout().is_terminal(), banner_disabled) {
         return;
     }
 
-    println!("{}", render_blue_purple_gradient(STARTUP_BANNER));
+    if color_disabled_by_env() {
+        println!("{}", STARTUP_BANNER);
+    } else {
+        println!("{}", render_blue_purple_gradient(STARTUP_BANNER));
+    }
 }
 
-fn should_render_local_banner() -> bool {
-    should_render_local_banner_with(std::io::stdout().is_terminal())
+const fn should_render_local_banner_with(stdout_is_terminal: bool, banner_disabled: bool) -> bool {
+    stdout_is_terminal && !banner_disabled
 }
 
-const fn should_render_local_banner_with(stdout_is_terminal: bool) -> bool {
-    stdout_is_terminal
+fn env_flag_enabled(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|value| {
+        let trimmed = value.trim();
+        !trimmed.is_empty() && trimmed != "0" && !trimmed.eq_ignore_ascii_case("false")
+    })
 }
 
 fn render_blue_purple_gradient(text: &str) -> String {
@@ -329,16 +748,30 @@ fn gradient_channel(start: u8, end: u8, index: usize, max_index: usize) -> u8 {
 
 #[cfg(test)]
 mod tests {
-    use super::{gradient_channel, render_blue_purple_gradient, should_render_local_banner_with};
+    use std::path::PathBuf;
+
+    use age::secrecy::SecretString;
+    use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+    use super::{
+        decrypt_keypair, gradient_channel, parse_keypair_json, render_blue_purple_gradient,
+        should_render_local_banner_with, verify_keypair_pubkey,
+    };
+    use crate::app::errors::KeypairLoadError;
 
     #[test]
     fn banner_is_disabled_when_stdout_is_not_terminal() {
-        assert!(!should_render_local_banner_with(false));
+        assert!(!should_render_local_banner_with(false, false));
     }
 
     #[test]
     fn banner_is_enabled_for_local_terminal_runs() {
-        assert!(should_render_local_banner_with(true));
+        assert!(should_render_local_banner_with(true, false));
+    }
+
+    #[test]
+    fn banner_can_be_disabled_explicitly() {
+        assert!(!should_render_local_banner_with(true, true));
     }
 
     #[test]
@@ -347,6 +780,41 @@ mod tests {
         assert_eq!(value, 255);
     }
 
+    #[test]
+    fn keypair_must_match_expected_pubkey() {
+        let keypair = Keypair::new();
+        assert!(verify_keypair_pubkey(&keypair, &keypair.pubkey().to_string()).is_ok());
+
+        let other = Pubkey::new_unique().to_string();
+        let result = verify_keypair_pubkey(&keypair, &other);
+        assert!(matches!(
+            result,
+            Err(KeypairLoadError::PubkeyMismatch { ref expected, ref found })
+                if *expected == other && *found == keypair.pubkey().to_string()
+        ));
+    }
+
+    #[test]
+    fn decrypts_an_age_encrypted_keypair_before_parsing() {
+        let encrypted = include_bytes!("../../tests/fixtures/encrypted_keypair.age");
+        assert!(encrypted.starts_with(super::AGE_MAGIC));
+
+        let decrypted = decrypt_keypair(
+            encrypted,
+            SecretString::from("correct horse battery staple".to_owned()),
+        );
+        assert!(decrypted.is_ok());
+        if let Ok(decrypted) = decrypted {
+            let keypair = parse_keypair_json(&decrypted, PathBuf::from("encrypted_keypair.age"));
+            assert_eq!(
+                keypair.map(|keypair| keypair.pubkey().to_string()).ok(),
+                Some("GmaDrppBC7P5ARKV8g3djiwP89vz1jLK23V2GBjuAEGB".to_owned())
+            );
+        }
+
+        assert!(decrypt_keypair(encrypted, SecretString::from("wrong".to_owned())).is_err());
+    }
+
     #[test]
     fn gradient_renderer_preserves_whitespace() {
         let rendered = render_blue_purple_gradient("A B");
@@ -0,0 +1,223 @@
+use std::{
+    fmt::{Display, Formatter},
+    io::{self, Read as _},
+    path::PathBuf,
+};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
+use solana_sdk::{message::VersionedMessage, pubkey::Pubkey, transaction::VersionedTransaction};
+use thiserror::Error;
+
+use crate::{
+    adapters::raydium::{
+        RAYDIUM_STANDARD_AMM_PROGRAM_ID, classify_raydium_creation_message,
+        is_cpmm_creation_instruction, is_openbook_creation_instruction, raydium_v4_program_pubkey,
+    },
+    domain::events::RaydiumCandidateKind,
+};
+
+const STDIN_INPUT: &str = "-";
+
+#[derive(Debug, Error)]
+pub enum ClassifyError {
+    #[error("--classify needs a sample file path, or - for stdin")]
+    MissingInput,
+    #[error("failed to read classify samples from {path}")]
+    ReadInput {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Which ingress checks a sample transaction passes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SampleClassification {
+    pub prefilter: bool,
+    pub cpmm_creation: bool,
+    pub openbook_creation: bool,
+    pub strategy: Option<RaydiumCandidateKind>,
+}
+
+impl Display for SampleClassification {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let strategy = self.strategy.map_or("none", RaydiumCandidateKind::label);
+        write!(
+            f,
+            "prefilter: {} | cpmm creation: {} | openbook creation: {} | strategy: {}",
+            pass_or_fail(self.prefilter),
+            yes_or_no(self.cpmm_creation),
+            yes_or_no(self.openbook_creation),
+            strategy,
+        )
+    }
+}
+
+/// Classifies sample transactions offline and exits; samples come from the file after
+/// `--classify`, or stdin for `-`.
+pub fn maybe_handle_classify(args: &[String]) -> Result<bool, ClassifyError> {
+    let Some(index) = args.iter().position(|arg| arg == "--classify") else {
+        return Ok(false);
+    };
+    let input_path = args
+        .get(index.saturating_add(1))
+        .ok_or(ClassifyError::MissingInput)?;
+    let input = read_samples(input_path)?;
+
+    let cpmm_program = Pubkey::from_str_const(RAYDIUM_STANDARD_AMM_PROGRAM_ID);
+    let openbook_program = raydium_v4_program_pubkey();
+    for (line_index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let rendered = match decode_sample(line) {
+            Ok(message) => classify_sample(&message, cpmm_program, openbook_program).to_string(),
+            Err(error) => format!("unreadable ({error})"),
+        };
+        println!("line {}: {}", line_index.saturating_add(1), rendered);
+    }
+
+    Ok(true)
+}
+
+fn read_samples(input_path: &str) -> Result<String, ClassifyError> {
+    let mut input = String::new();
+    let read = if input_path == STDIN_INPUT {
+        io::stdin().read_to_string(&mut input).map(|_bytes| input)
+    } else {
+        std::fs::read_to_string(input_path)
+    };
+
+    read.map_err(|source| ClassifyError::ReadInput {
+        path: PathBuf::from(input_path),
+        source,
+    })
+}
+
+/// Accepts the base64 wire transaction from the debug transaction dump, optionally still
+/// prefixed by the rest of its log line. The dump's JSON message line keeps the wire
+/// short-vec framing and is not read back; feed the `Signed transaction (base64)` line.
+fn decode_sample(line: &str) -> Result<VersionedMessage, String> {
+    let encoded = line.split_whitespace().last().unwrap_or_default();
+    let bytes = BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|error| format!("invalid base64: {error}"))?;
+    bincode::deserialize::<VersionedTransaction>(&bytes)
+        .map(|transaction| transaction.message)
+        .map_err(|error| format!("invalid transaction: {error}"))
+}
+
+/// Mirrors the SOF plugin: the Raydium account prefilter, then the creation-instruction
+/// classifiers. Only static account keys are checked, so lookup-table accounts are not seen.
+fn classify_sample(
+    message: &VersionedMessage,
+    cpmm_program: Pubkey,
+    openbook_program: Pubkey,
+) -> SampleClassification {
+    let account_keys = message.static_account_keys();
+    let instructions = message.instructions();
+    let has_instruction = |program: Pubkey, is_creation: fn(&[u8]) -> bool| {
+        instructions.iter().any(|instruction| {
+            account_keys.get(usize::from(instruction.program_id_index)) == Some(&program)
+                && is_creation(&instruction.data)
+        })
+    };
+
+    SampleClassification {
+        prefilter: account_keys
+            .iter()
+            .any(|key| *key == cpmm_program || *key == openbook_program),
+        cpmm_creation: has_instruction(cpmm_program, is_cpmm_creation_instruction),
+        openbook_creation: has_instruction(openbook_program, is_openbook_creation_instruction),
+        strategy: classify_raydium_creation_message(message, cpmm_program, openbook_program),
+    }
+}
+
+const fn pass_or_fail(value: bool) -> &'static str {
+    if value { "pass" } else { "fail" }
+}
+
+const fn yes_or_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
+    use solana_sdk::{
+        instruction::Instruction,
+        message::{Message, VersionedMessage},
+        pubkey::Pubkey,
+        signature::Signature,
+        transaction::VersionedTransaction,
+    };
+
+    use super::{classify_sample, decode_sample};
+    use crate::{
+        adapters::raydium::{RAYDIUM_V4_SWAP_BASE_IN_TAG, STANDARD_AMM_INITIALIZE},
+        domain::events::RaydiumCandidateKind,
+    };
+
+    fn message_calling(program: Pubkey, data: &[u8]) -> VersionedMessage {
+        let payer = Pubkey::new_unique();
+        VersionedMessage::Legacy(Message::new(
+            &[Instruction::new_with_bytes(program, data, Vec::new())],
+            Some(&payer),
+        ))
+    }
+
+    #[test]
+    fn classifies_a_base64_cpmm_creation_from_a_log_line() {
+        let cpmm_program = Pubkey::new_unique();
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: message_calling(cpmm_program, &STANDARD_AMM_INITIALIZE),
+        };
+        let encoded = bincode::serialize(&transaction)
+            .map(|bytes| BASE64_STANDARD.encode(bytes))
+            .unwrap_or_default();
+        let line = format!("CPMM > mint > Signed transaction (base64): {encoded}");
+
+        let message = decode_sample(&line);
+        assert!(message.is_ok());
+        if let Ok(message) = message {
+            let classification = classify_sample(&message, cpmm_program, Pubkey::new_unique());
+            assert!(classification.prefilter);
+            assert!(classification.cpmm_creation);
+            assert!(!classification.openbook_creation);
+            assert_eq!(classification.strategy, Some(RaydiumCandidateKind::Cpmm));
+        }
+    }
+
+    #[test]
+    fn openbook_swap_passes_the_prefilter_but_matches_no_strategy() {
+        let openbook_program = Pubkey::new_unique();
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: message_calling(openbook_program, &[RAYDIUM_V4_SWAP_BASE_IN_TAG]),
+        };
+        let encoded = bincode::serialize(&transaction)
+            .map(|bytes| BASE64_STANDARD.encode(bytes))
+            .unwrap_or_default();
+
+        let message = decode_sample(&encoded);
+        assert!(message.is_ok());
+        if let Ok(message) = message {
+            let classification = classify_sample(&message, Pubkey::new_unique(), openbook_program);
+            assert!(classification.prefilter);
+            assert!(!classification.openbook_creation);
+            assert_eq!(classification.strategy, None);
+            assert_eq!(
+                classification.to_string(),
+                "prefilter: pass | cpmm creation: no | openbook creation: no | strategy: none"
+            );
+        }
+
+        let unrelated = message_calling(Pubkey::new_unique(), &STANDARD_AMM_INITIALIZE);
+        assert!(!classify_sample(&unrelated, Pubkey::new_unique(), openbook_program).prefilter);
+        assert!(decode_sample("not-a-transaction").is_err());
+        let json = serde_json::to_string(&transaction.message).unwrap_or_default();
+        assert!(decode_sample(&format!("Transaction message: {json}")).is_err());
+    }
+}
@@ -1,30 +1,322 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use sof_tx::{
-    RecentBlockhashProvider, SubmitPlan, TxSubmitClient, adapters::PluginHostTxProviderAdapter,
-};
+use sof_tx::{RecentBlockhashProvider, adapters::PluginHostTxProviderAdapter};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_commitment_config::{CommitmentConfig, CommitmentLevel};
 use solana_sdk::{hash::Hash, signature::Keypair};
-use tokio::sync::Mutex;
+use thiserror::Error;
 
-use crate::domain::value_objects::TxSubmissionMode;
+use crate::{
+    adapters::{
+        blockhash_cache::BlockhashCache, read_rpc::ReadRpcPool, rpc_retry::with_rpc_timeout,
+        tx_submitters::FakeSubmitter,
+    },
+    domain::value_objects::{
+        ExecutionMode, PriorityFeesMicrolamports, RuleFeeMultiplier, SofCommitmentLevel,
+        TxSubmissionMode, WsolCloseTiming, sol_amount::InitLiquidityBand,
+    },
+    ports::transaction_submitter::TransactionSubmitter,
+    slices::sniper::{
+        ledger::SessionLedger, outcome::SnipeOutcomeLog, paper::PaperPortfolio,
+        rule_stats::RuleStats,
+    },
+};
 
 #[derive(Clone)]
 pub struct ExecutionContext {
     pub priority_fees: u64,
+    pub max_priority_fees: u64,
+    /// Hard ceiling on the signed compute unit price, whatever fee adjustment produced it.
+    pub max_compute_unit_price: u64,
     pub rpc: Arc<RpcClient>,
+    /// Reads on the strategy fetch paths; fails over across `runtime.fallback_rpc_urls`.
+    pub read_rpc: Arc<ReadRpcPool>,
+    pub rpc_call_timeout: Option<Duration>,
     pub keypair: Arc<Keypair>,
-    pub dry_run: bool,
+    pub execution_mode: ExecutionMode,
     pub tx_submission_mode: TxSubmissionMode,
-    pub jito_url: Arc<String>,
-    pub sof_tx_client: Option<Arc<Mutex<TxSubmitClient>>>,
-    pub sof_tx_plan: Option<SubmitPlan>,
+    /// Block engine URL, checked by `ExecutionContextBuilder::build` before the submitter is
+    /// built from it.
+    pub jito_url: Option<String>,
+    pub submitter: Arc<dyn TransactionSubmitter>,
     pub sof_tx_uses_jito: bool,
     pub sof_tx_blockhash_adapter: Option<Arc<PluginHostTxProviderAdapter>>,
     pub require_local_blockhash: bool,
+    pub blockhash_cache: Option<Arc<BlockhashCache>>,
+    pub confirmation_commitment: CommitmentConfig,
+    pub use_live_reserves: bool,
+    pub wsol_close_timing: WsolCloseTiming,
+    pub wsol_wrap_buffer_lamports: u64,
+    pub reuse_wrapped_sol: bool,
+    pub allow_zero_min_amount_out: bool,
+    /// Resends after an on-chain slippage failure, widening toward the rule's `max_slippage_pct`.
+    pub slippage_retry_attempts: u8,
+    pub init_liquidity_band: InitLiquidityBand,
+    pub compute_unit_limit: Option<u32>,
+    pub max_pool_open_wait: Option<Duration>,
+    pub pre_open_offset: Duration,
+    pub outcome_log: Option<Arc<SnipeOutcomeLog>>,
+    pub session_ledger: Option<Arc<SessionLedger>>,
+    pub paper_portfolio: Option<Arc<PaperPortfolio>>,
+    pub rule_stats: Option<Arc<RuleStats>>,
+}
+
+/// Invariants `ExecutionContextBuilder::build` rejects.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum ExecutionContextError {
+    #[error("tx_submission_mode is jito but no jito_url was provided")]
+    MissingJitoUrl,
+    #[error("priority_fees {priority_fees} exceeds max_priority_fees {max_priority_fees}")]
+    PriorityFeesAboveCeiling {
+        priority_fees: u64,
+        max_priority_fees: u64,
+    },
+    #[error("compute_unit_limit must be greater than 0")]
+    ZeroComputeUnitLimit,
+    #[error("rpc_call_timeout must be greater than 0")]
+    ZeroRpcCallTimeout,
+}
+
+/// Validated construction of an `ExecutionContext`: call sites name only the settings they
+/// change from the `ExecutionContext::new` defaults.
+pub struct ExecutionContextBuilder {
+    context: ExecutionContext,
+}
+
+impl ExecutionContextBuilder {
+    #[must_use]
+    pub const fn with_priority_fees(mut self, priority_fees: u64, max_priority_fees: u64) -> Self {
+        self.context.priority_fees = priority_fees;
+        self.context.max_priority_fees = max_priority_fees;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_compute_unit_price(mut self, max_compute_unit_price: u64) -> Self {
+        self.context.max_compute_unit_price = max_compute_unit_price;
+        self
+    }
+
+    #[must_use]
+    pub fn with_read_rpc(mut self, read_rpc: Arc<ReadRpcPool>) -> Self {
+        self.context.read_rpc = read_rpc;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_rpc_call_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.context.rpc_call_timeout = timeout;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_execution_mode(mut self, execution_mode: ExecutionMode) -> Self {
+        self.context.execution_mode = execution_mode;
+        self
+    }
+
+    /// `jito_url` is where the submitter sends bundles; Jito mode requires one.
+    #[must_use]
+    pub fn with_tx_submission_mode(
+        mut self,
+        tx_submission_mode: TxSubmissionMode,
+        jito_url: Option<String>,
+    ) -> Self {
+        self.context.tx_submission_mode = tx_submission_mode;
+        self.context.jito_url = jito_url;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_sof_tx_uses_jito(mut self, sof_tx_uses_jito: bool) -> Self {
+        self.context.sof_tx_uses_jito = sof_tx_uses_jito;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sof_tx_blockhash_adapter(
+        mut self,
+        adapter: Option<Arc<PluginHostTxProviderAdapter>>,
+        require_local_blockhash: bool,
+    ) -> Self {
+        self.context.sof_tx_blockhash_adapter = adapter;
+        self.context.require_local_blockhash = require_local_blockhash;
+        self
+    }
+
+    #[must_use]
+    pub fn with_blockhash_cache(mut self, cache: Option<Arc<BlockhashCache>>) -> Self {
+        self.context.blockhash_cache = cache;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_confirmation_commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.context.confirmation_commitment = commitment;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_live_reserves(mut self, use_live_reserves: bool) -> Self {
+        self.context.use_live_reserves = use_live_reserves;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_wsol_handling(
+        mut self,
+        close_timing: WsolCloseTiming,
+        wrap_buffer_lamports: u64,
+        reuse_wrapped_sol: bool,
+    ) -> Self {
+        self.context.wsol_close_timing = close_timing;
+        self.context.wsol_wrap_buffer_lamports = wrap_buffer_lamports;
+        self.context.reuse_wrapped_sol = reuse_wrapped_sol;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_allow_zero_min_amount_out(mut self, allow: bool) -> Self {
+        self.context.allow_zero_min_amount_out = allow;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_slippage_retry_attempts(mut self, attempts: u8) -> Self {
+        self.context.slippage_retry_attempts = attempts;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_init_liquidity_band(mut self, band: InitLiquidityBand) -> Self {
+        self.context.init_liquidity_band = band;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_compute_unit_limit(mut self, limit: Option<u32>) -> Self {
+        self.context.compute_unit_limit = limit;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_pool_open_wait(
+        mut self,
+        max_wait: Option<Duration>,
+        pre_open_offset: Duration,
+    ) -> Self {
+        self.context.max_pool_open_wait = max_wait;
+        self.context.pre_open_offset = pre_open_offset;
+        self
+    }
+
+    #[must_use]
+    pub fn with_outcome_log(mut self, outcome_log: Option<Arc<SnipeOutcomeLog>>) -> Self {
+        self.context.outcome_log = outcome_log;
+        self
+    }
+
+    #[must_use]
+    pub fn with_session_ledger(mut self, session_ledger: Option<Arc<SessionLedger>>) -> Self {
+        self.context.session_ledger = session_ledger;
+        self
+    }
+
+    #[must_use]
+    pub fn with_paper_portfolio(mut self, paper_portfolio: Option<Arc<PaperPortfolio>>) -> Self {
+        self.context.paper_portfolio = paper_portfolio;
+        self
+    }
+
+    #[must_use]
+    pub fn with_rule_stats(mut self, rule_stats: Option<Arc<RuleStats>>) -> Self {
+        self.context.rule_stats = rule_stats;
+        self
+    }
+
+    /// Checks the configured combination, then builds the submitter from the validated
+    /// context, so it sends to the `jito_url` that was checked.
+    pub fn build<F>(self, submitter: F) -> Result<ExecutionContext, ExecutionContextError>
+    where
+        F: FnOnce(&ExecutionContext) -> Arc<dyn TransactionSubmitter>,
+    {
+        let Self { mut context } = self;
+        if context.tx_submission_mode == TxSubmissionMode::Jito
+            && context
+                .jito_url
+                .as_deref()
+                .is_none_or(|url| url.trim().is_empty())
+        {
+            return Err(ExecutionContextError::MissingJitoUrl);
+        }
+        if context.priority_fees > context.max_priority_fees {
+            return Err(ExecutionContextError::PriorityFeesAboveCeiling {
+                priority_fees: context.priority_fees,
+                max_priority_fees: context.max_priority_fees,
+            });
+        }
+        if context.compute_unit_limit == Some(0) {
+            return Err(ExecutionContextError::ZeroComputeUnitLimit);
+        }
+        if context.rpc_call_timeout == Some(Duration::ZERO) {
+            return Err(ExecutionContextError::ZeroRpcCallTimeout);
+        }
+
+        context.submitter = submitter(&context);
+        Ok(context)
+    }
 }
 
 impl ExecutionContext {
+    /// A dry-run context that submits directly and reads through `rpc` alone. These defaults
+    /// always pass `ExecutionContextBuilder::build`.
+    pub fn new(
+        rpc: Arc<RpcClient>,
+        keypair: Arc<Keypair>,
+        submitter: Arc<dyn TransactionSubmitter>,
+    ) -> Self {
+        Self {
+            priority_fees: 0,
+            max_priority_fees: 0,
+            max_compute_unit_price: PriorityFeesMicrolamports::DEFAULT_CEILING,
+            read_rpc: Arc::new(ReadRpcPool::new(Arc::clone(&rpc))),
+            rpc,
+            rpc_call_timeout: None,
+            keypair,
+            execution_mode: ExecutionMode::DryRun,
+            tx_submission_mode: TxSubmissionMode::Direct,
+            jito_url: None,
+            submitter,
+            sof_tx_uses_jito: false,
+            sof_tx_blockhash_adapter: None,
+            require_local_blockhash: false,
+            blockhash_cache: None,
+            confirmation_commitment: CommitmentConfig::confirmed(),
+            use_live_reserves: false,
+            wsol_close_timing: WsolCloseTiming::Inline,
+            wsol_wrap_buffer_lamports: 0,
+            reuse_wrapped_sol: false,
+            allow_zero_min_amount_out: false,
+            slippage_retry_attempts: 0,
+            init_liquidity_band: InitLiquidityBand::default(),
+            compute_unit_limit: None,
+            max_pool_open_wait: None,
+            pre_open_offset: Duration::ZERO,
+            outcome_log: None,
+            session_ledger: None,
+            paper_portfolio: None,
+            rule_stats: None,
+        }
+    }
+
+    /// Starts from the `new` defaults; `build` checks the configured combination and
+    /// supplies the submitter, which stays a `FakeSubmitter` until then.
+    pub fn builder(rpc: Arc<RpcClient>, keypair: Arc<Keypair>) -> ExecutionContextBuilder {
+        ExecutionContextBuilder {
+            context: Self::new(rpc, keypair, Arc::new(FakeSubmitter::default())),
+        }
+    }
+
     pub async fn latest_swap_blockhash(&self) -> Result<Hash, String> {
         if let Some(adapter) = &self.sof_tx_blockhash_adapter {
             let blockhash = adapter.latest_blockhash();
@@ -36,16 +328,67 @@ impl ExecutionContext {
             }
         }
 
-        self.rpc
-            .get_latest_blockhash()
+        if let Some(cache) = &self.blockhash_cache {
+            if let Some(entry) = cache.latest() {
+                return Ok(entry.blockhash);
+            }
+
+            return with_rpc_timeout(self.rpc_call_timeout, cache.refresh(&self.rpc))
+                .await
+                .map(|entry| entry.blockhash)
+                .map_err(|error| format!("failed to fetch blockhash from RPC: {error}"));
+        }
+
+        with_rpc_timeout(self.rpc_call_timeout, self.rpc.get_latest_blockhash())
             .await
             .map_err(|error| format!("failed to fetch blockhash from RPC: {error}"))
     }
+
+    /// Compute unit price for a rule, scaled by its fee multiplier. `build_swap_transaction`
+    /// clamps the result to `max_compute_unit_price` when signing.
+    #[inline(always)]
+    pub const fn rule_priority_fees(&self, fee_multiplier: RuleFeeMultiplier) -> u64 {
+        fee_multiplier.apply(self.priority_fees)
+    }
+
+    /// Only Jito submission, directly or through a SOF-TX Jito route, carries a tip transfer.
+    #[inline(always)]
+    pub fn pays_jito_tip(&self) -> bool {
+        self.sof_tx_uses_jito || self.tx_submission_mode == TxSubmissionMode::Jito
+    }
+
+    /// The tip a swap actually pays: the rule's tip when [`Self::pays_jito_tip`], else zero.
+    #[inline(always)]
+    pub fn paid_jito_tip(&self, jito_tip_lamports: u64) -> u64 {
+        if self.pays_jito_tip() {
+            jito_tip_lamports
+        } else {
+            0
+        }
+    }
+
+    #[inline(always)]
+    pub const fn wsol_wrap_lamports(&self, swap_lamports: u64) -> u64 {
+        swap_lamports.saturating_add(self.wsol_wrap_buffer_lamports)
+    }
+}
+
+pub const fn rpc_commitment_config(level: SofCommitmentLevel) -> CommitmentConfig {
+    let commitment = match level {
+        SofCommitmentLevel::Processed => CommitmentLevel::Processed,
+        SofCommitmentLevel::Confirmed => CommitmentLevel::Confirmed,
+        SofCommitmentLevel::Finalized => CommitmentLevel::Finalized,
+    };
+
+    CommitmentConfig { commitment }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
 
     use sof::framework::{ObservedRecentBlockhashEvent, ObserverPlugin};
     use sof_tx::adapters::PluginHostTxProviderAdapter;
@@ -56,8 +399,15 @@ mod tests {
         net::TcpListener,
     };
 
-    use super::ExecutionContext;
-    use crate::domain::value_objects::TxSubmissionMode;
+    use super::{ExecutionContext, ExecutionContextBuilder, ExecutionContextError};
+    use crate::{
+        adapters::{
+            blockhash_cache::{BlockhashCache, CachedBlockhash},
+            tx_submitters::FakeSubmitter,
+        },
+        domain::value_objects::{ExecutionMode, RuleFeeMultiplier, TxSubmissionMode},
+        ports::transaction_submitter::TransactionSubmitter,
+    };
 
     #[tokio::test]
     async fn latest_swap_blockhash_prefers_sof_adapter_when_available() {
@@ -127,24 +477,161 @@ mod tests {
         assert!(server_result.is_ok());
     }
 
+    #[tokio::test]
+    async fn latest_swap_blockhash_serves_fresh_cache_without_rpc() {
+        let expected = Hash::new_from_array([5_u8; 32]);
+        let cache = Arc::new(BlockhashCache::new(Duration::from_secs(10)));
+        cache.store(CachedBlockhash {
+            blockhash: expected,
+            slot: 42,
+            last_valid_block_height: 192,
+            fetched_at: Instant::now(),
+        });
+        let mut context = execution_context(
+            Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())),
+            None,
+            false,
+        );
+        context.blockhash_cache = Some(cache);
+
+        let blockhash = context.latest_swap_blockhash().await;
+
+        assert_eq!(blockhash, Ok(expected));
+    }
+
+    #[test]
+    fn pays_no_jito_tip_outside_jito_submission() {
+        let mut context = execution_context(
+            Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())),
+            None,
+            false,
+        );
+        context.tx_submission_mode = TxSubmissionMode::Direct;
+        assert!(!context.pays_jito_tip());
+        assert_eq!(context.paid_jito_tip(1_000_000), 0);
+
+        context.sof_tx_uses_jito = true;
+        assert_eq!(context.paid_jito_tip(1_000_000), 1_000_000);
+
+        context.sof_tx_uses_jito = false;
+        context.tx_submission_mode = TxSubmissionMode::Jito;
+        assert_eq!(context.paid_jito_tip(1_000_000), 1_000_000);
+    }
+
+    #[test]
+    fn rule_priority_fees_scale_with_the_fee_multiplier() {
+        let mut context = execution_context(
+            Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())),
+            None,
+            false,
+        );
+        context.priority_fees = 1_000;
+        context.max_priority_fees = 2_500;
+        context.max_compute_unit_price = 2_500;
+
+        assert_eq!(context.rule_priority_fees(RuleFeeMultiplier::ONE), 1_000);
+        assert_eq!(
+            RuleFeeMultiplier::from_decimal_str("2").map(|value| context.rule_priority_fees(value)),
+            Ok(2_000)
+        );
+        assert_eq!(
+            RuleFeeMultiplier::from_decimal_str("3.5")
+                .map(|value| context.rule_priority_fees(value)),
+            Ok(3_500)
+        );
+    }
+
+    #[test]
+    fn builder_requires_a_jito_url_in_jito_mode() {
+        assert_eq!(
+            builder()
+                .with_tx_submission_mode(TxSubmissionMode::Jito, None)
+                .build(fake_submitter)
+                .err(),
+            Some(ExecutionContextError::MissingJitoUrl)
+        );
+        assert_eq!(
+            builder()
+                .with_tx_submission_mode(TxSubmissionMode::Jito, Some(" ".to_owned()))
+                .build(fake_submitter)
+                .err(),
+            Some(ExecutionContextError::MissingJitoUrl)
+        );
+
+        let mut submitter_jito_url = None;
+        let context = builder()
+            .with_tx_submission_mode(
+                TxSubmissionMode::Jito,
+                Some("https://jito.example".to_owned()),
+            )
+            .with_priority_fees(1_000, 2_500)
+            .build(|context| {
+                submitter_jito_url = context.jito_url.clone();
+                fake_submitter(context)
+            });
+        assert_eq!(submitter_jito_url.as_deref(), Some("https://jito.example"));
+        assert!(context.is_ok());
+        if let Ok(context) = context {
+            assert_eq!(context.tx_submission_mode, TxSubmissionMode::Jito);
+            assert_eq!(context.execution_mode, ExecutionMode::DryRun);
+            assert_eq!(context.read_rpc.endpoint_count(), 1);
+        }
+    }
+
+    #[test]
+    fn builder_rejects_inconsistent_limits() {
+        assert_eq!(
+            builder()
+                .with_priority_fees(2_000, 1_000)
+                .build(fake_submitter)
+                .err(),
+            Some(ExecutionContextError::PriorityFeesAboveCeiling {
+                priority_fees: 2_000,
+                max_priority_fees: 1_000,
+            })
+        );
+        assert_eq!(
+            builder()
+                .with_compute_unit_limit(Some(0))
+                .build(fake_submitter)
+                .err(),
+            Some(ExecutionContextError::ZeroComputeUnitLimit)
+        );
+        assert_eq!(
+            builder()
+                .with_rpc_call_timeout(Some(Duration::ZERO))
+                .build(fake_submitter)
+                .err(),
+            Some(ExecutionContextError::ZeroRpcCallTimeout)
+        );
+    }
+
     fn execution_context(
         rpc: Arc<RpcClient>,
         adapter: Option<Arc<PluginHostTxProviderAdapter>>,
         require_local_blockhash: bool,
     ) -> ExecutionContext {
-        ExecutionContext {
-            priority_fees: 1,
+        let mut context = ExecutionContext::new(
             rpc,
-            keypair: Arc::new(Keypair::new()),
-            dry_run: true,
-            tx_submission_mode: TxSubmissionMode::Direct,
-            jito_url: Arc::new("https://jito.example".to_owned()),
-            sof_tx_client: None,
-            sof_tx_plan: None,
-            sof_tx_uses_jito: false,
-            sof_tx_blockhash_adapter: adapter,
-            require_local_blockhash,
-        }
+            Arc::new(Keypair::new()),
+            Arc::new(FakeSubmitter::default()),
+        );
+        context.priority_fees = 1;
+        context.max_priority_fees = 100;
+        context.sof_tx_blockhash_adapter = adapter;
+        context.require_local_blockhash = require_local_blockhash;
+        context
+    }
+
+    fn builder() -> ExecutionContextBuilder {
+        ExecutionContext::builder(
+            Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())),
+            Arc::new(Keypair::new()),
+        )
+    }
+
+    fn fake_submitter(_context: &ExecutionContext) -> Arc<dyn TransactionSubmitter> {
+        Arc::new(FakeSubmitter::default())
     }
 
     async fn spawn_mock_blockhash_rpc(
@@ -17,8 +17,11 @@ use solana_epoch_schedule::EpochSchedule;
 use solana_sdk::pubkey::Pubkey;
 use tokio::{task::JoinHandle, time::MissedTickBehavior};
 
+use crate::adapters::rpc_retry::{RetryDecision, classify_rpc_error, classify_rpc_error_message};
+
 const DIRECT_SCHEDULE_CURSOR_TICK: Duration = Duration::from_millis(250);
 const DIRECT_SCHEDULE_RPC_RETRY: Duration = Duration::from_secs(5);
+const DIRECT_SCHEDULE_RPC_SLOW_RETRY: Duration = Duration::from_secs(30);
 const MIN_LEADER_WINDOW_SLOTS: usize = 512;
 const MAX_LEADER_WINDOW_SLOTS: usize = 2_048;
 const LEADER_WINDOW_MULTIPLIER: usize = 256;
@@ -179,7 +182,9 @@ impl DirectLeaderScheduleState {
                             "SOF-TX direct leader-schedule bootstrap failed to fetch epoch schedule: {}",
                             error
                         );
-                        self.defer_rpc_retry();
+                        if !self.defer_rpc_retry(classify_rpc_error(&error)) {
+                            return;
+                        }
                         continue;
                     }
                 }
@@ -190,13 +195,16 @@ impl DirectLeaderScheduleState {
             };
 
             let current_epoch = epoch_schedule.get_epoch(observed_slot);
-            if self.rpc_retry_ready()
-                && self
-                    .ensure_epoch_cache(&epoch_schedule, current_epoch, observed_slot)
+            let refreshed = if self.rpc_retry_ready() {
+                self.ensure_epoch_cache(&epoch_schedule, current_epoch, observed_slot)
                     .await
-                    .is_err()
-            {
-                self.defer_rpc_retry();
+            } else {
+                Ok(())
+            };
+            if let Err(decision) = refreshed {
+                if !self.defer_rpc_retry(decision) {
+                    return;
+                }
                 continue;
             }
 
@@ -238,10 +246,23 @@ impl DirectLeaderScheduleState {
         Instant::now() >= self.next_rpc_retry_at
     }
 
-    fn defer_rpc_retry(&mut self) {
+    /// Schedules the next RPC attempt; returns `false` when the endpoint cannot serve the
+    /// leader schedule and the task should stop.
+    fn defer_rpc_retry(&mut self, decision: RetryDecision) -> bool {
+        let delay = match decision {
+            RetryDecision::RetryFast => DIRECT_SCHEDULE_RPC_RETRY,
+            RetryDecision::RetrySlow => DIRECT_SCHEDULE_RPC_SLOW_RETRY,
+            RetryDecision::Abort => {
+                log::error!(
+                    "SOF-TX direct leader-schedule refresh stopped; the RPC endpoint rejected the request"
+                );
+                return false;
+            }
+        };
         self.next_rpc_retry_at = Instant::now()
-            .checked_add(DIRECT_SCHEDULE_RPC_RETRY)
+            .checked_add(delay)
             .unwrap_or_else(Instant::now);
+        true
     }
 
     async fn ensure_epoch_cache(
@@ -249,7 +270,7 @@ impl DirectLeaderScheduleState {
         epoch_schedule: &EpochSchedule,
         current_epoch: u64,
         observed_slot: u64,
-    ) -> Result<(), ()> {
+    ) -> Result<(), RetryDecision> {
         if !self.cached_epochs.contains_key(&current_epoch) {
             let entries =
                 fetch_epoch_schedule_window(self.rpc.as_ref(), epoch_schedule, current_epoch)
@@ -260,6 +281,7 @@ impl DirectLeaderScheduleState {
                             current_epoch,
                             error
                         );
+                        classify_rpc_error_message(&error)
                     })?;
             log::info!(
                 "SOF-TX direct loaded leader-schedule cache for epoch {} ({} slots) at observed slot {}",
@@ -1,11 +1,20 @@
-use std::path::PathBuf;
+use std::{net::SocketAddr, path::PathBuf};
 
 use solana_client::client_error::ClientError;
 use thiserror::Error;
 
 use crate::{
-    app::{logging::LoggingError, systemd::SystemdError},
+    app::{
+        classify::ClassifyError,
+        config_validation::{ConfigValidationError, EffectiveConfigError},
+        context::ExecutionContextError,
+        logging::LoggingError,
+        systemd::SystemdError,
+    },
     domain::settings::SettingsError,
+    slices::sniper::{
+        cache::AddressInitError, engine::EngineStopReason, outcome::SnipeOutcomeLogError,
+    },
 };
 
 #[derive(Debug, Error)]
@@ -13,10 +22,18 @@ pub enum AppError {
     #[error(transparent)]
     ServiceCommand(#[from] SystemdError),
     #[error(transparent)]
+    ConfigValidation(#[from] ConfigValidationError),
+    #[error(transparent)]
+    EffectiveConfig(#[from] EffectiveConfigError),
+    #[error(transparent)]
+    Classify(#[from] ClassifyError),
+    #[error(transparent)]
     Logging(#[from] LoggingError),
     #[error(transparent)]
     Settings(#[from] SettingsError),
     #[error(transparent)]
+    Addresses(#[from] AddressInitError),
+    #[error(transparent)]
     Keypair(#[from] KeypairLoadError),
     #[error(transparent)]
     Rulebook(#[from] RulebookLoadError),
@@ -24,6 +41,16 @@ pub enum AppError {
     WalletBalance(#[from] WalletBalanceError),
     #[error(transparent)]
     IngressStartup(#[from] IngressStartupError),
+    #[error(transparent)]
+    HealthEndpoint(#[from] HealthEndpointError),
+    #[error(transparent)]
+    StrategyRuntime(#[from] StrategyRuntimeError),
+    #[error(transparent)]
+    SnipeOutcomeLog(#[from] SnipeOutcomeLogError),
+    #[error("invalid execution context: {0}")]
+    ExecutionContext(#[from] ExecutionContextError),
+    #[error("sniper engine stopped unexpectedly: {reason}")]
+    EngineStopped { reason: EngineStopReason },
 }
 
 #[derive(Debug, Error)]
@@ -40,6 +67,14 @@ pub enum KeypairLoadError {
         #[source]
         source: std::io::Error,
     },
+    #[error("keypair file at {path} is encrypted but {env} is not set")]
+    MissingPassphrase { path: PathBuf, env: &'static str },
+    #[error("failed to decrypt keypair file at {path}")]
+    Decrypt {
+        path: PathBuf,
+        #[source]
+        source: age::DecryptError,
+    },
     #[error("failed to parse keypair json at {path}")]
     ParseJson {
         path: PathBuf,
@@ -52,6 +87,8 @@ pub enum KeypairLoadError {
         #[source]
         source: Box<dyn std::error::Error + Send + Sync>,
     },
+    #[error("keypair pubkey {found} does not match runtime.expected_pubkey {expected}")]
+    PubkeyMismatch { expected: String, found: String },
 }
 
 #[derive(Debug, Error)]
@@ -77,3 +114,23 @@ pub enum IngressStartupError {
     #[error("failed to start SOF runtime: {detail}")]
     Sof { detail: String },
 }
+
+#[derive(Debug, Error)]
+pub enum HealthEndpointError {
+    #[error("failed to bind health endpoint on {addr}")]
+    Bind {
+        addr: SocketAddr,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum StrategyRuntimeError {
+    #[error("failed to build strategy runtime with {worker_threads} worker threads")]
+    Build {
+        worker_threads: usize,
+        #[source]
+        source: std::io::Error,
+    },
+}
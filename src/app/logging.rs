@@ -1,8 +1,11 @@
 use std::{
     fs::OpenOptions,
-    io::{BufWriter, Write},
+    io::{BufWriter, IsTerminal, Write},
     path::PathBuf,
-    sync::mpsc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
     thread,
 };
 
@@ -12,6 +15,8 @@ use log::{Level, LevelFilter, Metadata, Record};
 use thiserror::Error;
 use tokio::fs;
 
+static LOG_WRITER_ALIVE: AtomicBool = AtomicBool::new(false);
+
 #[derive(Debug)]
 struct AsyncLogEvent {
     timestamp: String,
@@ -38,11 +43,20 @@ impl AsyncLogger {
 struct LogWriter {
     receiver: mpsc::Receiver<AsyncLogEvent>,
     log_path: PathBuf,
+    console_color: bool,
 }
 
 impl LogWriter {
-    const fn new(receiver: mpsc::Receiver<AsyncLogEvent>, log_path: PathBuf) -> Self {
-        Self { receiver, log_path }
+    const fn new(
+        receiver: mpsc::Receiver<AsyncLogEvent>,
+        log_path: PathBuf,
+        console_color: bool,
+    ) -> Self {
+        Self {
+            receiver,
+            log_path,
+            console_color,
+        }
     }
 
     fn run(self) {
@@ -66,13 +80,12 @@ impl LogWriter {
         let mut stdout_lock = stdout.lock();
 
         while let Ok(event) = self.receiver.recv() {
-            let console_level = colored_level(event.level);
             let file_level = plain_level(event.level);
 
             if let Err(error) = writeln!(
                 stdout_lock,
-                "{} [ {} ] > {}",
-                event.timestamp, console_level, event.message
+                "{}",
+                render_console_line(&event, self.console_color)
             ) {
                 eprintln!("Failed to write log line to stdout: {}", error);
             }
@@ -138,10 +151,13 @@ pub async fn init_logging(level_filter: LevelFilter) -> Result<(), LoggingError>
 
     let log_path = log_dir.join("output.ans");
     let (sender, receiver) = mpsc::channel::<AsyncLogEvent>();
-    let writer = LogWriter::new(receiver, log_path);
+    let console_color = std::io::stdout().is_terminal() && !color_disabled_by_env();
+    let writer = LogWriter::new(receiver, log_path, console_color);
 
+    LOG_WRITER_ALIVE.store(true, Ordering::Release);
     thread::spawn(move || {
         writer.run();
+        LOG_WRITER_ALIVE.store(false, Ordering::Release);
     });
 
     let logger = AsyncLogger::new(level_filter, sender);
@@ -155,6 +171,24 @@ pub async fn init_logging(level_filter: LevelFilter) -> Result<(), LoggingError>
     Ok(())
 }
 
+pub fn log_writer_alive() -> bool {
+    LOG_WRITER_ALIVE.load(Ordering::Acquire)
+}
+
+/// `NO_COLOR` (https://no-color.org): any non-empty value disables ANSI color.
+pub fn color_disabled_by_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+fn render_console_line(event: &AsyncLogEvent, color: bool) -> String {
+    let level = if color {
+        colored_level(event.level)
+    } else {
+        plain_level(event.level).to_owned()
+    };
+    format!("{} [ {} ] > {}", event.timestamp, level, event.message)
+}
+
 fn colored_level(level: Level) -> String {
     match level {
         Level::Info => "+".green().to_string(),
@@ -174,3 +208,25 @@ const fn plain_level(level: Level) -> &'static str {
         Level::Trace => "~",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+
+    use super::{AsyncLogEvent, render_console_line};
+
+    #[test]
+    fn plain_console_lines_contain_no_escape_sequences() {
+        let event = AsyncLogEvent {
+            timestamp: "2024-01-01 00:00:00.000".to_owned(),
+            level: Level::Warn,
+            message: "queue full".to_owned(),
+        };
+
+        assert_eq!(
+            render_console_line(&event, false),
+            "2024-01-01 00:00:00.000 [ ! ] > queue full"
+        );
+        assert!(!render_console_line(&event, false).contains('\u{1b}'));
+    }
+}
@@ -1,7 +1,10 @@
 pub mod bootstrap;
+pub mod classify;
+pub mod config_validation;
 pub mod context;
 pub mod direct_leader_schedule;
 pub mod errors;
 pub mod logging;
 pub mod sof_runtime;
 pub mod systemd;
+pub mod tasks;
@@ -6,6 +6,7 @@ use std::{
         Arc,
         atomic::{AtomicBool, AtomicU64, Ordering},
     },
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -35,15 +36,19 @@ use sof_tx::{
 use solana_sdk::{pubkey::Pubkey, transaction::VersionedTransaction};
 use tokio::{
     net::UnixDatagram,
-    sync::{Mutex, mpsc},
+    sync::Mutex,
     task::JoinHandle,
+    time::{MissedTickBehavior, interval},
 };
 use url::Url;
 
 use crate::{
-    adapters::raydium::{
-        RAYDIUM_STANDARD_AMM_PROGRAM_ID, RAYDIUM_V4_PROGRAM_ID, RaydiumStructuredCandidateKind,
-        classify_raydium_creation_instructions,
+    adapters::{
+        health_http::HealthState,
+        raydium::{
+            RAYDIUM_STANDARD_AMM_PROGRAM_ID, RAYDIUM_V4_PROGRAM_ID,
+            classify_raydium_creation_message, has_raydium_creation_instruction,
+        },
     },
     app::{
         direct_leader_schedule::{
@@ -55,7 +60,7 @@ use crate::{
     domain::{
         events::{
             IngressMetadata, IngressSource, RaydiumCandidateEvent, RaydiumCandidateKind,
-            SniperInputEvent, unix_timestamp_now_ns,
+            SniperInputEvent, next_event_id, unix_timestamp_now_ns,
         },
         settings::{RuntimeSettings, SofRuntimeSettings, SofTxRuntimeSettings},
         value_objects::{
@@ -63,6 +68,7 @@ use crate::{
             SofTxReliability, SofTxRoute, SofTxStrategy,
         },
     },
+    slices::sniper::queue::{QueuePushOutcome, SniperEventQueue},
 };
 
 const PRIVATE_SHRED_BATCH_CAPACITY: usize = 128;
@@ -72,6 +78,7 @@ pub struct SofRuntimeHarness {
     pub sof_tx_plan: Option<SubmitPlan>,
     pub sof_tx_uses_jito: bool,
     pub control_plane_adapter: Option<Arc<PluginHostTxProviderAdapter>>,
+    pub ingress_counters: Arc<IngressCounters>,
     runtime: ObserverRuntime,
     background_source: SofBackgroundSource,
     direct_leader_schedule_task: Option<JoinHandle<()>>,
@@ -93,22 +100,106 @@ enum SofBackgroundSource {
     },
 }
 
+/// Running totals of what the candidate plugin saw and did with each transaction.
+#[derive(Debug, Default)]
+pub struct IngressCounters {
+    observed: AtomicU64,
+    unsigned: AtomicU64,
+    candidates: AtomicU64,
+    forwarded: AtomicU64,
+    dropped: AtomicU64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IngressCountersSnapshot {
+    /// Transactions that passed SOF's Raydium program prefilter.
+    pub observed: u64,
+    pub unsigned: u64,
+    /// Observed transactions classified as pool creations.
+    pub candidates: u64,
+    pub forwarded: u64,
+    /// Candidates lost to a full or closed engine queue.
+    pub dropped: u64,
+}
+
+impl IngressCounters {
+    pub fn snapshot(&self) -> IngressCountersSnapshot {
+        IngressCountersSnapshot {
+            observed: self.observed.load(Ordering::Relaxed),
+            unsigned: self.unsigned.load(Ordering::Relaxed),
+            candidates: self.candidates.load(Ordering::Relaxed),
+            forwarded: self.forwarded.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    #[must_use]
+    pub fn spawn_reporter(self: Arc<Self>, period: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let snapshot = self.snapshot();
+                log::info!(
+                    "Ingress counters > observed={} unsigned={} candidates={} forwarded={} dropped={}",
+                    snapshot.observed,
+                    snapshot.unsigned,
+                    snapshot.candidates,
+                    snapshot.forwarded,
+                    snapshot.dropped
+                );
+            }
+        })
+    }
+
+    fn increment(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 impl SofRuntimeHarness {
     pub async fn build(
         settings: &RuntimeSettings,
-        events_tx: mpsc::Sender<SniperInputEvent>,
+        events: Arc<SniperEventQueue>,
+        health: Arc<HealthState>,
     ) -> Result<Self, IngressStartupError> {
-        let cpmm_program =
-            parse_pubkey(RAYDIUM_STANDARD_AMM_PROGRAM_ID, "raydium cpmm program id")?;
-        let openbook_program = parse_pubkey(RAYDIUM_V4_PROGRAM_ID, "raydium openbook program id")?;
-        let candidate_plugin = Arc::new(RaydiumCandidatePlugin::new(
-            settings.sof.source,
-            settings.sof.commitment,
-            settings.sof.inline_transaction_dispatch,
-            events_tx,
-            cpmm_program,
-            openbook_program,
-        ));
+        let addresses = &settings.address_overrides;
+        let cpmm_program = parse_pubkey(
+            addresses
+                .raydium_cpmm_program
+                .as_deref()
+                .unwrap_or(RAYDIUM_STANDARD_AMM_PROGRAM_ID),
+            "raydium cpmm program id",
+        )?;
+        let openbook_program = parse_pubkey(
+            addresses
+                .raydium_v4_program
+                .as_deref()
+                .unwrap_or(RAYDIUM_V4_PROGRAM_ID),
+            "raydium openbook program id",
+        )?;
+        let candidate_plugin = Arc::new(
+            RaydiumCandidatePlugin::new(
+                RaydiumCandidatePluginConfig {
+                    ingress_source: settings.sof.source,
+                    commitment: settings.sof.commitment,
+                    inline_dispatch: settings.sof.inline_transaction_dispatch,
+                    cpmm_program,
+                    openbook_program,
+                    force_strategy: settings.force_strategy,
+                },
+                events,
+            )
+            .with_health_state(health),
+        );
+        let ingress_counters = Arc::clone(&candidate_plugin.counters);
+        if let Some(strategy) = settings.force_strategy {
+            log::warn!(
+                "Strategy override > only {} creation transactions are dispatched (runtime.force_strategy)",
+                strategy.as_str()
+            );
+        }
         let control_plane_adapter = build_control_plane_adapter(settings);
         let mut host_builder = PluginHost::builder().add_shared_plugin(candidate_plugin);
         if let Some(adapter) = &control_plane_adapter {
@@ -149,6 +240,7 @@ impl SofRuntimeHarness {
                     sof_tx_plan,
                     sof_tx_uses_jito,
                     control_plane_adapter,
+                    ingress_counters,
                     runtime,
                     background_source: SofBackgroundSource::Websocket(source),
                     direct_leader_schedule_task,
@@ -181,6 +273,7 @@ impl SofRuntimeHarness {
                     sof_tx_plan,
                     sof_tx_uses_jito,
                     control_plane_adapter,
+                    ingress_counters,
                     runtime,
                     background_source: SofBackgroundSource::Grpc(source),
                     direct_leader_schedule_task,
@@ -214,6 +307,7 @@ impl SofRuntimeHarness {
                     sof_tx_plan,
                     sof_tx_uses_jito,
                     control_plane_adapter,
+                    ingress_counters,
                     runtime,
                     background_source: SofBackgroundSource::PrivateShred { task, socket_path },
                     direct_leader_schedule_task,
@@ -266,45 +360,62 @@ struct RaydiumCandidatePlugin {
     ingress_source: SofIngressSource,
     commitment: SofCommitmentLevel,
     inline_dispatch: bool,
-    sender: mpsc::Sender<SniperInputEvent>,
-    dropped_candidate_events: AtomicU64,
+    events: Arc<SniperEventQueue>,
     closed_warned: AtomicBool,
     cpmm_program: Pubkey,
     openbook_program: Pubkey,
+    force_strategy: Option<RaydiumCandidateKind>,
     prefilter: TransactionPrefilter,
+    counters: Arc<IngressCounters>,
+    health: Option<Arc<HealthState>>,
+}
+
+/// Ingress settings and program ids for [`RaydiumCandidatePlugin`].
+#[derive(Clone, Copy, Debug)]
+struct RaydiumCandidatePluginConfig {
+    ingress_source: SofIngressSource,
+    commitment: SofCommitmentLevel,
+    inline_dispatch: bool,
+    cpmm_program: Pubkey,
+    openbook_program: Pubkey,
+    force_strategy: Option<RaydiumCandidateKind>,
 }
 
 impl RaydiumCandidatePlugin {
-    fn new(
-        ingress_source: SofIngressSource,
-        commitment: SofCommitmentLevel,
-        inline_dispatch: bool,
-        sender: mpsc::Sender<SniperInputEvent>,
-        cpmm_program: Pubkey,
-        openbook_program: Pubkey,
-    ) -> Self {
+    fn new(config: RaydiumCandidatePluginConfig, events: Arc<SniperEventQueue>) -> Self {
         Self {
-            ingress_source,
-            commitment,
-            inline_dispatch,
-            sender,
-            dropped_candidate_events: AtomicU64::new(0),
+            ingress_source: config.ingress_source,
+            commitment: config.commitment,
+            inline_dispatch: config.inline_dispatch,
+            events,
             closed_warned: AtomicBool::new(false),
-            cpmm_program,
-            openbook_program,
+            cpmm_program: config.cpmm_program,
+            openbook_program: config.openbook_program,
+            force_strategy: config.force_strategy,
             prefilter: TransactionPrefilter::new(TransactionInterest::Critical)
-                .with_account_include([cpmm_program, openbook_program]),
+                .with_account_include([config.cpmm_program, config.openbook_program]),
+            counters: Arc::new(IngressCounters::default()),
+            health: None,
         }
     }
 
+    /// Marks ingress ready on `health` once the first transaction arrives.
+    fn with_health_state(mut self, health: Arc<HealthState>) -> Self {
+        self.health = Some(health);
+        self
+    }
+
     fn enqueue_candidate_event(&self, event: SniperInputEvent) {
-        match self.sender.try_send(event) {
-            Ok(()) => {}
-            Err(mpsc::error::TrySendError::Full(_event)) => {
-                let dropped = self
-                    .dropped_candidate_events
-                    .fetch_add(1, Ordering::Relaxed)
-                    .saturating_add(1);
+        let outcome = self.events.push(event);
+        IngressCounters::increment(if outcome == QueuePushOutcome::Enqueued {
+            &self.counters.forwarded
+        } else {
+            &self.counters.dropped
+        });
+        match outcome {
+            QueuePushOutcome::Enqueued => {}
+            QueuePushOutcome::DroppedNewest | QueuePushOutcome::DroppedOldest => {
+                let dropped = self.events.dropped_events();
                 if should_log_drop_count(dropped) {
                     log::warn!(
                         "SOF candidate plugin dropped {} candidate events because the sniper ingress queue is full",
@@ -312,7 +423,7 @@ impl RaydiumCandidatePlugin {
                     );
                 }
             }
-            Err(mpsc::error::TrySendError::Closed(_event)) => {
+            QueuePushOutcome::Closed => {
                 if !self.closed_warned.swap(true, Ordering::Relaxed) {
                     log::warn!(
                         "SOF candidate plugin could not forward candidate event to sniper engine because the queue is closed"
@@ -322,9 +433,30 @@ impl RaydiumCandidatePlugin {
         }
     }
 
+    /// A forced strategy takes any transaction carrying its own creation instruction, even
+    /// when another creation instruction comes first, and drops everything else.
+    fn candidate_kind(&self, tx: &VersionedTransaction) -> Option<RaydiumCandidateKind> {
+        self.force_strategy.map_or_else(
+            || {
+                classify_raydium_creation_message(
+                    &tx.message,
+                    self.cpmm_program,
+                    self.openbook_program,
+                )
+            },
+            |forced| {
+                let program = match forced {
+                    RaydiumCandidateKind::Cpmm => self.cpmm_program,
+                    RaydiumCandidateKind::OpenBook => self.openbook_program,
+                };
+                has_raydium_creation_instruction(&tx.message, forced, program).then_some(forced)
+            },
+        )
+    }
+
     #[cfg(test)]
     fn dropped_candidate_events(&self) -> u64 {
-        self.dropped_candidate_events.load(Ordering::Relaxed)
+        self.events.dropped_events()
     }
 }
 
@@ -351,20 +483,26 @@ impl ObserverPlugin for RaydiumCandidatePlugin {
     }
 
     async fn on_transaction(&self, event: &TransactionEvent) {
+        IngressCounters::increment(&self.counters.observed);
+        if let Some(health) = &self.health {
+            health.mark_ingress_ready();
+        }
         if event.signature.is_none() {
+            IngressCounters::increment(&self.counters.unsigned);
             return;
         }
 
-        let kind =
-            classify_raydium_candidate(event.tx.as_ref(), self.cpmm_program, self.openbook_program);
-        let Some(kind) = kind else {
+        let Some(kind) = self.candidate_kind(event.tx.as_ref()) else {
             return;
         };
+        IngressCounters::increment(&self.counters.candidates);
 
         let ingress = IngressMetadata::from_receive_clock(
             self.ingress_source.into(),
             unix_timestamp_now_ns(),
-        );
+        )
+        .with_slot(event.slot)
+        .with_event_id(next_event_id());
         let event = SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
             kind,
             transaction: Arc::clone(&event.tx),
@@ -379,23 +517,6 @@ const fn should_log_drop_count(dropped: u64) -> bool {
     dropped == 1 || dropped.is_power_of_two()
 }
 
-fn classify_raydium_candidate(
-    tx: &VersionedTransaction,
-    cpmm_program: Pubkey,
-    openbook_program: Pubkey,
-) -> Option<RaydiumCandidateKind> {
-    match classify_raydium_creation_instructions(
-        tx.message.static_account_keys(),
-        tx.message.instructions(),
-        cpmm_program,
-        openbook_program,
-    ) {
-        Some(RaydiumStructuredCandidateKind::Cpmm) => Some(RaydiumCandidateKind::Cpmm),
-        Some(RaydiumStructuredCandidateKind::OpenBook) => Some(RaydiumCandidateKind::OpenBook),
-        None => None,
-    }
-}
-
 fn build_runtime_setup(settings: &SofRuntimeSettings) -> RuntimeSetup {
     let mut setup = RuntimeSetup::new()
         .with_inline_transaction_dispatch(settings.inline_transaction_dispatch)
@@ -453,7 +574,7 @@ fn build_websocket_config(
         .with_commitment(settings.sof.commitment.into())
         .with_source_instance("slotstrike-websocket")
         .with_vote(false)
-        .with_failed(false)
+        .with_failed(settings.snipe_on_error_events)
         .with_account_include(vec![cpmm_program, openbook_program])
 }
 
@@ -467,7 +588,7 @@ fn build_grpc_config(
         .with_commitment(settings.sof.commitment.into())
         .with_source_instance("slotstrike-yellowstone")
         .with_vote(false)
-        .with_failed(false)
+        .with_failed(settings.snipe_on_error_events)
         .with_account_include(vec![cpmm_program, openbook_program]);
 
     if let Some(x_token) = &settings.sof.grpc_x_token {
@@ -769,26 +890,38 @@ mod tests {
 
     use sof::provider_stream::ProviderStreamMode;
     use solana_sdk::{
+        instruction::Instruction,
         message::Message,
         pubkey::Pubkey,
         transaction::{Transaction, VersionedTransaction},
     };
-    use tokio::sync::mpsc;
 
     use super::{
-        RaydiumCandidatePlugin, build_control_plane_adapter, build_grpc_config,
-        build_websocket_config,
+        IngressCountersSnapshot, RaydiumCandidatePlugin, RaydiumCandidatePluginConfig,
+        build_control_plane_adapter, build_grpc_config, build_websocket_config,
     };
-    use crate::domain::{
-        events::{
-            IngressMetadata, IngressSource, RaydiumCandidateEvent, RaydiumCandidateKind,
-            SniperInputEvent,
+    use crate::{
+        adapters::raydium::{
+            RAYDIUM_V4_INITIALIZE2_TAG, RAYDIUM_V4_SWAP_BASE_IN_TAG, STANDARD_AMM_INITIALIZE,
         },
-        settings::{RuntimeSettings, SofRuntimeSettings, SofTxRuntimeSettings},
-        value_objects::{
-            PriorityFeesMicrolamports, ReplayBurstSize, ReplayEventCount, SofCommitmentLevel,
-            SofGossipRuntimeMode, SofIngressSource, SofTxJitoTransport, SofTxMode,
-            SofTxReliability, SofTxRoute, SofTxStrategy, TxSubmissionMode,
+        domain::{
+            events::{
+                IngressMetadata, IngressSource, RaydiumCandidateEvent, RaydiumCandidateKind,
+                SniperInputEvent,
+            },
+            settings::{
+                AddressOverrides, RuntimeSettings, SofRuntimeSettings, SofTxRuntimeSettings,
+            },
+            value_objects::{
+                EventQueueDropPolicy, PriorityFeesMicrolamports, ReplayBurstSize, ReplayEventCount,
+                SofCommitmentLevel, SofGossipRuntimeMode, SofIngressSource, SofTxJitoTransport,
+                SofTxMode, SofTxReliability, SofTxRoute, SofTxStrategy, TxSubmissionMode,
+                WsolCloseTiming, sol_amount::InitLiquidityBand,
+            },
+        },
+        slices::sniper::{
+            queue::{QueuePushOutcome, SniperEventQueue},
+            telemetry::LatencyTelemetry,
         },
     };
 
@@ -801,9 +934,33 @@ mod tests {
 
         Ok(RuntimeSettings {
             config_path: "slotstrike.toml".to_owned(),
+            config_override_path: None,
             priority_fees: PriorityFeesMicrolamports::new(1_000),
+            max_priority_fees: PriorityFeesMicrolamports::DEFAULT_CEILING,
+            max_compute_unit_price: PriorityFeesMicrolamports::DEFAULT_CEILING,
             keypair_path: "keypair.json".to_owned(),
+            expected_pubkey: None,
             dry_run: true,
+            paper_trading: false,
+            skip_preflight: true,
+            send_max_retries: 0,
+            submit_rpc_urls: Vec::new(),
+            fallback_rpc_urls: Vec::new(),
+            force_strategy: None,
+            snipe_on_error_events: false,
+            use_live_reserves: false,
+            wsol_close_timing: WsolCloseTiming::Inline,
+            wsol_wrap_buffer_lamports: 0,
+            reuse_wrapped_sol: false,
+            allow_zero_min_amount_out: false,
+            slippage_retry_attempts: 0,
+            init_liquidity_band: InitLiquidityBand::default(),
+            compute_unit_limit: None,
+            max_pool_open_wait_secs: 0,
+            pre_open_offset_ms: 0,
+            blockhash_refresh_ms: 2_000,
+            blockhash_max_age_ms: 20_000,
+            rpc_call_timeout_ms: 5_000,
             tx_submission_mode: TxSubmissionMode::Direct,
             jito_url: "https://jito.example".to_owned(),
             rpc_url: "https://rpc.example".to_owned(),
@@ -849,10 +1006,31 @@ mod tests {
             run_replay_benchmark: false,
             replay_event_count,
             replay_burst_size,
+            event_queue_capacity: 4_096,
+            event_queue_drop_policy: EventQueueDropPolicy::DropNewest,
+            signature_dedup_capacity: 4_096,
+            signature_dedup_path: None,
+            max_candidate_instructions: 256,
+            strategy_worker_threads: None,
+            strategy_worker_tasks: 0,
+            snipe_deadline_secs: 0,
+            startup_warmup_secs: 0,
+            snipe_outcome_path: None,
+            session_ledger_report_secs: 0,
+            rule_stats_report_secs: 0,
+            config_reload_secs: 1,
+            config_watch: false,
+            fetch_commitment: SofCommitmentLevel::Confirmed,
+            confirmation_commitment: SofCommitmentLevel::Confirmed,
             latency_sample_capacity: 4_096,
+            latency_sample_max_age_ms: 0,
             latency_slo_ns: 1_000_000,
+            latency_slo_alert_repeat_periods: 0,
             latency_report_period_secs: 15,
+            latency_expected_events_per_sec: 0,
             telemetry_enabled: true,
+            health_listen_addr: None,
+            address_overrides: AddressOverrides::default(),
         })
     }
 
@@ -941,53 +1119,130 @@ mod tests {
 
     #[test]
     fn candidate_plugin_drops_when_ingress_queue_is_full() {
-        let (sender, mut receiver) = mpsc::channel(1);
-        let plugin = candidate_plugin(sender.clone());
-        let first_send = sender.try_send(candidate_event(RaydiumCandidateKind::Cpmm));
-        assert!(first_send.is_ok());
+        let events = event_queue(EventQueueDropPolicy::DropNewest);
+        let plugin = candidate_plugin(Arc::clone(&events));
+        let first_send = events.push(candidate_event(RaydiumCandidateKind::Cpmm));
+        assert_eq!(first_send, QueuePushOutcome::Enqueued);
 
         plugin.enqueue_candidate_event(candidate_event(RaydiumCandidateKind::OpenBook));
 
         assert_eq!(plugin.dropped_candidate_events(), 1);
-        drop(sender);
-        let queued = receiver.try_recv();
-        assert!(queued.is_ok());
+        assert_eq!(
+            plugin.counters.snapshot(),
+            IngressCountersSnapshot {
+                dropped: 1,
+                ..IngressCountersSnapshot::default()
+            }
+        );
+        let queued = events.try_recv();
+        assert!(queued.is_some());
         assert!(matches!(
             queued,
-            Ok(SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+            Some(SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
                 kind: RaydiumCandidateKind::Cpmm,
                 ..
             }))
         ));
     }
 
+    #[test]
+    fn candidate_plugin_replaces_oldest_when_configured() {
+        let events = event_queue(EventQueueDropPolicy::DropOldest);
+        let plugin = candidate_plugin(Arc::clone(&events));
+        plugin.enqueue_candidate_event(candidate_event(RaydiumCandidateKind::Cpmm));
+        plugin.enqueue_candidate_event(candidate_event(RaydiumCandidateKind::OpenBook));
+
+        assert_eq!(plugin.dropped_candidate_events(), 1);
+        assert!(matches!(
+            events.try_recv(),
+            Some(SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+                kind: RaydiumCandidateKind::OpenBook,
+                ..
+            }))
+        ));
+    }
+
     #[test]
     fn candidate_plugin_enqueues_when_queue_has_capacity() {
-        let (sender, mut receiver) = mpsc::channel(1);
-        let plugin = candidate_plugin(sender);
+        let events = event_queue(EventQueueDropPolicy::DropNewest);
+        let plugin = candidate_plugin(Arc::clone(&events));
 
         plugin.enqueue_candidate_event(candidate_event(RaydiumCandidateKind::OpenBook));
 
         assert_eq!(plugin.dropped_candidate_events(), 0);
-        let queued = receiver.try_recv();
+        let queued = events.try_recv();
         assert!(matches!(
             queued,
-            Ok(SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+            Some(SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
                 kind: RaydiumCandidateKind::OpenBook,
                 ..
             }))
         ));
     }
 
-    fn candidate_plugin(sender: mpsc::Sender<SniperInputEvent>) -> RaydiumCandidatePlugin {
-        RaydiumCandidatePlugin::new(
-            SofIngressSource::Websocket,
-            SofCommitmentLevel::Processed,
-            true,
-            sender,
-            Pubkey::new_unique(),
-            Pubkey::new_unique(),
-        )
+    #[test]
+    fn forced_strategy_takes_only_its_own_creation_transactions() {
+        let payer = Pubkey::new_unique();
+        let cpmm_program = Pubkey::new_unique();
+        let openbook_program = Pubkey::new_unique();
+        let creation =
+            |program: Pubkey, data: &[u8]| Instruction::new_with_bytes(program, data, Vec::new());
+        let transaction = |instructions: &[Instruction]| {
+            VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+                instructions,
+                Some(&payer),
+            )))
+        };
+        let cpmm_creation = creation(cpmm_program, &STANDARD_AMM_INITIALIZE);
+        let openbook_creation = creation(openbook_program, &[RAYDIUM_V4_INITIALIZE2_TAG]);
+        let openbook_swap = creation(openbook_program, &[RAYDIUM_V4_SWAP_BASE_IN_TAG]);
+        let plugin = |force_strategy| {
+            RaydiumCandidatePlugin::new(
+                RaydiumCandidatePluginConfig {
+                    force_strategy,
+                    cpmm_program,
+                    openbook_program,
+                    ..plugin_config()
+                },
+                event_queue(EventQueueDropPolicy::DropNewest),
+            )
+        };
+
+        let both = transaction(&[cpmm_creation.clone(), openbook_creation]);
+        assert_eq!(
+            plugin(None).candidate_kind(&both),
+            Some(RaydiumCandidateKind::Cpmm)
+        );
+        let forced = plugin(Some(RaydiumCandidateKind::OpenBook));
+        assert_eq!(
+            forced.candidate_kind(&both),
+            Some(RaydiumCandidateKind::OpenBook)
+        );
+        assert_eq!(forced.candidate_kind(&transaction(&[cpmm_creation])), None);
+        assert_eq!(forced.candidate_kind(&transaction(&[openbook_swap])), None);
+    }
+
+    fn event_queue(drop_policy: EventQueueDropPolicy) -> Arc<SniperEventQueue> {
+        Arc::new(SniperEventQueue::new(
+            1,
+            drop_policy,
+            Arc::new(LatencyTelemetry::disabled()),
+        ))
+    }
+
+    fn plugin_config() -> RaydiumCandidatePluginConfig {
+        RaydiumCandidatePluginConfig {
+            ingress_source: SofIngressSource::Websocket,
+            commitment: SofCommitmentLevel::Processed,
+            inline_dispatch: true,
+            cpmm_program: Pubkey::new_unique(),
+            openbook_program: Pubkey::new_unique(),
+            force_strategy: None,
+        }
+    }
+
+    fn candidate_plugin(events: Arc<SniperEventQueue>) -> RaydiumCandidatePlugin {
+        RaydiumCandidatePlugin::new(plugin_config(), events)
     }
 
     fn candidate_event(kind: RaydiumCandidateKind) -> SniperInputEvent {
@@ -6,9 +6,10 @@ use std::{
 
 use thiserror::Error;
 
+use crate::domain::settings::resolve_config_path;
+
 const DEFAULT_SERVICE_NAME: &str = "slotstrike";
 const DEFAULT_SYSTEMD_DIR: &str = "/etc/systemd/system";
-const DEFAULT_CONFIG_PATH: &str = "slotstrike.toml";
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct ServiceOptions {
@@ -235,10 +236,7 @@ fn build_options(args: &[String]) -> Result<ServiceOptions, ServiceOptionsError>
         arg_value(args, "--systemd-dir").unwrap_or_else(|| DEFAULT_SYSTEMD_DIR.to_owned()),
         PathField::SystemdDir,
     )?;
-    let config_path = absolutize(
-        arg_value(args, "--config").unwrap_or_else(|| DEFAULT_CONFIG_PATH.to_owned()),
-        PathField::ConfigPath,
-    )?;
+    let config_path = absolutize(resolve_config_path(args), PathField::ConfigPath)?;
     let working_dir =
         env::current_dir().map_err(|source| ServiceOptionsError::ResolveCurrentDir { source })?;
     let bin_path = env::current_exe()
@@ -0,0 +1,207 @@
+use std::{
+    sync::{Mutex, MutexGuard, PoisonError},
+    thread,
+    time::Duration,
+};
+
+use tokio::{task::JoinHandle, time::Instant};
+
+/// How often `shutdown` checks whether registered threads have exited.
+const THREAD_JOIN_POLL: Duration = Duration::from_millis(10);
+
+/// Background tasks and threads started for the life of the runtime, so teardown can stop
+/// them instead of leaving them detached. Async tasks still registered when the registry is
+/// dropped are aborted; threads cannot be interrupted and are left to exit on their own.
+#[derive(Default)]
+pub struct TaskRegistry {
+    tasks: Mutex<Vec<RegisteredTask>>,
+}
+
+struct RegisteredTask {
+    name: &'static str,
+    handle: TaskHandle,
+}
+
+enum TaskHandle {
+    Async(JoinHandle<()>),
+    Thread(thread::JoinHandle<()>),
+}
+
+/// What `TaskRegistry::shutdown` did with each registered task.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TaskShutdownReport {
+    /// Finished, or cancelled and confirmed stopped, within the grace period.
+    pub joined: usize,
+    /// Still running when the grace period ran out.
+    pub detached: usize,
+}
+
+impl TaskRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&self, name: &'static str, handle: JoinHandle<()>) {
+        self.push(name, TaskHandle::Async(handle));
+    }
+
+    pub fn register_thread(&self, name: &'static str, handle: thread::JoinHandle<()>) {
+        self.push(name, TaskHandle::Thread(handle));
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock_tasks().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock_tasks().is_empty()
+    }
+
+    /// Aborts every async task and waits up to `grace` for all registered tasks and threads
+    /// to stop. Whatever is still running after that is logged and detached.
+    pub async fn shutdown(&self, grace: Duration) -> TaskShutdownReport {
+        let tasks = std::mem::take(&mut *self.lock_tasks());
+        let deadline = Instant::now().checked_add(grace);
+        let mut report = TaskShutdownReport::default();
+
+        for task in &tasks {
+            if let TaskHandle::Async(handle) = &task.handle {
+                handle.abort();
+            }
+        }
+
+        for task in tasks {
+            let stopped = match task.handle {
+                TaskHandle::Async(handle) => join_async(handle, deadline).await,
+                TaskHandle::Thread(handle) => join_thread(handle, deadline).await,
+            };
+            if stopped {
+                report.joined = report.joined.saturating_add(1);
+            } else {
+                report.detached = report.detached.saturating_add(1);
+                log::warn!(
+                    "Shutdown > background task '{}' did not stop within {}ms; detaching it",
+                    task.name,
+                    grace.as_millis()
+                );
+            }
+        }
+
+        report
+    }
+
+    fn push(&self, name: &'static str, handle: TaskHandle) {
+        self.lock_tasks().push(RegisteredTask { name, handle });
+    }
+
+    fn lock_tasks(&self) -> MutexGuard<'_, Vec<RegisteredTask>> {
+        self.tasks.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for TaskRegistry {
+    fn drop(&mut self) {
+        for task in self.lock_tasks().iter() {
+            if let TaskHandle::Async(handle) = &task.handle {
+                handle.abort();
+            }
+        }
+    }
+}
+
+/// `true` once the task has finished; a cancelled task counts as stopped.
+async fn join_async(handle: JoinHandle<()>, deadline: Option<Instant>) -> bool {
+    match deadline {
+        Some(deadline) => tokio::time::timeout_at(deadline, handle).await.is_ok(),
+        None => {
+            let _joined = handle.await;
+            true
+        }
+    }
+}
+
+async fn join_thread(handle: thread::JoinHandle<()>, deadline: Option<Instant>) -> bool {
+    while !handle.is_finished() {
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return false;
+        }
+        tokio::time::sleep(THREAD_JOIN_POLL).await;
+    }
+    if handle.join().is_err() {
+        log::warn!("Shutdown > a background thread panicked");
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+        thread,
+        time::Duration,
+    };
+
+    use super::{TaskRegistry, TaskShutdownReport};
+
+    #[tokio::test]
+    async fn aborts_async_tasks_and_joins_finished_threads() {
+        let registry = TaskRegistry::new();
+        registry.register("idle", tokio::spawn(std::future::pending::<()>()));
+        registry.register("done", tokio::spawn(async {}));
+        registry.register_thread("thread", thread::spawn(|| {}));
+        assert_eq!(registry.len(), 3);
+
+        let report = registry.shutdown(Duration::from_secs(1)).await;
+
+        assert_eq!(
+            report,
+            TaskShutdownReport {
+                joined: 3,
+                detached: 0
+            }
+        );
+        assert!(registry.is_empty());
+    }
+
+    #[tokio::test]
+    async fn detaches_threads_that_outlive_the_grace_period() {
+        let registry = TaskRegistry::new();
+        let release = Arc::new(AtomicBool::new(false));
+        let thread_release = Arc::clone(&release);
+        registry.register_thread(
+            "stuck",
+            thread::spawn(move || {
+                while !thread_release.load(Ordering::Acquire) {
+                    thread::sleep(Duration::from_millis(1));
+                }
+            }),
+        );
+
+        let report = registry.shutdown(Duration::from_millis(30)).await;
+        release.store(true, Ordering::Release);
+
+        assert_eq!(report.detached, 1);
+        assert_eq!(report.joined, 0);
+    }
+
+    #[tokio::test]
+    async fn dropping_the_registry_aborts_its_tasks() {
+        let registry = TaskRegistry::new();
+        let task = tokio::spawn(std::future::pending::<()>());
+        let abort_handle = task.abort_handle();
+        registry.register("idle", task);
+
+        drop(registry);
+        let stopped = tokio::time::timeout(Duration::from_secs(1), async {
+            while !abort_handle.is_finished() {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await;
+
+        assert!(stopped.is_ok());
+    }
+}
@@ -1,11 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use crate::domain::{entities::SnipeRule, value_objects::RuleAddress};
+use crate::domain::{
+    entities::SnipeRule,
+    value_objects::{RuleAddress, sol_amount::Lamports},
+};
 
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct RuleBook {
     mint_rules: HashMap<RuleAddress, SnipeRule>,
     deployer_rules: HashMap<RuleAddress, SnipeRule>,
+    catch_all_rule: Option<SnipeRule>,
+    blocked_deployers: HashSet<RuleAddress>,
+    allowed_deployers: HashSet<RuleAddress>,
+    max_priority: u8,
 }
 
 impl RuleBook {
@@ -15,17 +22,63 @@ impl RuleBook {
             .map(|rule| (rule.address().clone(), rule))
             .collect::<HashMap<_, _>>();
 
+        let mut catch_all_rule = None;
         let deployer_rules = deployers
             .into_iter()
-            .map(|rule| (rule.address().clone(), rule))
+            .filter_map(|rule| {
+                if rule.address().is_catch_all() {
+                    catch_all_rule = Some(rule);
+                    return None;
+                }
+                Some((rule.address().clone(), rule))
+            })
             .collect::<HashMap<_, _>>();
 
+        let max_priority = mint_rules
+            .values()
+            .chain(deployer_rules.values())
+            .chain(catch_all_rule.iter())
+            .map(SnipeRule::priority)
+            .max()
+            .unwrap_or_default();
+
         Self {
             mint_rules,
             deployer_rules,
+            catch_all_rule,
+            blocked_deployers: HashSet::new(),
+            allowed_deployers: HashSet::new(),
+            max_priority,
         }
     }
 
+    /// Global deployer filter applied before any rule: blocked deployers are never sniped,
+    /// and a non-empty allowlist restricts sniping to its deployers.
+    pub fn with_deployer_lists(
+        mut self,
+        blocklist: Vec<RuleAddress>,
+        allowlist: Vec<RuleAddress>,
+    ) -> Self {
+        self.blocked_deployers = blocklist.into_iter().collect();
+        self.allowed_deployers = allowlist.into_iter().collect();
+        self
+    }
+
+    #[inline(always)]
+    pub fn is_deployer_permitted(&self, deployer_address: &str) -> bool {
+        !self.blocked_deployers.contains(deployer_address)
+            && (self.allowed_deployers.is_empty()
+                || self.allowed_deployers.contains(deployer_address))
+    }
+
+    pub const fn blocked_deployers(&self) -> &HashSet<RuleAddress> {
+        &self.blocked_deployers
+    }
+
+    pub const fn allowed_deployers(&self) -> &HashSet<RuleAddress> {
+        &self.allowed_deployers
+    }
+
     #[inline(always)]
     pub fn mint_rule(&self, token_address: &str) -> Option<&SnipeRule> {
         self.mint_rules.get(token_address)
@@ -36,6 +89,40 @@ impl RuleBook {
         self.deployer_rules.get(deployer_address)
     }
 
+    #[inline(always)]
+    pub const fn catch_all_rule(&self) -> Option<&SnipeRule> {
+        self.catch_all_rule.as_ref()
+    }
+
+    /// False when every rule has the default priority, so dispatch can skip the pre-match.
+    #[inline(always)]
+    pub const fn uses_priorities(&self) -> bool {
+        self.max_priority > 0
+    }
+
+    /// Highest priority among the rules any of `addresses` could match, as a cheap pre-match
+    /// before the strategy handler resolves the mint and deployer.
+    pub fn dispatch_priority<I>(&self, addresses: I) -> u8
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut priority = self.catch_all_rule.as_ref().map_or(0, SnipeRule::priority);
+        for address in addresses {
+            if priority >= self.max_priority {
+                break;
+            }
+            let address = address.as_ref();
+            for rule in [self.mint_rule(address), self.deployer_rule(address)]
+                .into_iter()
+                .flatten()
+            {
+                priority = priority.max(rule.priority());
+            }
+        }
+        priority
+    }
+
     pub const fn mint_rules(&self) -> &HashMap<RuleAddress, SnipeRule> {
         &self.mint_rules
     }
@@ -53,12 +140,41 @@ impl RuleBook {
             .collect::<Vec<_>>()
     }
 
+    /// One line per rule whose snipe height plus tip budget exceeds `balance`.
+    pub fn unfundable_rule_log_lines(&self, balance: Lamports) -> Vec<String> {
+        let mut mints = self.mint_rules.values().collect::<Vec<_>>();
+        mints.sort_by(|left, right| left.address().as_str().cmp(right.address().as_str()));
+        let mut deployers = self.deployer_rules.values().collect::<Vec<_>>();
+        deployers.sort_by(|left, right| left.address().as_str().cmp(right.address().as_str()));
+
+        mints
+            .into_iter()
+            .map(|rule| ("Token address", rule))
+            .chain(deployers.into_iter().map(|rule| ("Deployer address", rule)))
+            .chain(self.catch_all_rule.iter().map(|rule| ("Catch-all", rule)))
+            .filter(|(_label, rule)| rule.required_lamports() > balance)
+            .map(|(label, rule)| {
+                format!(
+                    "{} > {} needs {} SOL (snipe height + tip budget)",
+                    label,
+                    rule.address(),
+                    rule.required_lamports().as_sol_string()
+                )
+            })
+            .collect()
+    }
+
     pub fn deployer_log_lines(&self) -> Vec<String> {
         let mut rules = self.deployer_rules.values().collect::<Vec<_>>();
         rules.sort_by(|left, right| left.address().as_str().cmp(right.address().as_str()));
         rules
             .iter()
             .map(|rule| rule.as_log_line("Deployer address"))
+            .chain(
+                self.catch_all_rule
+                    .iter()
+                    .map(|rule| rule.as_log_line("Catch-all")),
+            )
             .collect::<Vec<_>>()
     }
 }
@@ -102,6 +218,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn keeps_catch_all_rule_out_of_deployer_index() {
+        let deployer_rule = build_rule("11111111111111111111111111111111");
+        let catch_all_rule = build_rule("*");
+        assert!(deployer_rule.is_some());
+        assert!(catch_all_rule.is_some());
+
+        if let (Some(deployer_rule), Some(catch_all_rule)) = (deployer_rule, catch_all_rule) {
+            let book = RuleBook::new(Vec::new(), vec![deployer_rule, catch_all_rule]);
+            assert_eq!(book.deployer_rules().len(), 1);
+            assert!(book.deployer_rule("*").is_none());
+            assert!(book.catch_all_rule().is_some());
+            assert_eq!(book.deployer_log_lines().len(), 2);
+        }
+    }
+
     #[test]
     fn emits_sorted_log_lines() {
         let mint_a = build_rule("11111111111111111111111111111111");
@@ -119,4 +251,67 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn dispatch_priority_takes_the_highest_matching_rule() {
+        let mint_rule = build_rule("So11111111111111111111111111111111111111112");
+        let deployer_rule = build_rule("11111111111111111111111111111111");
+        let catch_all_rule = build_rule("*");
+        assert!(mint_rule.is_some());
+        assert!(deployer_rule.is_some());
+        assert!(catch_all_rule.is_some());
+
+        if let (Some(mint_rule), Some(deployer_rule), Some(catch_all_rule)) =
+            (mint_rule, deployer_rule, catch_all_rule)
+        {
+            let unprioritized = RuleBook::new(vec![mint_rule.clone()], Vec::new());
+            assert!(!unprioritized.uses_priorities());
+
+            let book = RuleBook::new(
+                vec![mint_rule.with_priority(7)],
+                vec![
+                    deployer_rule.with_priority(3),
+                    catch_all_rule.with_priority(1),
+                ],
+            );
+            assert!(book.uses_priorities());
+            assert_eq!(
+                book.dispatch_priority([
+                    "11111111111111111111111111111111",
+                    "So11111111111111111111111111111111111111112",
+                ]),
+                7
+            );
+            assert_eq!(
+                book.dispatch_priority(["11111111111111111111111111111111"]),
+                3
+            );
+            assert_eq!(book.dispatch_priority(Vec::<String>::new()), 1);
+        }
+    }
+
+    #[test]
+    fn lists_rules_the_wallet_cannot_fund() {
+        let mint_rule = build_rule("So11111111111111111111111111111111111111112");
+        let catch_all_rule = build_rule("*");
+        assert!(mint_rule.is_some());
+        assert!(catch_all_rule.is_some());
+
+        if let (Some(mint_rule), Some(catch_all_rule)) = (mint_rule, catch_all_rule) {
+            let book = RuleBook::new(vec![mint_rule], vec![catch_all_rule]);
+            assert!(
+                book.unfundable_rule_log_lines(Lamports::new(1_100_000_000))
+                    .is_empty()
+            );
+
+            let lines = book.unfundable_rule_log_lines(Lamports::new(1_099_999_999));
+            assert_eq!(
+                lines,
+                vec![
+                    "Token address > So11111111111111111111111111111111111111112 needs 1.1 SOL (snipe height + tip budget)".to_owned(),
+                    "Catch-all > * needs 1.1 SOL (snipe height + tip budget)".to_owned(),
+                ]
+            );
+        }
+    }
 }
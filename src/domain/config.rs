@@ -2,6 +2,8 @@ use serde::Deserialize;
 use std::path::PathBuf;
 use thiserror::Error;
 
+use crate::domain::value_objects::PriorityFeesMicrolamports;
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct SniperConfigFile {
     pub runtime: RuntimeConfigSection,
@@ -12,18 +14,74 @@ pub struct SniperConfigFile {
     #[serde(default)]
     pub telemetry: TelemetryConfigSection,
     #[serde(default)]
+    pub health: HealthConfigSection,
+    #[serde(default)]
+    pub addresses: AddressesConfigSection,
+    #[serde(default)]
     pub rules: Vec<RuleConfigEntry>,
+    #[serde(default)]
+    pub deployers: DeployerListsConfigSection,
 }
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct RuntimeConfigSection {
     pub keypair_path: String,
+    #[serde(default)]
+    pub expected_pubkey: Option<String>,
     pub rpc_url: String,
     pub wss_url: String,
     pub priority_fees: u64,
+    #[serde(default = "default_max_priority_fees")]
+    pub max_priority_fees: u64,
+    #[serde(default = "default_max_compute_unit_price")]
+    pub max_compute_unit_price: u64,
     #[serde(default)]
     pub dry_run: bool,
+    #[serde(default)]
+    pub paper_trading: bool,
+    #[serde(default = "default_skip_preflight")]
+    pub skip_preflight: bool,
+    #[serde(default)]
+    pub send_max_retries: usize,
+    #[serde(default)]
+    pub submit_rpc_urls: Vec<String>,
+    #[serde(default)]
+    pub fallback_rpc_urls: Vec<String>,
+    #[serde(default)]
+    pub force_strategy: Option<String>,
+    #[serde(default)]
+    pub snipe_on_error_events: bool,
+    #[serde(default)]
+    pub use_live_reserves: bool,
+    #[serde(default)]
+    pub keep_wsol_account_open: bool,
+    #[serde(default)]
+    pub wsol_close_timing: Option<String>,
+    #[serde(default)]
+    pub wsol_wrap_buffer_lamports: u64,
+    #[serde(default)]
+    pub reuse_wrapped_sol: bool,
+    #[serde(default)]
+    pub allow_zero_min_amount_out: bool,
+    #[serde(default)]
+    pub slippage_retry_attempts: u8,
+    #[serde(default)]
+    pub min_init_liquidity_sol: Option<String>,
+    #[serde(default)]
+    pub max_init_liquidity_sol: Option<String>,
+    #[serde(default)]
+    pub compute_unit_limit: Option<u32>,
+    #[serde(default)]
+    pub max_pool_open_wait_secs: u64,
+    #[serde(default)]
+    pub pre_open_offset_ms: u64,
+    #[serde(default = "default_blockhash_refresh_ms")]
+    pub blockhash_refresh_ms: u64,
+    #[serde(default = "default_blockhash_max_age_ms")]
+    pub blockhash_max_age_ms: u64,
+    #[serde(default = "default_rpc_call_timeout_ms")]
+    pub rpc_call_timeout_ms: u64,
     #[serde(default = "default_tx_submission_mode")]
     pub tx_submission_mode: String,
     #[serde(default)]
@@ -34,6 +92,112 @@ pub struct RuntimeConfigSection {
     pub replay_event_count: usize,
     #[serde(default = "default_replay_burst_size")]
     pub replay_burst_size: usize,
+    #[serde(default = "default_event_queue_capacity")]
+    pub event_queue_capacity: usize,
+    #[serde(default = "default_event_queue_drop_policy")]
+    pub event_queue_drop_policy: String,
+    #[serde(default = "default_signature_dedup_capacity")]
+    pub signature_dedup_capacity: usize,
+    #[serde(default)]
+    pub signature_dedup_path: Option<String>,
+    #[serde(default = "default_max_candidate_instructions")]
+    pub max_candidate_instructions: usize,
+    #[serde(default)]
+    pub strategy_worker_threads: Option<usize>,
+    #[serde(default)]
+    pub strategy_worker_tasks: usize,
+    #[serde(default)]
+    pub snipe_deadline_secs: u64,
+    #[serde(default)]
+    pub startup_warmup_secs: u64,
+    #[serde(default)]
+    pub snipe_outcome_path: Option<String>,
+    #[serde(default)]
+    pub session_ledger_report_secs: u64,
+    #[serde(default)]
+    pub rule_stats_report_secs: u64,
+    #[serde(default = "default_config_reload_secs")]
+    pub config_reload_secs: u64,
+    #[serde(default)]
+    pub config_watch: bool,
+    #[serde(default = "default_rpc_commitment")]
+    pub fetch_commitment: String,
+    #[serde(default = "default_rpc_commitment")]
+    pub confirmation_commitment: String,
+}
+
+impl SniperConfigFile {
+    pub fn new(runtime: RuntimeConfigSection) -> Self {
+        Self {
+            runtime,
+            sof: SofConfigSection::default(),
+            sof_tx: SofTxConfigSection::default(),
+            telemetry: TelemetryConfigSection::default(),
+            health: HealthConfigSection::default(),
+            addresses: AddressesConfigSection::default(),
+            rules: Vec::new(),
+            deployers: DeployerListsConfigSection::default(),
+        }
+    }
+}
+
+impl RuntimeConfigSection {
+    /// Runtime section with the required fields set and every other field at its TOML default.
+    pub fn new(keypair_path: String, rpc_url: String, wss_url: String, priority_fees: u64) -> Self {
+        Self {
+            keypair_path,
+            expected_pubkey: None,
+            rpc_url,
+            wss_url,
+            priority_fees,
+            max_priority_fees: default_max_priority_fees(),
+            max_compute_unit_price: default_max_compute_unit_price(),
+            dry_run: false,
+            paper_trading: false,
+            skip_preflight: default_skip_preflight(),
+            send_max_retries: 0,
+            submit_rpc_urls: Vec::new(),
+            fallback_rpc_urls: Vec::new(),
+            force_strategy: None,
+            snipe_on_error_events: false,
+            use_live_reserves: false,
+            keep_wsol_account_open: false,
+            wsol_close_timing: None,
+            wsol_wrap_buffer_lamports: 0,
+            reuse_wrapped_sol: false,
+            allow_zero_min_amount_out: false,
+            slippage_retry_attempts: 0,
+            min_init_liquidity_sol: None,
+            max_init_liquidity_sol: None,
+            compute_unit_limit: None,
+            max_pool_open_wait_secs: 0,
+            pre_open_offset_ms: 0,
+            blockhash_refresh_ms: default_blockhash_refresh_ms(),
+            blockhash_max_age_ms: default_blockhash_max_age_ms(),
+            rpc_call_timeout_ms: default_rpc_call_timeout_ms(),
+            tx_submission_mode: default_tx_submission_mode(),
+            jito_url: None,
+            replay_benchmark: false,
+            replay_event_count: default_replay_event_count(),
+            replay_burst_size: default_replay_burst_size(),
+            event_queue_capacity: default_event_queue_capacity(),
+            event_queue_drop_policy: default_event_queue_drop_policy(),
+            signature_dedup_capacity: default_signature_dedup_capacity(),
+            signature_dedup_path: None,
+            max_candidate_instructions: default_max_candidate_instructions(),
+            strategy_worker_threads: None,
+            strategy_worker_tasks: 0,
+            snipe_deadline_secs: 0,
+            startup_warmup_secs: 0,
+            snipe_outcome_path: None,
+            session_ledger_report_secs: 0,
+            rule_stats_report_secs: 0,
+            config_reload_secs: default_config_reload_secs(),
+            config_watch: false,
+            fetch_commitment: default_rpc_commitment(),
+            confirmation_commitment: default_rpc_commitment(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -178,9 +342,40 @@ pub enum RuleKind {
 pub struct RuleConfigEntry {
     pub kind: RuleKind,
     pub address: String,
-    pub snipe_height_sol: String,
-    pub tip_budget_sol: String,
+    #[serde(default)]
+    pub snipe_height_sol: Option<String>,
+    #[serde(default)]
+    pub target_token_amount: Option<u64>,
+    #[serde(default)]
+    pub max_snipe_height_sol: Option<String>,
+    #[serde(default)]
+    pub tip_budget_sol: Option<String>,
+    #[serde(default)]
+    pub tip_budget_pct: Option<String>,
+    #[serde(default)]
+    pub tip_budget_min_sol: Option<String>,
+    #[serde(default)]
+    pub tip_budget_max_sol: Option<String>,
     pub slippage_pct: String,
+    #[serde(default)]
+    pub max_slippage_pct: Option<String>,
+    #[serde(default)]
+    pub active_from: Option<String>,
+    #[serde(default)]
+    pub active_until: Option<String>,
+    #[serde(default)]
+    pub fee_multiplier: Option<String>,
+    #[serde(default)]
+    pub priority: u8,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DeployerListsConfigSection {
+    #[serde(default)]
+    pub blocklist: Vec<String>,
+    #[serde(default)]
+    pub allowlist: Vec<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -190,10 +385,18 @@ pub struct TelemetryConfigSection {
     pub enabled: bool,
     #[serde(default = "default_telemetry_sample_capacity")]
     pub sample_capacity: usize,
+    #[serde(default)]
+    pub sample_max_age_ms: u64,
     #[serde(default = "default_telemetry_slo_ns")]
     pub slo_ns: u64,
+    #[serde(default)]
+    pub slo_alert_repeat_periods: u64,
     #[serde(default = "default_telemetry_report_period_secs")]
     pub report_period_secs: u64,
+    #[serde(default)]
+    pub expected_events_per_sec: u64,
+    #[serde(default)]
+    pub strict_sample_capacity: bool,
 }
 
 impl Default for TelemetryConfigSection {
@@ -201,12 +404,65 @@ impl Default for TelemetryConfigSection {
         Self {
             enabled: default_telemetry_enabled(),
             sample_capacity: default_telemetry_sample_capacity(),
+            sample_max_age_ms: 0,
             slo_ns: default_telemetry_slo_ns(),
+            slo_alert_repeat_periods: 0,
             report_period_secs: default_telemetry_report_period_secs(),
+            expected_events_per_sec: 0,
+            strict_sample_capacity: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HealthConfigSection {
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default = "default_health_bind_address")]
+    pub bind_address: String,
+}
+
+impl Default for HealthConfigSection {
+    fn default() -> Self {
+        Self {
+            port: None,
+            bind_address: default_health_bind_address(),
         }
     }
 }
 
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AddressesConfigSection {
+    #[serde(default)]
+    pub wsol_mint: Option<String>,
+    #[serde(default)]
+    pub token_program: Option<String>,
+    #[serde(default)]
+    pub jito_tip_account: Option<String>,
+    #[serde(default)]
+    pub jito_tip_accounts: Vec<String>,
+    #[serde(default)]
+    pub raydium_cpmm_program: Option<String>,
+    #[serde(default)]
+    pub raydium_v4_program: Option<String>,
+}
+
+/// How `[[rules]]` from a `--config-override` file combine with the base file's rules.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RulesMergePolicy {
+    /// Override rules replace base rules with the same `kind` and `address`; others are appended.
+    #[default]
+    Append,
+    /// Override rules replace the base rules entirely.
+    Replace,
+}
+
+const RULES_KEY: &str = "rules";
+const RULES_MERGE_KEY: &str = "rules_merge";
+
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("failed to read config file at {path}")]
@@ -215,20 +471,94 @@ pub enum ConfigError {
         #[source]
         source: std::io::Error,
     },
-    #[error("invalid slotstrike.toml format")]
+    #[error("invalid slotstrike.toml format: {source}")]
     ParseToml {
         #[source]
         source: toml::de::Error,
     },
+    #[error("invalid config override format: {source}")]
+    ParseOverrideToml {
+        #[source]
+        source: toml::de::Error,
+    },
+}
+
+pub fn load_sniper_config_file(
+    path: &str,
+    override_path: Option<&str>,
+) -> Result<SniperConfigFile, ConfigError> {
+    let config_text = read_config_text(path)?;
+    match override_path {
+        Some(override_path) => {
+            merge_sniper_config_toml(&config_text, &read_config_text(override_path)?)
+        }
+        None => parse_sniper_config_toml(&config_text),
+    }
+}
+
+/// Where a settings error points in the config files.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigLocation {
+    pub path: String,
+    pub line: Option<usize>,
+    pub field: &'static str,
+}
+
+impl std::fmt::Display for ConfigLocation {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(formatter, "{}:{} ({})", self.path, line, self.field),
+            None => write!(formatter, "{} ({})", self.path, self.field),
+        }
+    }
 }
 
-pub fn load_sniper_config_file(path: &str) -> Result<SniperConfigFile, ConfigError> {
-    let config_text =
-        std::fs::read_to_string(path).map_err(|source| ConfigError::ReadConfigFile {
-            path: PathBuf::from(path),
-            source,
-        })?;
-    parse_sniper_config_toml(&config_text)
+/// Finds the line setting the dotted `field` in the file at `path`. `None` when the file
+/// cannot be read or leaves the key at its default.
+pub fn locate_config_key(path: &str, field: &'static str) -> Option<ConfigLocation> {
+    let config_text = std::fs::read_to_string(path).ok()?;
+    find_config_key_line(&config_text, field).map(|line| ConfigLocation {
+        path: path.to_owned(),
+        line: Some(line),
+        field,
+    })
+}
+
+/// 1-based line of `section.key` in `config_text`, under its `[section]` header or as a
+/// top-level dotted key. Spans would be exact, but they do not survive the override merge
+/// through `toml::Value`, so this scans the text instead.
+pub fn find_config_key_line(config_text: &str, field: &str) -> Option<usize> {
+    let (section, key) = field.split_once('.')?;
+    let mut current_section = "";
+    for (index, line) in config_text.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            current_section = line
+                .trim_matches(|character| character == '[' || character == ']')
+                .trim();
+            continue;
+        }
+        let Some((line_key, _value)) = line.split_once('=') else {
+            continue;
+        };
+        let line_key = line_key.trim().trim_matches('"');
+        let matches = if current_section.is_empty() {
+            line_key == field
+        } else {
+            current_section == section && line_key == key
+        };
+        if matches {
+            return Some(index.saturating_add(1));
+        }
+    }
+    None
+}
+
+fn read_config_text(path: &str) -> Result<String, ConfigError> {
+    std::fs::read_to_string(path).map_err(|source| ConfigError::ReadConfigFile {
+        path: PathBuf::from(path),
+        source,
+    })
 }
 
 pub fn parse_sniper_config_toml(config_text: &str) -> Result<SniperConfigFile, ConfigError> {
@@ -236,6 +566,106 @@ pub fn parse_sniper_config_toml(config_text: &str) -> Result<SniperConfigFile, C
         .map_err(|source| ConfigError::ParseToml { source })
 }
 
+/// Deep-merges `override_text` over `base_text` before deserializing.
+///
+/// Tables merge key by key and any other value in the override, arrays included,
+/// replaces the base value. `[[rules]]` follow the override's `rules_merge` policy.
+pub fn merge_sniper_config_toml(
+    base_text: &str,
+    override_text: &str,
+) -> Result<SniperConfigFile, ConfigError> {
+    let mut base = toml::from_str::<toml::Table>(base_text)
+        .map_err(|source| ConfigError::ParseToml { source })?;
+    let mut overrides = toml::from_str::<toml::Table>(override_text)
+        .map_err(|source| ConfigError::ParseOverrideToml { source })?;
+
+    let policy = overrides
+        .remove(RULES_MERGE_KEY)
+        .map(toml::Value::try_into::<RulesMergePolicy>)
+        .transpose()
+        .map_err(|source| ConfigError::ParseOverrideToml { source })?
+        .unwrap_or_default();
+    let override_rules = overrides.remove(RULES_KEY);
+
+    merge_tables(&mut base, overrides);
+    match (policy, override_rules) {
+        (RulesMergePolicy::Append, Some(override_rules)) => append_rules(&mut base, override_rules),
+        (RulesMergePolicy::Append, None) => {}
+        (RulesMergePolicy::Replace, override_rules) => {
+            base.insert(
+                RULES_KEY.to_owned(),
+                override_rules.unwrap_or_else(|| toml::Value::Array(Vec::new())),
+            );
+        }
+    }
+
+    toml::Value::Table(base)
+        .try_into::<SniperConfigFile>()
+        .map_err(|source| ConfigError::ParseToml { source })
+}
+
+fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        let merged = match (base.remove(&key), value) {
+            (Some(toml::Value::Table(mut base_table)), toml::Value::Table(override_table)) => {
+                merge_tables(&mut base_table, override_table);
+                toml::Value::Table(base_table)
+            }
+            (_, value) => value,
+        };
+        base.insert(key, merged);
+    }
+}
+
+fn append_rules(base: &mut toml::Table, override_rules: toml::Value) {
+    let merged = match (base.remove(RULES_KEY), override_rules) {
+        (Some(toml::Value::Array(mut rules)), toml::Value::Array(override_rules)) => {
+            for rule in override_rules {
+                match rules
+                    .iter_mut()
+                    .find(|base_rule| rule_key(base_rule) == rule_key(&rule))
+                {
+                    Some(base_rule) => *base_rule = rule,
+                    None => rules.push(rule),
+                }
+            }
+            toml::Value::Array(rules)
+        }
+        (_, override_rules) => override_rules,
+    };
+    base.insert(RULES_KEY.to_owned(), merged);
+}
+
+fn rule_key(rule: &toml::Value) -> Option<(&str, &str)> {
+    let kind = rule.get("kind")?.as_str()?;
+    let address = rule.get("address")?.as_str()?;
+    Some((kind, address.trim()))
+}
+
+const fn default_max_priority_fees() -> u64 {
+    PriorityFeesMicrolamports::DEFAULT_CEILING
+}
+
+const fn default_max_compute_unit_price() -> u64 {
+    PriorityFeesMicrolamports::DEFAULT_CEILING
+}
+
+const fn default_skip_preflight() -> bool {
+    true
+}
+
+const fn default_blockhash_refresh_ms() -> u64 {
+    2_000
+}
+
+const fn default_blockhash_max_age_ms() -> u64 {
+    20_000
+}
+
+const fn default_rpc_call_timeout_ms() -> u64 {
+    5_000
+}
+
 fn default_tx_submission_mode() -> String {
     "jito".to_owned()
 }
@@ -320,6 +750,30 @@ const fn default_sof_tx_guard_suppression_ttl_ms() -> u64 {
     750
 }
 
+fn default_rpc_commitment() -> String {
+    "confirmed".to_owned()
+}
+
+const fn default_config_reload_secs() -> u64 {
+    1
+}
+
+const fn default_event_queue_capacity() -> usize {
+    4_096
+}
+
+const fn default_signature_dedup_capacity() -> usize {
+    4_096
+}
+
+const fn default_max_candidate_instructions() -> usize {
+    256
+}
+
+fn default_event_queue_drop_policy() -> String {
+    "drop_newest".to_owned()
+}
+
 const fn default_replay_event_count() -> usize {
     50_000
 }
@@ -344,9 +798,15 @@ const fn default_telemetry_report_period_secs() -> u64 {
     15
 }
 
+fn default_health_bind_address() -> String {
+    "0.0.0.0".to_owned()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{RuleKind, parse_sniper_config_toml};
+    use super::{
+        RuleKind, find_config_key_line, merge_sniper_config_toml, parse_sniper_config_toml,
+    };
 
     #[test]
     fn parses_runtime_and_rules_from_toml() {
@@ -434,4 +894,131 @@ replay_burst_size = 512
 
         assert!(config.is_err());
     }
+
+    const MERGE_BASE: &str = r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+dry_run = true
+tx_submission_mode = "direct"
+replay_benchmark = false
+replay_event_count = 50000
+replay_burst_size = 512
+
+[telemetry]
+sample_capacity = 1024
+
+[[rules]]
+kind = "mint"
+address = "So11111111111111111111111111111111111111112"
+snipe_height_sol = "0.01"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+
+[[rules]]
+kind = "deployer"
+address = "11111111111111111111111111111111"
+snipe_height_sol = "0.01"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+"#;
+
+    #[test]
+    fn override_wins_per_field_and_keeps_unset_base_fields() {
+        let config = merge_sniper_config_toml(
+            MERGE_BASE,
+            r#"
+[runtime]
+keypair_path = "/etc/slotstrike/host.json"
+priority_fees = 2500
+
+[telemetry]
+report_period_secs = 30
+"#,
+        );
+
+        assert!(config.is_ok());
+        if let Ok(config) = config {
+            assert_eq!(config.runtime.keypair_path, "/etc/slotstrike/host.json");
+            assert_eq!(config.runtime.priority_fees, 2_500);
+            assert!(config.runtime.dry_run);
+            assert_eq!(config.telemetry.sample_capacity, 1_024);
+            assert_eq!(config.telemetry.report_period_secs, 30);
+            assert_eq!(config.rules.len(), 2);
+        }
+    }
+
+    #[test]
+    fn merges_override_rules_by_kind_and_address() {
+        let override_rules = r#"
+[[rules]]
+kind = "mint"
+address = "So11111111111111111111111111111111111111112"
+snipe_height_sol = "0.05"
+tip_budget_sol = "0.001"
+slippage_pct = "2"
+
+[[rules]]
+kind = "mint"
+address = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"
+snipe_height_sol = "0.02"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+"#;
+
+        let appended = merge_sniper_config_toml(MERGE_BASE, override_rules);
+        assert!(appended.is_ok());
+        if let Ok(config) = appended {
+            let heights = config
+                .rules
+                .iter()
+                .filter_map(|rule| rule.snipe_height_sol.as_deref())
+                .collect::<Vec<_>>();
+            assert_eq!(heights, vec!["0.05", "0.01", "0.02"]);
+        }
+
+        let replaced = merge_sniper_config_toml(
+            MERGE_BASE,
+            &format!("rules_merge = \"replace\"\n{override_rules}"),
+        );
+        assert!(replaced.is_ok());
+        if let Ok(config) = replaced {
+            assert_eq!(config.rules.len(), 2);
+            assert!(config.rules.iter().all(|rule| rule.kind == RuleKind::Mint));
+        }
+
+        assert!(merge_sniper_config_toml(MERGE_BASE, "rules_merge = \"merge\"").is_err());
+    }
+
+    #[test]
+    fn finds_the_line_setting_a_dotted_key() {
+        let config_text = r#"
+[runtime]
+keypair_path = "keypair.json"
+event_queue_drop_policy = "drop_sideways"
+
+[sof]
+"source" = "grpc"
+
+[[rules]]
+kind = "mint"
+"#;
+
+        assert_eq!(
+            find_config_key_line(config_text, "runtime.event_queue_drop_policy"),
+            Some(4)
+        );
+        assert_eq!(find_config_key_line(config_text, "sof.source"), Some(7));
+        assert_eq!(find_config_key_line(config_text, "sof.keypair_path"), None);
+        assert_eq!(
+            find_config_key_line(config_text, "runtime.priority_fees"),
+            None
+        );
+        assert_eq!(
+            find_config_key_line("runtime.priority_fees = 1\n", "runtime.priority_fees"),
+            Some(1)
+        );
+    }
 }
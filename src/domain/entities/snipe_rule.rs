@@ -1,12 +1,22 @@
 use std::sync::Arc;
 
-use crate::domain::value_objects::{RuleAddress, RuleSlippageBps, RuleSolAmount};
+use crate::domain::value_objects::{
+    RuleActiveWindow, RuleAddress, RuleFeeMultiplier, RuleSlippageBps, RuleSolAmount, RuleTipPct,
+    sol_amount::Lamports,
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct SnipeRuleHot {
     snipe_height: RuleSolAmount,
     jito_tip: RuleSolAmount,
     slippage: RuleSlippageBps,
+    active_window: Option<RuleActiveWindow>,
+    fee_multiplier: RuleFeeMultiplier,
+    priority: u8,
+    target_token_amount: Option<u64>,
+    jito_tip_pct: Option<RuleTipPct>,
+    jito_tip_floor: RuleSolAmount,
+    max_slippage: Option<RuleSlippageBps>,
 }
 
 impl SnipeRuleHot {
@@ -20,9 +30,59 @@ impl SnipeRuleHot {
             snipe_height,
             jito_tip,
             slippage,
+            active_window: None,
+            fee_multiplier: RuleFeeMultiplier::ONE,
+            priority: 0,
+            target_token_amount: None,
+            jito_tip_pct: None,
+            jito_tip_floor: RuleSolAmount::new(Lamports::new(0)),
+            max_slippage: None,
         }
     }
 
+    #[inline(always)]
+    pub const fn with_active_window(mut self, active_window: Option<RuleActiveWindow>) -> Self {
+        self.active_window = active_window;
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_fee_multiplier(mut self, fee_multiplier: RuleFeeMultiplier) -> Self {
+        self.fee_multiplier = fee_multiplier;
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_target_token_amount(mut self, target_token_amount: Option<u64>) -> Self {
+        self.target_token_amount = target_token_amount;
+        self
+    }
+
+    /// Tips `pct` of the swap input instead of the fixed `jito_tip`, which then caps the
+    /// tip; `floor` is the smallest tip paid.
+    #[inline(always)]
+    pub const fn with_jito_tip_pct(
+        mut self,
+        pct: Option<RuleTipPct>,
+        floor: RuleSolAmount,
+    ) -> Self {
+        self.jito_tip_pct = pct;
+        self.jito_tip_floor = floor;
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_max_slippage(mut self, max_slippage: Option<RuleSlippageBps>) -> Self {
+        self.max_slippage = max_slippage;
+        self
+    }
+
     #[inline(always)]
     pub const fn snipe_height(self) -> RuleSolAmount {
         self.snipe_height
@@ -37,6 +97,63 @@ impl SnipeRuleHot {
     pub const fn slippage(self) -> RuleSlippageBps {
         self.slippage
     }
+
+    #[inline(always)]
+    pub const fn active_window(self) -> Option<RuleActiveWindow> {
+        self.active_window
+    }
+
+    #[inline(always)]
+    pub const fn fee_multiplier(self) -> RuleFeeMultiplier {
+        self.fee_multiplier
+    }
+
+    /// Dispatch order under contention; higher values run first.
+    #[inline(always)]
+    pub const fn priority(self) -> u8 {
+        self.priority
+    }
+
+    /// Raw token units to buy instead of spending `snipe_height`, which then caps the SOL
+    /// input derived from the pool's reserves at send time.
+    #[inline(always)]
+    pub const fn target_token_amount(self) -> Option<u64> {
+        self.target_token_amount
+    }
+
+    #[inline(always)]
+    pub const fn jito_tip_pct(self) -> Option<RuleTipPct> {
+        self.jito_tip_pct
+    }
+
+    #[inline(always)]
+    pub const fn jito_tip_floor(self) -> RuleSolAmount {
+        self.jito_tip_floor
+    }
+
+    /// Widest slippage a resend after a slippage failure may use; `None` never resends.
+    #[inline(always)]
+    pub const fn max_slippage(self) -> Option<RuleSlippageBps> {
+        self.max_slippage
+    }
+
+    /// Tip for a swap spending `swap_lamports`: the fixed `jito_tip`, or `jito_tip_pct` of the
+    /// swap clamped between the floor and `jito_tip`.
+    #[inline(always)]
+    pub fn jito_tip_for(self, swap_lamports: u64) -> Lamports {
+        let ceiling = self.jito_tip.as_lamports();
+        self.jito_tip_pct.map_or(ceiling, |pct| {
+            pct.of_lamports(Lamports::new(swap_lamports))
+                .max(self.jito_tip_floor.as_lamports())
+                .min(ceiling)
+        })
+    }
+
+    #[inline(always)]
+    pub fn is_active_at(self, minute_of_day: u16) -> bool {
+        self.active_window
+            .is_none_or(|window| window.contains(minute_of_day))
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -64,6 +181,46 @@ impl SnipeRule {
         }
     }
 
+    #[inline(always)]
+    pub const fn with_active_window(mut self, active_window: Option<RuleActiveWindow>) -> Self {
+        self.hot = self.hot.with_active_window(active_window);
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_fee_multiplier(mut self, fee_multiplier: RuleFeeMultiplier) -> Self {
+        self.hot = self.hot.with_fee_multiplier(fee_multiplier);
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_priority(mut self, priority: u8) -> Self {
+        self.hot = self.hot.with_priority(priority);
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_target_token_amount(mut self, target_token_amount: Option<u64>) -> Self {
+        self.hot = self.hot.with_target_token_amount(target_token_amount);
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_jito_tip_pct(
+        mut self,
+        pct: Option<RuleTipPct>,
+        floor: RuleSolAmount,
+    ) -> Self {
+        self.hot = self.hot.with_jito_tip_pct(pct, floor);
+        self
+    }
+
+    #[inline(always)]
+    pub const fn with_max_slippage(mut self, max_slippage: Option<RuleSlippageBps>) -> Self {
+        self.hot = self.hot.with_max_slippage(max_slippage);
+        self
+    }
+
     #[inline(always)]
     pub const fn hot(&self) -> SnipeRuleHot {
         self.hot
@@ -94,14 +251,102 @@ impl SnipeRule {
         self.hot.slippage
     }
 
+    #[inline(always)]
+    pub const fn active_window(&self) -> Option<RuleActiveWindow> {
+        self.hot.active_window
+    }
+
+    #[inline(always)]
+    pub const fn fee_multiplier(&self) -> RuleFeeMultiplier {
+        self.hot.fee_multiplier
+    }
+
+    #[inline(always)]
+    pub const fn priority(&self) -> u8 {
+        self.hot.priority
+    }
+
+    #[inline(always)]
+    pub const fn target_token_amount(&self) -> Option<u64> {
+        self.hot.target_token_amount
+    }
+
+    #[inline(always)]
+    pub const fn jito_tip_pct(&self) -> Option<RuleTipPct> {
+        self.hot.jito_tip_pct
+    }
+
+    #[inline(always)]
+    pub const fn max_slippage(&self) -> Option<RuleSlippageBps> {
+        self.hot.max_slippage
+    }
+
+    /// Lamports one snipe can spend: the snipe height plus the tip budget, which for
+    /// percentage tips is their cap. For token-amount rules the snipe height is their SOL cap.
+    pub const fn required_lamports(&self) -> Lamports {
+        match self
+            .snipe_height()
+            .as_lamports()
+            .checked_add(self.jito_tip().as_lamports())
+        {
+            Some(required) => required,
+            None => Lamports::new(u64::MAX),
+        }
+    }
+
     pub fn as_log_line(&self, label: &str) -> String {
+        let active_window = self
+            .active_window()
+            .map(|window| format!(" \\n\t\t\tActive window: {}", window.as_hhmm_string()))
+            .unwrap_or_default();
+        let fee_multiplier = if self.fee_multiplier() == RuleFeeMultiplier::ONE {
+            String::new()
+        } else {
+            format!(
+                " \\n\t\t\tFee multiplier: {}x",
+                self.fee_multiplier().as_decimal_string()
+            )
+        };
+        let priority = match self.priority() {
+            0 => String::new(),
+            priority => format!(" \\n\t\t\tPriority: {}", priority),
+        };
+        let amount = self.target_token_amount().map_or_else(
+            || format!("Snipe height: {} SOL", self.snipe_height().as_sol_string()),
+            |tokens| {
+                format!(
+                    "Target amount: {} tokens (up to {} SOL)",
+                    tokens,
+                    self.snipe_height().as_sol_string()
+                )
+            },
+        );
+        let jito_tip = self.jito_tip_pct().map_or_else(
+            || format!("{} SOL", self.jito_tip().as_sol_string()),
+            |pct| {
+                format!(
+                    "{} % ({}-{} SOL)",
+                    pct.as_pct_string(),
+                    self.hot.jito_tip_floor.as_sol_string(),
+                    self.jito_tip().as_sol_string()
+                )
+            },
+        );
+        let max_slippage = self
+            .max_slippage()
+            .map(|max_slippage| format!(" (retries up to {} %)", max_slippage.as_pct_string()))
+            .unwrap_or_default();
         format!(
-            "{} > {} \\n\t\t\tSnipe height: {} SOL \\n\t\t\tJito tip: {} SOL \\n\t\t\tSlippage: {} %",
+            "{} > {} \\n\t\t\t{} \\n\t\t\tJito tip: {} \\n\t\t\tSlippage: {} %{}{}{}{}",
             label,
             self.address(),
-            self.snipe_height().as_sol_string(),
-            self.jito_tip().as_sol_string(),
+            amount,
+            jito_tip,
             self.slippage().as_pct_string(),
+            max_slippage,
+            active_window,
+            fee_multiplier,
+            priority,
         )
     }
 }
@@ -110,7 +355,7 @@ impl SnipeRule {
 mod tests {
     use super::SnipeRule;
     use crate::domain::value_objects::{
-        RuleAddress, RuleSlippageBps, RuleSolAmount, sol_amount::Lamports,
+        RuleAddress, RuleSlippageBps, RuleSolAmount, RuleTipPct, sol_amount::Lamports,
     };
 
     fn build_rule() -> Option<SnipeRule> {
@@ -153,4 +398,27 @@ mod tests {
             assert!(line.contains("Slippage: 1.50 %"));
         }
     }
+
+    #[test]
+    fn clamps_percentage_tips_to_the_rule_bounds() {
+        let rule = build_rule();
+        let pct = RuleTipPct::from_pct_str("1");
+        assert!(rule.is_some() && pct.is_ok());
+
+        if let (Some(rule), Ok(pct)) = (rule, pct) {
+            assert_eq!(rule.hot().jito_tip_for(5_000_000_000).as_u64(), 100_000_000);
+
+            let rule =
+                rule.with_jito_tip_pct(Some(pct), RuleSolAmount::new(Lamports::new(1_000_000)));
+            let hot = rule.hot();
+            // 1% of 2 SOL, inside the 0.001-0.1 SOL bounds.
+            assert_eq!(hot.jito_tip_for(2_000_000_000).as_u64(), 20_000_000);
+            assert_eq!(hot.jito_tip_for(50_000_000).as_u64(), 1_000_000);
+            assert_eq!(hot.jito_tip_for(50_000_000_000).as_u64(), 100_000_000);
+            assert!(
+                rule.as_log_line("Token address")
+                    .contains("Jito tip: 1.00 % (0.001-0.1 SOL)")
+            );
+        }
+    }
 }
@@ -1,12 +1,18 @@
 use std::{
-    sync::Arc,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
     time::{SystemTime, UNIX_EPOCH},
 };
 
+use serde::Serialize;
 use solana_sdk::transaction::VersionedTransaction;
 
 const HARDWARE_TIMESTAMP_MAX_SKEW_NS: u64 = 5_000_000_000;
 
+static NEXT_EVENT_ID: AtomicU64 = AtomicU64::new(1);
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum IngressSource {
     Websocket,
@@ -31,6 +37,11 @@ pub struct IngressMetadata {
     pub hardware_timestamp_ns: Option<u64>,
     pub received_timestamp_ns: u64,
     pub normalized_timestamp_ns: u64,
+    /// Slot the candidate transaction was observed in, when the source reports one.
+    pub slot: Option<u64>,
+    /// Per-process id tying together the log lines of one candidate; 0 when ingress did not
+    /// assign one.
+    pub event_id: u64,
 }
 
 impl IngressMetadata {
@@ -48,22 +59,64 @@ impl IngressMetadata {
                 hardware_timestamp_ns,
                 received_timestamp_ns,
             ),
+            slot: None,
+            event_id: 0,
         }
     }
 
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_slot(mut self, slot: u64) -> Self {
+        self.slot = Some(slot);
+        self
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn with_event_id(mut self, event_id: u64) -> Self {
+        self.event_id = event_id;
+        self
+    }
+
     #[inline(always)]
     pub const fn from_receive_clock(source: IngressSource, received_timestamp_ns: u64) -> Self {
         Self::from_hardware_clock(source, None, received_timestamp_ns)
     }
+
+    #[inline(always)]
+    pub const fn hardware_clock_skew_ns(&self) -> Option<u64> {
+        match self.hardware_timestamp_ns {
+            Some(value) if value != 0 => Some(value.abs_diff(self.received_timestamp_ns)),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn hardware_clock_clamped(&self) -> bool {
+        match self.hardware_clock_skew_ns() {
+            Some(skew_ns) => skew_ns > HARDWARE_TIMESTAMP_MAX_SKEW_NS,
+            None => false,
+        }
+    }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum RaydiumCandidateKind {
     Cpmm,
     OpenBook,
 }
 
 impl RaydiumCandidateKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "cpmm" => Some(Self::Cpmm),
+            "openbook" => Some(Self::OpenBook),
+            _ => None,
+        }
+    }
+
     #[inline(always)]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -71,6 +124,15 @@ impl RaydiumCandidateKind {
             Self::OpenBook => "openbook",
         }
     }
+
+    /// Display name used in log lines and the `--classify` report.
+    #[inline(always)]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Cpmm => "CPMM",
+            Self::OpenBook => "OpenBook",
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -113,10 +175,30 @@ pub fn unix_timestamp_now_ns() -> u64 {
     u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
 }
 
+/// Next ingress event id. Ids start at 1 and increase for the life of the process.
+#[inline(always)]
+pub fn next_event_id() -> u64 {
+    NEXT_EVENT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Time elapsed since ingress, measured from the normalized (hardware-or-receive) timestamp.
+#[inline(always)]
+pub fn ingress_latency_ns(ingress: &IngressMetadata) -> u64 {
+    ingress_latency_ns_at(ingress, unix_timestamp_now_ns())
+}
+
+#[inline(always)]
+pub const fn ingress_latency_ns_at(ingress: &IngressMetadata, now_ns: u64) -> u64 {
+    now_ns.saturating_sub(ingress.normalized_timestamp_ns)
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use super::{
-        IngressMetadata, IngressSource, normalize_hardware_timestamp_ns, unix_timestamp_now_ns,
+        IngressMetadata, IngressSource, ingress_latency_ns_at, next_event_id,
+        normalize_hardware_timestamp_ns, unix_timestamp_now_ns,
     };
 
     #[test]
@@ -149,5 +231,71 @@ mod tests {
         assert_eq!(metadata.source, IngressSource::Websocket);
         assert_eq!(metadata.hardware_timestamp_ns, None);
         assert_eq!(metadata.normalized_timestamp_ns, receive_ns);
+        assert_eq!(metadata.hardware_clock_skew_ns(), None);
+        assert!(!metadata.hardware_clock_clamped());
+    }
+
+    #[test]
+    fn reports_hardware_clock_skew_and_clamp() {
+        let receive_ns = 10_000_000_000_u64;
+        let within = IngressMetadata::from_hardware_clock(
+            IngressSource::PrivateShred,
+            Some(receive_ns.saturating_sub(250_000)),
+            receive_ns,
+        );
+        assert_eq!(within.hardware_clock_skew_ns(), Some(250_000));
+        assert!(!within.hardware_clock_clamped());
+
+        let outlier = IngressMetadata::from_hardware_clock(
+            IngressSource::PrivateShred,
+            Some(receive_ns.saturating_add(10_000_000_000)),
+            receive_ns,
+        );
+        assert_eq!(outlier.hardware_clock_skew_ns(), Some(10_000_000_000));
+        assert!(outlier.hardware_clock_clamped());
+        assert_eq!(outlier.normalized_timestamp_ns, receive_ns);
+    }
+
+    #[test]
+    fn measures_ingress_latency_from_normalized_timestamp() {
+        let receive_ns = 10_000_000_000_u64;
+        let hardware = IngressMetadata::from_hardware_clock(
+            IngressSource::PrivateShred,
+            Some(9_999_750_000),
+            receive_ns,
+        );
+        assert_eq!(ingress_latency_ns_at(&hardware, 10_000_250_000), 500_000);
+
+        let clamped = IngressMetadata::from_hardware_clock(
+            IngressSource::PrivateShred,
+            Some(20_000_000_000),
+            receive_ns,
+        );
+        assert_eq!(ingress_latency_ns_at(&clamped, 10_000_250_000), 250_000);
+        assert_eq!(ingress_latency_ns_at(&clamped, 0), 0);
+    }
+
+    #[test]
+    fn assigns_unique_increasing_event_ids() {
+        let first = next_event_id();
+        let second = next_event_id();
+        assert!(first > 0);
+        assert!(second > first);
+
+        let handles = (0..4)
+            .map(|_| std::thread::spawn(|| (0..256).map(|_| next_event_id()).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        let mut seen = HashSet::new();
+        for handle in handles {
+            let ids = handle.join().unwrap_or_default();
+            assert_eq!(ids.len(), 256);
+            assert!(ids.windows(2).all(|pair| matches!(pair, [a, b] if b > a)));
+            assert!(ids.iter().all(|id| *id > second && seen.insert(*id)));
+        }
+        assert_eq!(seen.len(), 1024);
+
+        let metadata =
+            IngressMetadata::from_receive_clock(IngressSource::Grpc, 1).with_event_id(second);
+        assert_eq!(metadata.event_id, second);
     }
 }
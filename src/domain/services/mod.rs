@@ -1,3 +1,3 @@
 pub mod rule_matcher;
 
-pub use rule_matcher::RuleMatcher;
+pub use rule_matcher::{MatchedRule, RuleMatcher};
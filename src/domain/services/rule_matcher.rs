@@ -1,15 +1,32 @@
 use std::sync::Arc;
 
+use chrono::{Timelike, Utc};
+
 use crate::domain::{
     aggregates::RuleBook,
     entities::{SnipeRuleCold, SnipeRuleHot},
-    specifications::{DeployerAddressMatchSpecification, MintAddressMatchSpecification},
+    specifications::{
+        CatchAllMatchSpecification, DeployerAddressMatchSpecification,
+        MintAddressMatchSpecification,
+    },
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RuleSource {
     Mint,
     Deployer,
+    CatchAll,
+}
+
+impl RuleSource {
+    #[inline(always)]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Mint => "mint",
+            Self::Deployer => "deployer",
+            Self::CatchAll => "catch_all",
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -28,8 +45,29 @@ impl RuleMatcher {
         token_address: &str,
         deployer_address: &str,
     ) -> Option<MatchedRule> {
+        Self::match_rule_at(
+            rule_book,
+            token_address,
+            deployer_address,
+            utc_minute_of_day(),
+        )
+    }
+
+    pub fn match_rule_at(
+        rule_book: &RuleBook,
+        token_address: &str,
+        deployer_address: &str,
+        minute_of_day: u16,
+    ) -> Option<MatchedRule> {
+        if !rule_book.is_deployer_permitted(deployer_address) {
+            return None;
+        }
+
         let mint_specification = MintAddressMatchSpecification::new(token_address);
-        if let Some(rule) = mint_specification.select(rule_book) {
+        if let Some(rule) = mint_specification
+            .select(rule_book)
+            .filter(|rule| rule.hot().is_active_at(minute_of_day))
+        {
             return Some(MatchedRule {
                 source: RuleSource::Mint,
                 hot: rule.hot(),
@@ -38,25 +76,50 @@ impl RuleMatcher {
         }
 
         let deployer_specification = DeployerAddressMatchSpecification::new(deployer_address);
-        deployer_specification
+        if let Some(rule) = deployer_specification
             .select(rule_book)
-            .map(|rule| MatchedRule {
+            .filter(|rule| rule.hot().is_active_at(minute_of_day))
+        {
+            return Some(MatchedRule {
                 source: RuleSource::Deployer,
                 hot: rule.hot(),
                 cold: rule.cold_arc(),
+            });
+        }
+
+        CatchAllMatchSpecification
+            .select(rule_book)
+            .filter(|rule| rule.hot().is_active_at(minute_of_day))
+            .map(|rule| MatchedRule {
+                source: RuleSource::CatchAll,
+                hot: rule.hot(),
+                cold: rule.cold_arc(),
             })
     }
 }
 
+fn utc_minute_of_day() -> u16 {
+    let now = Utc::now();
+    let minute_of_day = now.hour().saturating_mul(60).saturating_add(now.minute());
+    u16::try_from(minute_of_day).unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::{RuleMatcher, RuleSource};
     use crate::domain::{
         aggregates::RuleBook,
         entities::SnipeRule,
-        value_objects::{RuleAddress, RuleSlippageBps, RuleSolAmount, sol_amount::Lamports},
+        value_objects::{
+            RuleActiveWindow, RuleAddress, RuleSlippageBps, RuleSolAmount, sol_amount::Lamports,
+        },
     };
 
+    fn build_windowed_rule(address: &str, from: &str, until: &str) -> Option<SnipeRule> {
+        let window = RuleActiveWindow::from_hhmm_strs(from, until).ok()?;
+        build_rule(address).map(|rule| rule.with_active_window(Some(window)))
+    }
+
     fn build_rule(address: &str) -> Option<SnipeRule> {
         let address = RuleAddress::try_from(address).ok()?;
         let slippage = RuleSlippageBps::from_pct_str("1").ok()?;
@@ -107,4 +170,176 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn catch_all_is_lowest_priority_fallback() {
+        let mint = build_rule("So11111111111111111111111111111111111111112");
+        let deployer = build_rule("11111111111111111111111111111111");
+        let catch_all = build_rule("*");
+        assert!(mint.is_some());
+        assert!(deployer.is_some());
+        assert!(catch_all.is_some());
+
+        if let (Some(mint), Some(deployer), Some(catch_all)) = (mint, deployer, catch_all) {
+            let book = RuleBook::new(vec![mint], vec![deployer, catch_all]);
+
+            let by_mint = RuleMatcher::match_rule(
+                &book,
+                "So11111111111111111111111111111111111111112",
+                "11111111111111111111111111111111",
+            );
+            assert_eq!(
+                by_mint.map(|matched| matched.source),
+                Some(RuleSource::Mint)
+            );
+
+            let by_deployer = RuleMatcher::match_rule(
+                &book,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                "11111111111111111111111111111111",
+            );
+            assert_eq!(
+                by_deployer.map(|matched| matched.source),
+                Some(RuleSource::Deployer)
+            );
+
+            let by_catch_all = RuleMatcher::match_rule(
+                &book,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                "Vote111111111111111111111111111111111111111",
+            );
+            assert_eq!(
+                by_catch_all.map(|matched| matched.source),
+                Some(RuleSource::CatchAll)
+            );
+        }
+    }
+
+    #[test]
+    fn blocklisted_deployer_overrides_mint_match() {
+        let mint = build_rule("So11111111111111111111111111111111111111112");
+        let blocked = RuleAddress::try_from("11111111111111111111111111111111");
+        assert!(mint.is_some());
+        assert!(blocked.is_ok());
+
+        if let (Some(mint), Ok(blocked)) = (mint, blocked) {
+            let book = RuleBook::new(vec![mint], Vec::new())
+                .with_deployer_lists(vec![blocked], Vec::new());
+            let by_blocked = RuleMatcher::match_rule(
+                &book,
+                "So11111111111111111111111111111111111111112",
+                "11111111111111111111111111111111",
+            );
+            assert!(by_blocked.is_none());
+
+            let by_other = RuleMatcher::match_rule(
+                &book,
+                "So11111111111111111111111111111111111111112",
+                "Vote111111111111111111111111111111111111111",
+            );
+            assert_eq!(
+                by_other.map(|matched| matched.source),
+                Some(RuleSource::Mint)
+            );
+        }
+    }
+
+    #[test]
+    fn allowlist_restricts_matches_to_listed_deployers() {
+        let catch_all = build_rule("*");
+        let allowed = RuleAddress::try_from("11111111111111111111111111111111");
+        assert!(catch_all.is_some());
+        assert!(allowed.is_ok());
+
+        if let (Some(catch_all), Ok(allowed)) = (catch_all, allowed) {
+            let book = RuleBook::new(Vec::new(), vec![catch_all])
+                .with_deployer_lists(Vec::new(), vec![allowed]);
+            let by_allowed = RuleMatcher::match_rule(
+                &book,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                "11111111111111111111111111111111",
+            );
+            assert_eq!(
+                by_allowed.map(|matched| matched.source),
+                Some(RuleSource::CatchAll)
+            );
+
+            let by_unlisted = RuleMatcher::match_rule(
+                &book,
+                "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+                "Vote111111111111111111111111111111111111111",
+            );
+            assert!(by_unlisted.is_none());
+        }
+    }
+
+    #[test]
+    fn matches_rule_inside_active_window() {
+        let mint = build_windowed_rule(
+            "So11111111111111111111111111111111111111112",
+            "09:00",
+            "17:00",
+        );
+        assert!(mint.is_some());
+
+        if let Some(mint) = mint {
+            let book = RuleBook::new(vec![mint], Vec::new());
+            let matched = RuleMatcher::match_rule_at(
+                &book,
+                "So11111111111111111111111111111111111111112",
+                "11111111111111111111111111111111",
+                720,
+            );
+            assert!(matched.is_some());
+        }
+    }
+
+    #[test]
+    fn skips_rule_outside_active_window() {
+        let mint = build_windowed_rule(
+            "So11111111111111111111111111111111111111112",
+            "09:00",
+            "17:00",
+        );
+        let deployer = build_rule("11111111111111111111111111111111");
+        assert!(mint.is_some());
+        assert!(deployer.is_some());
+
+        if let (Some(mint), Some(deployer)) = (mint, deployer) {
+            let book = RuleBook::new(vec![mint], vec![deployer]);
+            let matched = RuleMatcher::match_rule_at(
+                &book,
+                "So11111111111111111111111111111111111111112",
+                "11111111111111111111111111111111",
+                1_080,
+            );
+            assert_eq!(
+                matched.map(|matched| matched.source),
+                Some(RuleSource::Deployer)
+            );
+        }
+    }
+
+    #[test]
+    fn honors_active_window_wrapping_midnight() {
+        let mint = build_windowed_rule(
+            "So11111111111111111111111111111111111111112",
+            "22:00",
+            "02:00",
+        );
+        assert!(mint.is_some());
+
+        if let Some(mint) = mint {
+            let book = RuleBook::new(vec![mint], Vec::new());
+            for (minute_of_day, expected) in [(1_380, true), (60, true), (720, false)] {
+                let matched = RuleMatcher::match_rule_at(
+                    &book,
+                    "So11111111111111111111111111111111111111112",
+                    "11111111111111111111111111111111",
+                    minute_of_day,
+                );
+                assert_eq!(matched.is_some(), expected);
+            }
+        }
+    }
 }
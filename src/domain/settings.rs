@@ -1,15 +1,31 @@
-use std::{env, net::SocketAddr};
+use std::{
+    env,
+    net::{IpAddr, SocketAddr},
+};
+
+use serde::Serialize;
 use thiserror::Error;
 
 use crate::domain::{
-    config::{ConfigError, SniperConfigFile, load_sniper_config_file},
+    config::{
+        AddressesConfigSection, ConfigError, ConfigLocation, HealthConfigSection,
+        RuntimeConfigSection, SniperConfigFile, SofConfigSection, SofTxConfigSection,
+        TelemetryConfigSection, load_sniper_config_file, locate_config_key,
+    },
+    events::RaydiumCandidateKind,
     value_objects::{
-        NonEmptyText, PriorityFeesMicrolamports, ReplayBurstSize, ReplayEventCount,
-        SofCommitmentLevel, SofGossipRuntimeMode, SofIngressSource, SofTxJitoTransport, SofTxMode,
-        SofTxReliability, SofTxRoute, SofTxStrategy, TxSubmissionMode,
+        EventQueueDropPolicy, ExecutionMode, NonEmptyText, PriorityFeesMicrolamports,
+        ReplayBurstSize, ReplayEventCount, SofCommitmentLevel, SofGossipRuntimeMode,
+        SofIngressSource, SofTxJitoTransport, SofTxMode, SofTxReliability, SofTxRoute,
+        SofTxStrategy, TxSubmissionMode, WsolCloseTiming,
+        sol_amount::{InitLiquidityBand, Lamports, parse_positive_sol_str_to_lamports},
     },
 };
 
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+pub const DEFAULT_CONFIG_PATH: &str = "slotstrike.toml";
+pub const CONFIG_PATH_ENV: &str = "SLOTSTRIKE_CONFIG";
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RequiredRuntimeField {
     KeypairPath,
@@ -38,6 +54,9 @@ pub enum NonEmptyRuntimeField {
     WebsocketUrl,
     GrpcUrl,
     PrivateShredSocketPath,
+    KeypairPath,
+    SignatureDedupPath,
+    SnipeOutcomePath,
 }
 
 impl NonEmptyRuntimeField {
@@ -46,6 +65,9 @@ impl NonEmptyRuntimeField {
             Self::WebsocketUrl => "sof.websocket_url",
             Self::GrpcUrl => "sof.grpc_url",
             Self::PrivateShredSocketPath => "sof.private_shred_socket_path",
+            Self::KeypairPath => "runtime.keypair_path",
+            Self::SignatureDedupPath => "runtime.signature_dedup_path",
+            Self::SnipeOutcomePath => "runtime.snipe_outcome_path",
         }
     }
 }
@@ -108,6 +130,12 @@ pub enum SettingsError {
     Runtime(#[from] RuntimeSettingsError),
     #[error(transparent)]
     Telemetry(#[from] TelemetrySettingsError),
+    #[error("{location}: {source}")]
+    Located {
+        location: ConfigLocation,
+        #[source]
+        source: Box<SettingsError>,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -120,6 +148,8 @@ pub enum ReplaySettingsError {
 pub enum RuntimeSettingsError {
     #[error("invalid tx_submission_mode; supported values: jito, direct")]
     InvalidTxSubmissionMode,
+    #[error("invalid force_strategy; supported values: cpmm, openbook")]
+    InvalidForceStrategy,
     #[error("invalid sof.source; supported values: websocket, grpc, private_shred")]
     InvalidSofIngressSource,
     #[error("invalid sof.commitment; supported values: processed, confirmed, finalized")]
@@ -154,26 +184,171 @@ pub enum RuntimeSettingsError {
     MissingSofDirectGossipEntrypoints,
     #[error("sof.ingest_queue_capacity must be greater than 0 when configured")]
     InvalidSofIngestQueueCapacity,
+    #[error("runtime.event_queue_capacity must be greater than 0")]
+    InvalidEventQueueCapacity,
+    #[error("runtime.config_reload_secs must be greater than 0")]
+    InvalidConfigReloadSecs,
+    #[error("runtime.max_candidate_instructions must be greater than 0")]
+    InvalidMaxCandidateInstructions,
+    #[error("invalid runtime.event_queue_drop_policy; supported values: drop_newest, drop_oldest")]
+    InvalidEventQueueDropPolicy,
+    #[error(
+        "invalid runtime.wsol_close_timing; supported values: inline, after_confirmation, never"
+    )]
+    InvalidWsolCloseTiming,
+    #[error("runtime.keep_wsol_account_open = true conflicts with runtime.wsol_close_timing")]
+    ConflictingWsolCloseTiming,
+    #[error(
+        "runtime.reuse_wrapped_sol = true has nothing to reuse with runtime.wsol_close_timing = \"inline\""
+    )]
+    ReuseWrappedSolWithInlineClose,
+    #[error("invalid {field}; supported values: processed, confirmed, finalized")]
+    InvalidRpcCommitment { field: &'static str },
+    #[error("runtime.strategy_worker_threads must be greater than 0 when configured")]
+    InvalidStrategyWorkerThreads,
+    #[error("runtime.compute_unit_limit must be between 1 and 1400000 when configured")]
+    InvalidComputeUnitLimit,
+    #[error("{field} must be a positive SOL amount when configured")]
+    InvalidInitLiquidity { field: &'static str },
+    #[error("runtime.min_init_liquidity_sol must not exceed runtime.max_init_liquidity_sol")]
+    InvertedInitLiquidityBand,
+    #[error(
+        "runtime.blockhash_max_age_ms ({max_age_ms}) must be at least runtime.blockhash_refresh_ms ({refresh_ms})"
+    )]
+    InvalidBlockhashMaxAge { refresh_ms: u64, max_age_ms: u64 },
     #[error("legacy ingress has been removed; Slotstrike now requires sof.enabled=true")]
     LegacyIngressRemoved,
+    #[error("priority_fees {value} exceeds max_priority_fees {ceiling}")]
+    PriorityFeeTooHigh { value: u64, ceiling: u64 },
+    #[error("invalid health.bind_address '{value}'")]
+    InvalidHealthBindAddress { value: String },
     #[error("missing {field} in runtime config")]
     MissingRuntimeField { field: RequiredRuntimeField },
     #[error("{field} must not be empty")]
     EmptyRuntimeField { field: NonEmptyRuntimeField },
+    #[error("{field} must not contain control characters such as newlines or NUL bytes")]
+    InvalidPathChars { field: NonEmptyRuntimeField },
 }
 
 #[derive(Debug, Error)]
 pub enum TelemetrySettingsError {
     #[error("{field} must be greater than 0 when telemetry.enabled=true")]
     InvalidEnabledValue { field: TelemetryField },
+    #[error(
+        "telemetry.sample_capacity ({capacity}) holds fewer than the {expected} samples expected per report at telemetry.expected_events_per_sec"
+    )]
+    UndersizedSampleCapacity { capacity: usize, expected: u64 },
 }
 
-#[derive(Clone, Debug)]
+impl SettingsError {
+    /// Dotted `section.key` path of the config value the error is about, when there is one.
+    pub const fn field(&self) -> Option<&'static str> {
+        match self {
+            Self::Config(_) => None,
+            Self::Replay(error) => Some(error.field()),
+            Self::Runtime(error) => Some(error.field()),
+            Self::Telemetry(error) => Some(error.field()),
+            Self::Located { location, .. } => Some(location.field),
+        }
+    }
+}
+
+impl ReplaySettingsError {
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::MustBeGreaterThanZero { field } => match field {
+                ReplayField::ReplayEventCount => "runtime.replay_event_count",
+                ReplayField::ReplayBurstSize => "runtime.replay_burst_size",
+            },
+        }
+    }
+}
+
+impl RuntimeSettingsError {
+    /// For errors spanning several keys, the one most likely to need the edit.
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::InvalidTxSubmissionMode => "runtime.tx_submission_mode",
+            Self::InvalidForceStrategy => "runtime.force_strategy",
+            Self::InvalidSofIngressSource => "sof.source",
+            Self::InvalidSofCommitment => "sof.commitment",
+            Self::InvalidSofGossipRuntimeMode => "sof.gossip_runtime_mode",
+            Self::InvalidSofPrivateShredSourceAddr { .. } => "sof.private_shred_source_addr",
+            Self::InvalidSofTxMode => "sof_tx.mode",
+            Self::InvalidSofTxStrategy => "sof_tx.strategy",
+            Self::InvalidSofTxReliability => "sof_tx.reliability",
+            Self::InvalidSofTxJitoTransport => "sof_tx.jito_transport",
+            Self::InvalidSofTxRoute { .. }
+            | Self::MissingCustomSofTxRoutes
+            | Self::EmptySofTxRoutes => "sof_tx.routes",
+            Self::InvalidSofTxRoutingMaxParallelSends => "sof_tx.routing_max_parallel_sends",
+            Self::MissingSofDirectGossipEntrypoints => "sof.gossip_entrypoints",
+            Self::InvalidSofIngestQueueCapacity => "sof.ingest_queue_capacity",
+            Self::InvalidEventQueueCapacity => "runtime.event_queue_capacity",
+            Self::InvalidConfigReloadSecs => "runtime.config_reload_secs",
+            Self::InvalidMaxCandidateInstructions => "runtime.max_candidate_instructions",
+            Self::InvalidEventQueueDropPolicy => "runtime.event_queue_drop_policy",
+            Self::InvalidWsolCloseTiming | Self::ConflictingWsolCloseTiming => {
+                "runtime.wsol_close_timing"
+            }
+            Self::ReuseWrappedSolWithInlineClose => "runtime.reuse_wrapped_sol",
+            Self::InvalidRpcCommitment { field } | Self::InvalidInitLiquidity { field } => field,
+            Self::InvalidStrategyWorkerThreads => "runtime.strategy_worker_threads",
+            Self::InvalidComputeUnitLimit => "runtime.compute_unit_limit",
+            Self::InvertedInitLiquidityBand => "runtime.min_init_liquidity_sol",
+            Self::InvalidBlockhashMaxAge { .. } => "runtime.blockhash_max_age_ms",
+            Self::LegacyIngressRemoved => "sof.enabled",
+            Self::PriorityFeeTooHigh { .. } => "runtime.priority_fees",
+            Self::InvalidHealthBindAddress { .. } => "health.bind_address",
+            Self::MissingRuntimeField { field } => match field {
+                RequiredRuntimeField::KeypairPath => "runtime.keypair_path",
+                RequiredRuntimeField::RpcUrl => "runtime.rpc_url",
+                RequiredRuntimeField::JitoUrl => "runtime.jito_url",
+            },
+            Self::EmptyRuntimeField { field } | Self::InvalidPathChars { field } => field.as_str(),
+        }
+    }
+}
+
+impl TelemetrySettingsError {
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::InvalidEnabledValue { field } => field.as_str(),
+            Self::UndersizedSampleCapacity { .. } => "telemetry.sample_capacity",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
 pub struct RuntimeSettings {
     pub config_path: String,
+    pub config_override_path: Option<String>,
     pub priority_fees: PriorityFeesMicrolamports,
+    pub max_priority_fees: u64,
+    pub max_compute_unit_price: u64,
     pub keypair_path: String,
+    pub expected_pubkey: Option<String>,
     pub dry_run: bool,
+    pub paper_trading: bool,
+    pub skip_preflight: bool,
+    pub send_max_retries: usize,
+    pub submit_rpc_urls: Vec<String>,
+    pub fallback_rpc_urls: Vec<String>,
+    pub force_strategy: Option<RaydiumCandidateKind>,
+    pub snipe_on_error_events: bool,
+    pub use_live_reserves: bool,
+    pub wsol_close_timing: WsolCloseTiming,
+    pub wsol_wrap_buffer_lamports: u64,
+    pub reuse_wrapped_sol: bool,
+    pub allow_zero_min_amount_out: bool,
+    pub slippage_retry_attempts: u8,
+    pub init_liquidity_band: InitLiquidityBand,
+    pub compute_unit_limit: Option<u32>,
+    pub max_pool_open_wait_secs: u64,
+    pub pre_open_offset_ms: u64,
+    pub blockhash_refresh_ms: u64,
+    pub blockhash_max_age_ms: u64,
+    pub rpc_call_timeout_ms: u64,
     pub tx_submission_mode: TxSubmissionMode,
     pub jito_url: String,
     pub rpc_url: String,
@@ -182,13 +357,44 @@ pub struct RuntimeSettings {
     pub run_replay_benchmark: bool,
     pub replay_event_count: ReplayEventCount,
     pub replay_burst_size: ReplayBurstSize,
+    pub event_queue_capacity: usize,
+    pub event_queue_drop_policy: EventQueueDropPolicy,
+    pub signature_dedup_capacity: usize,
+    pub signature_dedup_path: Option<String>,
+    pub max_candidate_instructions: usize,
+    pub strategy_worker_threads: Option<usize>,
+    pub strategy_worker_tasks: usize,
+    pub snipe_deadline_secs: u64,
+    pub startup_warmup_secs: u64,
+    pub snipe_outcome_path: Option<String>,
+    pub session_ledger_report_secs: u64,
+    pub rule_stats_report_secs: u64,
+    pub config_reload_secs: u64,
+    pub config_watch: bool,
+    pub fetch_commitment: SofCommitmentLevel,
+    pub confirmation_commitment: SofCommitmentLevel,
     pub latency_sample_capacity: usize,
+    pub latency_sample_max_age_ms: u64,
     pub latency_slo_ns: u64,
+    pub latency_slo_alert_repeat_periods: u64,
     pub latency_report_period_secs: u64,
+    pub latency_expected_events_per_sec: u64,
     pub telemetry_enabled: bool,
+    pub health_listen_addr: Option<SocketAddr>,
+    pub address_overrides: AddressOverrides,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct AddressOverrides {
+    pub wsol_mint: Option<String>,
+    pub token_program: Option<String>,
+    pub jito_tip_account: Option<String>,
+    pub jito_tip_accounts: Vec<String>,
+    pub raydium_cpmm_program: Option<String>,
+    pub raydium_v4_program: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
 pub struct SofRuntimeSettings {
     pub enabled: bool,
     pub source: SofIngressSource,
@@ -211,7 +417,7 @@ pub struct SofRuntimeSettings {
     pub ingest_queue_capacity: Option<usize>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SofTxRuntimeSettings {
     pub enabled: bool,
     pub mode: SofTxMode,
@@ -237,11 +443,54 @@ impl RuntimeSettings {
         Self::from_cli_args(&args)
     }
 
+    pub const fn execution_mode(&self) -> ExecutionMode {
+        ExecutionMode::from_flags(self.dry_run, self.paper_trading)
+    }
+
+    /// Samples a hop is expected to record per report when that overflows `latency_sample_capacity`.
+    ///
+    /// The sample ring overwrites older entries, so reports would describe only the
+    /// newest fraction of the window. `None` without an `expected_events_per_sec` hint.
+    pub const fn latency_sample_overrun(&self) -> Option<u64> {
+        if !self.telemetry_enabled {
+            return None;
+        }
+
+        let expected = expected_samples_per_report(
+            self.latency_expected_events_per_sec,
+            self.latency_report_period_secs,
+            self.latency_sample_max_age_ms,
+        );
+        if exceeds_capacity(expected, self.latency_sample_capacity) {
+            Some(expected)
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn from_cli_args(args: &[String]) -> Result<Self, SettingsError> {
-        let config_path =
-            arg_value(args, "--config").unwrap_or_else(|| "slotstrike.toml".to_owned());
-        let parsed_config = load_sniper_config_file(&config_path)?;
-        Self::from_parsed_config(args, config_path, &parsed_config)
+        let config_path = resolve_config_path(args);
+        let override_path = resolve_config_override_path(args);
+        let parsed_config = load_sniper_config_file(&config_path, override_path.as_deref())?;
+        Self::from_parsed_config(args, config_path.clone(), &parsed_config).map_err(|error| {
+            let Some(field) = error.field() else {
+                return error;
+            };
+            // A key set in the override file wins the merge, so look there first.
+            let location = override_path
+                .iter()
+                .chain(std::iter::once(&config_path))
+                .find_map(|path| locate_config_key(path, field))
+                .unwrap_or_else(|| ConfigLocation {
+                    path: config_path.clone(),
+                    line: None,
+                    field,
+                });
+            SettingsError::Located {
+                location,
+                source: Box::new(error),
+            }
+        })
     }
 
     fn from_parsed_config(
@@ -253,6 +502,8 @@ impl RuntimeSettings {
         let sof = &parsed_config.sof;
         let sof_tx = &parsed_config.sof_tx;
         let telemetry = &parsed_config.telemetry;
+        let health = &parsed_config.health;
+        let addresses = &parsed_config.addresses;
 
         let run_replay_benchmark = arg_flag(args, "--replay-benchmark") || runtime.replay_benchmark;
         let replay_event_count =
@@ -268,8 +519,83 @@ impl RuntimeSettings {
                 }
             })?;
 
+        let priority_fees =
+            PriorityFeesMicrolamports::try_new(runtime.priority_fees, runtime.max_priority_fees)
+                .map_err(|_source| RuntimeSettingsError::PriorityFeeTooHigh {
+                    value: runtime.priority_fees,
+                    ceiling: runtime.max_priority_fees,
+                })?;
+
         let tx_submission_mode = TxSubmissionMode::parse(&runtime.tx_submission_mode)
             .ok_or(RuntimeSettingsError::InvalidTxSubmissionMode)?;
+        let force_strategy = runtime
+            .force_strategy
+            .as_deref()
+            .map(|value| {
+                RaydiumCandidateKind::parse(value).ok_or(RuntimeSettingsError::InvalidForceStrategy)
+            })
+            .transpose()?;
+        let init_liquidity_band = InitLiquidityBand {
+            min: parse_init_liquidity(
+                runtime.min_init_liquidity_sol.as_deref(),
+                "runtime.min_init_liquidity_sol",
+            )?,
+            max: parse_init_liquidity(
+                runtime.max_init_liquidity_sol.as_deref(),
+                "runtime.max_init_liquidity_sol",
+            )?,
+        };
+        if !init_liquidity_band.is_ordered() {
+            return Err(RuntimeSettingsError::InvertedInitLiquidityBand.into());
+        }
+
+        let wsol_close_timing = parse_wsol_close_timing(
+            runtime.wsol_close_timing.as_deref(),
+            runtime.keep_wsol_account_open,
+        )?;
+        // The inline close empties the account in every swap, so there is never wSOL to reuse.
+        if runtime.reuse_wrapped_sol && wsol_close_timing == WsolCloseTiming::Inline {
+            return Err(RuntimeSettingsError::ReuseWrappedSolWithInlineClose.into());
+        }
+
+        if runtime.event_queue_capacity == 0 {
+            return Err(RuntimeSettingsError::InvalidEventQueueCapacity.into());
+        }
+        if runtime.config_reload_secs == 0 {
+            return Err(RuntimeSettingsError::InvalidConfigReloadSecs.into());
+        }
+        if runtime.max_candidate_instructions == 0 {
+            return Err(RuntimeSettingsError::InvalidMaxCandidateInstructions.into());
+        }
+        let event_queue_drop_policy = EventQueueDropPolicy::parse(&runtime.event_queue_drop_policy)
+            .ok_or(RuntimeSettingsError::InvalidEventQueueDropPolicy)?;
+        let fetch_commitment = SofCommitmentLevel::parse(&runtime.fetch_commitment).ok_or(
+            RuntimeSettingsError::InvalidRpcCommitment {
+                field: "runtime.fetch_commitment",
+            },
+        )?;
+        let confirmation_commitment = SofCommitmentLevel::parse(&runtime.confirmation_commitment)
+            .ok_or(RuntimeSettingsError::InvalidRpcCommitment {
+            field: "runtime.confirmation_commitment",
+        })?;
+        if runtime.strategy_worker_threads == Some(0) {
+            return Err(RuntimeSettingsError::InvalidStrategyWorkerThreads.into());
+        }
+        if runtime
+            .compute_unit_limit
+            .is_some_and(|limit| limit == 0 || limit > MAX_COMPUTE_UNIT_LIMIT)
+        {
+            return Err(RuntimeSettingsError::InvalidComputeUnitLimit.into());
+        }
+        if runtime.blockhash_refresh_ms > 0
+            && runtime.blockhash_max_age_ms < runtime.blockhash_refresh_ms
+        {
+            return Err(RuntimeSettingsError::InvalidBlockhashMaxAge {
+                refresh_ms: runtime.blockhash_refresh_ms,
+                max_age_ms: runtime.blockhash_max_age_ms,
+            }
+            .into());
+        }
 
         if !run_replay_benchmark {
             if runtime.keypair_path.trim().is_empty() {
@@ -287,6 +613,13 @@ impl RuntimeSettings {
         }
 
         let keypair_path = runtime.keypair_path.clone();
+        if !keypair_path.trim().is_empty() {
+            NonEmptyText::new_path(keypair_path.as_str()).map_err(|_source| {
+                RuntimeSettingsError::InvalidPathChars {
+                    field: NonEmptyRuntimeField::KeypairPath,
+                }
+            })?;
+        }
         let rpc_url = runtime.rpc_url.clone();
         let jito_url = if run_replay_benchmark {
             runtime.jito_url.clone().unwrap_or_default()
@@ -321,7 +654,7 @@ impl RuntimeSettings {
             optional_non_empty_text(sof.grpc_url.clone(), NonEmptyRuntimeField::GrpcUrl)?;
         let sof_grpc_x_token =
             optional_non_empty_text(sof.grpc_x_token.clone(), NonEmptyRuntimeField::GrpcUrl)?;
-        let sof_private_shred_socket_path = optional_non_empty_text(
+        let sof_private_shred_socket_path = optional_path_text(
             sof.private_shred_socket_path.clone(),
             NonEmptyRuntimeField::PrivateShredSocketPath,
         )?;
@@ -445,12 +778,64 @@ impl RuntimeSettings {
             }
             .into());
         }
+        if telemetry.enabled && telemetry.strict_sample_capacity {
+            let expected = expected_samples_per_report(
+                telemetry.expected_events_per_sec,
+                telemetry.report_period_secs,
+                telemetry.sample_max_age_ms,
+            );
+            if exceeds_capacity(expected, telemetry.sample_capacity) {
+                return Err(TelemetrySettingsError::UndersizedSampleCapacity {
+                    capacity: telemetry.sample_capacity,
+                    expected,
+                }
+                .into());
+            }
+        }
+
+        let health_listen_addr = health
+            .port
+            .map(|port| {
+                health
+                    .bind_address
+                    .trim()
+                    .parse::<IpAddr>()
+                    .map(|ip| SocketAddr::new(ip, port))
+                    .map_err(|_source| RuntimeSettingsError::InvalidHealthBindAddress {
+                        value: health.bind_address.clone(),
+                    })
+            })
+            .transpose()?;
 
         Ok(Self {
             config_path,
-            priority_fees: PriorityFeesMicrolamports::new(runtime.priority_fees),
+            config_override_path: resolve_config_override_path(args),
+            priority_fees,
+            max_priority_fees: runtime.max_priority_fees,
+            max_compute_unit_price: runtime.max_compute_unit_price,
             keypair_path,
+            expected_pubkey: non_blank(runtime.expected_pubkey.as_deref()),
             dry_run: runtime.dry_run,
+            paper_trading: runtime.paper_trading,
+            skip_preflight: runtime.skip_preflight,
+            send_max_retries: runtime.send_max_retries,
+            submit_rpc_urls: runtime.submit_rpc_urls.clone(),
+            fallback_rpc_urls: runtime.fallback_rpc_urls.clone(),
+            force_strategy,
+            snipe_on_error_events: runtime.snipe_on_error_events,
+            use_live_reserves: runtime.use_live_reserves,
+            wsol_close_timing,
+            wsol_wrap_buffer_lamports: runtime.wsol_wrap_buffer_lamports,
+            reuse_wrapped_sol: runtime.reuse_wrapped_sol,
+            allow_zero_min_amount_out: runtime.allow_zero_min_amount_out,
+            slippage_retry_attempts: runtime.slippage_retry_attempts,
+            init_liquidity_band,
+            compute_unit_limit: runtime.compute_unit_limit,
+            max_pool_open_wait_secs: runtime.max_pool_open_wait_secs,
+            pre_open_offset_ms: runtime.pre_open_offset_ms,
+            blockhash_refresh_ms: runtime.blockhash_refresh_ms,
+            blockhash_max_age_ms: runtime.blockhash_max_age_ms,
+            rpc_call_timeout_ms: runtime.rpc_call_timeout_ms,
             tx_submission_mode,
             jito_url,
             rpc_url,
@@ -459,14 +844,242 @@ impl RuntimeSettings {
             run_replay_benchmark,
             replay_event_count,
             replay_burst_size,
+            event_queue_capacity: runtime.event_queue_capacity,
+            event_queue_drop_policy,
+            signature_dedup_capacity: runtime.signature_dedup_capacity,
+            signature_dedup_path: optional_runtime_path(
+                runtime.signature_dedup_path.as_deref(),
+                NonEmptyRuntimeField::SignatureDedupPath,
+            )?,
+            max_candidate_instructions: runtime.max_candidate_instructions,
+            strategy_worker_threads: runtime.strategy_worker_threads,
+            strategy_worker_tasks: runtime.strategy_worker_tasks,
+            snipe_deadline_secs: runtime.snipe_deadline_secs,
+            startup_warmup_secs: runtime.startup_warmup_secs,
+            snipe_outcome_path: optional_runtime_path(
+                runtime.snipe_outcome_path.as_deref(),
+                NonEmptyRuntimeField::SnipeOutcomePath,
+            )?,
+            session_ledger_report_secs: runtime.session_ledger_report_secs,
+            rule_stats_report_secs: runtime.rule_stats_report_secs,
+            config_reload_secs: runtime.config_reload_secs,
+            config_watch: runtime.config_watch,
+            fetch_commitment,
+            confirmation_commitment,
             latency_sample_capacity: telemetry.sample_capacity,
+            latency_sample_max_age_ms: telemetry.sample_max_age_ms,
             latency_slo_ns: telemetry.slo_ns,
+            latency_slo_alert_repeat_periods: telemetry.slo_alert_repeat_periods,
             latency_report_period_secs: telemetry.report_period_secs,
+            latency_expected_events_per_sec: telemetry.expected_events_per_sec,
             telemetry_enabled: telemetry.enabled,
+            health_listen_addr,
+            address_overrides: AddressOverrides {
+                wsol_mint: non_blank(addresses.wsol_mint.as_deref()),
+                token_program: non_blank(addresses.token_program.as_deref()),
+                jito_tip_account: non_blank(addresses.jito_tip_account.as_deref()),
+                jito_tip_accounts: addresses
+                    .jito_tip_accounts
+                    .iter()
+                    .filter_map(|value| non_blank(Some(value.as_str())))
+                    .collect(),
+                raydium_cpmm_program: non_blank(addresses.raydium_cpmm_program.as_deref()),
+                raydium_v4_program: non_blank(addresses.raydium_v4_program.as_deref()),
+            },
         })
     }
 }
 
+/// Builds [`RuntimeSettings`] without a TOML file.
+///
+/// Unset fields take their TOML defaults, and `build` runs the same validation as
+/// loading a config file. The CLI path stays the primary entry point.
+#[derive(Clone, Debug)]
+pub struct RuntimeSettingsBuilder {
+    config_path: String,
+    config: SniperConfigFile,
+}
+
+impl RuntimeSettingsBuilder {
+    pub fn new(keypair_path: String, rpc_url: String, wss_url: String) -> Self {
+        Self {
+            config_path: DEFAULT_CONFIG_PATH.to_owned(),
+            config: SniperConfigFile::new(RuntimeConfigSection::new(
+                keypair_path,
+                rpc_url,
+                wss_url,
+                0,
+            )),
+        }
+    }
+
+    #[must_use]
+    pub fn config_path(mut self, config_path: String) -> Self {
+        self.config_path = config_path;
+        self
+    }
+
+    #[must_use]
+    pub const fn priority_fees(mut self, priority_fees: u64) -> Self {
+        self.config.runtime.priority_fees = priority_fees;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_priority_fees(mut self, max_priority_fees: u64) -> Self {
+        self.config.runtime.max_priority_fees = max_priority_fees;
+        self
+    }
+
+    #[must_use]
+    pub const fn max_compute_unit_price(mut self, max_compute_unit_price: u64) -> Self {
+        self.config.runtime.max_compute_unit_price = max_compute_unit_price;
+        self
+    }
+
+    #[must_use]
+    pub fn expected_pubkey(mut self, expected_pubkey: Option<String>) -> Self {
+        self.config.runtime.expected_pubkey = expected_pubkey;
+        self
+    }
+
+    #[must_use]
+    pub const fn dry_run(mut self, dry_run: bool) -> Self {
+        self.config.runtime.dry_run = dry_run;
+        self
+    }
+
+    #[must_use]
+    pub const fn skip_preflight(mut self, skip_preflight: bool) -> Self {
+        self.config.runtime.skip_preflight = skip_preflight;
+        self
+    }
+
+    #[must_use]
+    pub fn tx_submission_mode(mut self, tx_submission_mode: TxSubmissionMode) -> Self {
+        self.config.runtime.tx_submission_mode = tx_submission_mode.as_str().to_owned();
+        self
+    }
+
+    #[must_use]
+    pub fn jito_url(mut self, jito_url: String) -> Self {
+        self.config.runtime.jito_url = Some(jito_url);
+        self
+    }
+
+    #[must_use]
+    pub fn submit_rpc_urls(mut self, submit_rpc_urls: Vec<String>) -> Self {
+        self.config.runtime.submit_rpc_urls = submit_rpc_urls;
+        self
+    }
+
+    #[must_use]
+    pub const fn replay_benchmark(mut self, replay_benchmark: bool) -> Self {
+        self.config.runtime.replay_benchmark = replay_benchmark;
+        self
+    }
+
+    /// Sets any `[runtime]` field that has no dedicated setter.
+    #[must_use]
+    pub fn runtime(mut self, edit: impl FnOnce(&mut RuntimeConfigSection)) -> Self {
+        edit(&mut self.config.runtime);
+        self
+    }
+
+    #[must_use]
+    pub fn sof(mut self, sof: SofConfigSection) -> Self {
+        self.config.sof = sof;
+        self
+    }
+
+    #[must_use]
+    pub fn sof_tx(mut self, sof_tx: SofTxConfigSection) -> Self {
+        self.config.sof_tx = sof_tx;
+        self
+    }
+
+    #[must_use]
+    pub const fn telemetry(mut self, telemetry: TelemetryConfigSection) -> Self {
+        self.config.telemetry = telemetry;
+        self
+    }
+
+    #[must_use]
+    pub fn health(mut self, health: HealthConfigSection) -> Self {
+        self.config.health = health;
+        self
+    }
+
+    #[must_use]
+    pub fn addresses(mut self, addresses: AddressesConfigSection) -> Self {
+        self.config.addresses = addresses;
+        self
+    }
+
+    pub fn build(&self) -> Result<RuntimeSettings, SettingsError> {
+        RuntimeSettings::from_parsed_config(&[], self.config_path.clone(), &self.config)
+    }
+}
+
+fn non_blank(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_owned)
+}
+
+fn parse_init_liquidity(
+    value: Option<&str>,
+    field: &'static str,
+) -> Result<Option<Lamports>, RuntimeSettingsError> {
+    value
+        .map(|value| {
+            parse_positive_sol_str_to_lamports(value)
+                .ok_or(RuntimeSettingsError::InvalidInitLiquidity { field })
+        })
+        .transpose()
+}
+
+/// Samples one hop records between reports; `sample_max_age_ms` narrows the window it keeps.
+const fn expected_samples_per_report(
+    events_per_sec: u64,
+    report_period_secs: u64,
+    sample_max_age_ms: u64,
+) -> u64 {
+    let report_period_ms = report_period_secs.saturating_mul(1_000);
+    let window_ms = if sample_max_age_ms > 0 && sample_max_age_ms < report_period_ms {
+        sample_max_age_ms
+    } else {
+        report_period_ms
+    };
+    events_per_sec.saturating_mul(window_ms) / 1_000
+}
+
+const fn exceeds_capacity(expected_samples: u64, sample_capacity: usize) -> bool {
+    expected_samples > sample_capacity as u64
+}
+
+/// `keep_wsol_account_open = true` is shorthand for `wsol_close_timing = "never"`.
+fn parse_wsol_close_timing(
+    value: Option<&str>,
+    keep_open: bool,
+) -> Result<WsolCloseTiming, RuntimeSettingsError> {
+    let Some(value) = value else {
+        return Ok(if keep_open {
+            WsolCloseTiming::Never
+        } else {
+            WsolCloseTiming::Inline
+        });
+    };
+
+    let timing =
+        WsolCloseTiming::parse(value).ok_or(RuntimeSettingsError::InvalidWsolCloseTiming)?;
+    if keep_open && timing != WsolCloseTiming::Never {
+        return Err(RuntimeSettingsError::ConflictingWsolCloseTiming);
+    }
+    Ok(timing)
+}
+
 fn optional_non_empty_text(
     value: Option<String>,
     field: NonEmptyRuntimeField,
@@ -477,6 +1090,35 @@ fn optional_non_empty_text(
         .map_err(|_source| RuntimeSettingsError::EmptyRuntimeField { field })
 }
 
+fn optional_path_text(
+    value: Option<String>,
+    field: NonEmptyRuntimeField,
+) -> Result<Option<NonEmptyText>, RuntimeSettingsError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    if value.trim().is_empty() {
+        return Err(RuntimeSettingsError::EmptyRuntimeField { field });
+    }
+    NonEmptyText::new_path(value)
+        .map(Some)
+        .map_err(|_source| RuntimeSettingsError::InvalidPathChars { field })
+}
+
+/// A blank value leaves the path unset, like [`non_blank`].
+fn optional_runtime_path(
+    value: Option<&str>,
+    field: NonEmptyRuntimeField,
+) -> Result<Option<String>, RuntimeSettingsError> {
+    non_blank(value)
+        .map(|path| {
+            NonEmptyText::new_path(path.as_str())
+                .map(|_text| path)
+                .map_err(|_source| RuntimeSettingsError::InvalidPathChars { field })
+        })
+        .transpose()
+}
+
 fn resolve_sof_tx_routes(
     mode: SofTxMode,
     configured_routes: &[String],
@@ -518,6 +1160,22 @@ fn arg_flag(args: &[String], flag: &str) -> bool {
     args.iter().any(|arg| arg == flag)
 }
 
+/// Config path from `--config`, then `SLOTSTRIKE_CONFIG`, then `slotstrike.toml`.
+pub fn resolve_config_path(args: &[String]) -> String {
+    config_path_with_env(args, env::var(CONFIG_PATH_ENV).ok().as_deref())
+}
+
+/// Host-specific file from `--config-override`, deep-merged over the base config.
+pub fn resolve_config_override_path(args: &[String]) -> Option<String> {
+    arg_value(args, "--config-override")
+}
+
+fn config_path_with_env(args: &[String], env_value: Option<&str>) -> String {
+    arg_value(args, "--config")
+        .or_else(|| non_blank(env_value))
+        .unwrap_or_else(|| DEFAULT_CONFIG_PATH.to_owned())
+}
+
 fn arg_value(args: &[String], flag: &str) -> Option<String> {
     args.iter()
         .position(|arg| arg == flag)
@@ -527,12 +1185,30 @@ fn arg_value(args: &[String], flag: &str) -> Option<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::RuntimeSettings;
+    use super::{
+        NonEmptyRuntimeField, RuntimeSettings, RuntimeSettingsBuilder, RuntimeSettingsError,
+        SettingsError, config_path_with_env,
+    };
     use crate::domain::{
         config::{ConfigError, SniperConfigFile, parse_sniper_config_toml},
-        value_objects::TxSubmissionMode,
+        events::RaydiumCandidateKind,
+        value_objects::{
+            EventQueueDropPolicy, SofCommitmentLevel, TxSubmissionMode, WsolCloseTiming,
+            sol_amount::{InitLiquidityBand, Lamports},
+        },
     };
 
+    #[test]
+    fn config_path_prefers_flag_then_env_then_default() {
+        let flag = vec!["--config".to_owned(), "flag.toml".to_owned()];
+        let env_path = Some("env.toml");
+
+        assert_eq!(config_path_with_env(&flag, env_path), "flag.toml");
+        assert_eq!(config_path_with_env(&[], env_path), "env.toml");
+        assert_eq!(config_path_with_env(&[], Some("  ")), "slotstrike.toml");
+        assert_eq!(config_path_with_env(&[], None), "slotstrike.toml");
+    }
+
     fn minimal_config() -> Result<SniperConfigFile, ConfigError> {
         parse_sniper_config_toml(
             r#"
@@ -638,6 +1314,166 @@ replay_burst_size = 512
         }
     }
 
+    #[test]
+    fn parses_forced_strategy() {
+        for (value, expected) in [
+            ("\"openbook\"", Ok(Some(RaydiumCandidateKind::OpenBook))),
+            ("\"amm\"", Err(())),
+        ] {
+            let config = parse_sniper_config_toml(&format!(
+                r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+force_strategy = {value}
+"#
+            ));
+            assert!(config.is_ok());
+            if let Ok(config) = config {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert_eq!(
+                    settings
+                        .map(|settings| settings.force_strategy)
+                        .map_err(|_error| ()),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn errored_events_are_skipped_unless_enabled() {
+        for (line, expected) in [("", false), ("snipe_on_error_events = true", true)] {
+            let config = parse_sniper_config_toml(&format!(
+                r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+{line}
+"#
+            ));
+            assert!(config.is_ok());
+            if let Ok(config) = config {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert_eq!(
+                    settings.map(|settings| settings.snipe_on_error_events).ok(),
+                    Some(expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn resolves_wsol_close_timing() {
+        for (lines, expected) in [
+            ("", Some(WsolCloseTiming::Inline)),
+            (
+                "wsol_close_timing = \"after_confirmation\"",
+                Some(WsolCloseTiming::AfterConfirmation),
+            ),
+            (
+                "keep_wsol_account_open = true",
+                Some(WsolCloseTiming::Never),
+            ),
+            (
+                "keep_wsol_account_open = true\nwsol_close_timing = \"never\"",
+                Some(WsolCloseTiming::Never),
+            ),
+            (
+                "keep_wsol_account_open = true\nwsol_close_timing = \"inline\"",
+                None,
+            ),
+            ("wsol_close_timing = \"later\"", None),
+        ] {
+            let config = parse_sniper_config_toml(&format!(
+                r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+{lines}
+"#
+            ));
+            assert!(config.is_ok());
+            if let Ok(config) = config {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert_eq!(
+                    settings.map(|settings| settings.wsol_close_timing).ok(),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn validates_init_liquidity_band() {
+        for (lines, expected) in [
+            (
+                "min_init_liquidity_sol = \"1\"\nmax_init_liquidity_sol = \"25.5\"",
+                Some(InitLiquidityBand {
+                    min: Some(Lamports::new(1_000_000_000)),
+                    max: Some(Lamports::new(25_500_000_000)),
+                }),
+            ),
+            (
+                "max_init_liquidity_sol = \"25\"",
+                Some(InitLiquidityBand {
+                    min: None,
+                    max: Some(Lamports::new(25_000_000_000)),
+                }),
+            ),
+            (
+                "min_init_liquidity_sol = \"30\"\nmax_init_liquidity_sol = \"25\"",
+                None,
+            ),
+            ("max_init_liquidity_sol = \"-1\"", None),
+        ] {
+            let config = parse_sniper_config_toml(&format!(
+                r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+{lines}
+"#
+            ));
+            assert!(config.is_ok());
+            if let Ok(config) = config {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert_eq!(
+                    settings.map(|settings| settings.init_liquidity_band).ok(),
+                    expected
+                );
+            }
+        }
+    }
+
     #[test]
     fn disabled_telemetry_accepts_zero_capacity_and_report_period() {
         let config = parse_sniper_config_toml(
@@ -674,6 +1510,60 @@ report_period_secs = 0
         }
     }
 
+    #[test]
+    fn flags_sample_capacity_below_expected_event_rate() {
+        for (lines, overrun, strict_ok) in [
+            ("", None, true),
+            ("expected_events_per_sec = 10", None, true),
+            ("expected_events_per_sec = 100", Some(1_500), false),
+            (
+                "expected_events_per_sec = 100\nsample_max_age_ms = 5000",
+                None,
+                true,
+            ),
+        ] {
+            let toml = format!(
+                r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[telemetry]
+sample_capacity = 1024
+report_period_secs = 15
+{lines}
+"#
+            );
+            let config = parse_sniper_config_toml(&toml);
+            let strict_config =
+                parse_sniper_config_toml(&format!("{toml}strict_sample_capacity = true\n"));
+            assert!(config.is_ok());
+            assert!(strict_config.is_ok());
+            if let (Ok(config), Ok(strict_config)) = (config, strict_config) {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert_eq!(
+                    settings
+                        .map(|settings| settings.latency_sample_overrun())
+                        .ok(),
+                    Some(overrun)
+                );
+                let strict = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &strict_config,
+                );
+                assert_eq!(strict.is_ok(), strict_ok);
+            }
+        }
+    }
+
     #[test]
     fn preserves_dry_run_flag() {
         let config = parse_sniper_config_toml(
@@ -740,6 +1630,93 @@ mode = "direct"
         }
     }
 
+    #[test]
+    fn rejects_control_characters_in_socket_paths() {
+        let config = parse_sniper_config_toml(
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[sof]
+enabled = true
+source = "private_shred"
+private_shred_socket_path = "/tmp/slotstrike\nsof.sock"
+"#,
+        );
+        assert!(config.is_ok());
+        if let Ok(config) = config {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &config,
+            );
+            assert!(matches!(
+                settings,
+                Err(SettingsError::Runtime(
+                    RuntimeSettingsError::InvalidPathChars {
+                        field: NonEmptyRuntimeField::PrivateShredSocketPath
+                    }
+                ))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_control_characters_in_runtime_paths() {
+        for (path_setting, field) in [
+            (
+                r#"keypair_path = "keypair\u0000.json""#,
+                NonEmptyRuntimeField::KeypairPath,
+            ),
+            (
+                r#"signature_dedup_path = "dedup\n.bin""#,
+                NonEmptyRuntimeField::SignatureDedupPath,
+            ),
+            (
+                r#"snipe_outcome_path = "outcomes\r.jsonl""#,
+                NonEmptyRuntimeField::SnipeOutcomePath,
+            ),
+        ] {
+            let keypair_path = if field == NonEmptyRuntimeField::KeypairPath {
+                ""
+            } else {
+                "keypair_path = \"keypair.json\""
+            };
+            let config = parse_sniper_config_toml(&format!(
+                r#"
+[runtime]
+{keypair_path}
+{path_setting}
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+"#
+            ));
+            assert!(config.is_ok());
+            if let Ok(config) = config {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert!(
+                    matches!(
+                        settings,
+                        Err(SettingsError::Runtime(RuntimeSettingsError::InvalidPathChars {
+                            field: rejected
+                        })) if rejected == field
+                    ),
+                    "{field}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn websocket_direct_is_allowed() {
         let config = parse_sniper_config_toml(
@@ -813,4 +1790,329 @@ mode = "direct"
             assert!(settings.is_ok());
         }
     }
+
+    #[test]
+    fn parses_event_queue_settings() {
+        let config = parse_sniper_config_toml(
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+event_queue_capacity = 128
+event_queue_drop_policy = "drop_oldest"
+"#,
+        );
+        assert!(config.is_ok());
+        if let Ok(config) = config {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &config,
+            );
+            assert!(settings.is_ok());
+            if let Ok(settings) = settings {
+                assert_eq!(settings.event_queue_capacity, 128);
+                assert_eq!(
+                    settings.event_queue_drop_policy,
+                    EventQueueDropPolicy::DropOldest
+                );
+            }
+        }
+
+        let zero_capacity = parse_sniper_config_toml(
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+event_queue_capacity = 0
+"#,
+        );
+        assert!(zero_capacity.is_ok());
+        if let Ok(zero_capacity) = zero_capacity {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &zero_capacity,
+            );
+            assert!(matches!(
+                settings,
+                Err(SettingsError::Runtime(
+                    RuntimeSettingsError::InvalidEventQueueCapacity
+                ))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_reusing_wrapped_sol_with_an_inline_close() {
+        let config = parse_sniper_config_toml(
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+reuse_wrapped_sol = true
+"#,
+        );
+        assert!(config.is_ok());
+        if let Ok(config) = config {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &config,
+            );
+            assert!(matches!(
+                settings,
+                Err(SettingsError::Runtime(
+                    RuntimeSettingsError::ReuseWrappedSolWithInlineClose
+                ))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_sub_second_config_reloads() {
+        let config = parse_sniper_config_toml(
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+config_reload_secs = 0
+"#,
+        );
+        assert!(config.is_ok());
+        if let Ok(config) = config {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &config,
+            );
+            assert!(matches!(
+                settings,
+                Err(SettingsError::Runtime(
+                    RuntimeSettingsError::InvalidConfigReloadSecs
+                ))
+            ));
+        }
+    }
+
+    #[test]
+    fn parses_independent_rpc_commitments() {
+        let config = parse_sniper_config_toml(
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+fetch_commitment = "processed"
+confirmation_commitment = "finalized"
+"#,
+        );
+        assert!(config.is_ok());
+        if let Ok(config) = config {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &config,
+            );
+            assert!(settings.is_ok());
+            if let Ok(settings) = settings {
+                assert_eq!(settings.fetch_commitment, SofCommitmentLevel::Processed);
+                assert_eq!(
+                    settings.confirmation_commitment,
+                    SofCommitmentLevel::Finalized
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parses_optional_health_listen_addr() {
+        let config = parse_sniper_config_toml(
+            r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[health]
+port = 8080
+bind_address = "127.0.0.1"
+"#,
+        );
+        assert!(config.is_ok());
+        if let Ok(config) = config {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &config,
+            );
+            assert!(settings.is_ok());
+            if let Ok(settings) = settings {
+                assert_eq!(
+                    settings.health_listen_addr,
+                    Some(std::net::SocketAddr::from(([127, 0, 0, 1], 8080)))
+                );
+            }
+        }
+
+        let minimal = minimal_config();
+        assert!(minimal.is_ok());
+        if let Ok(minimal) = minimal {
+            let settings = RuntimeSettings::from_parsed_config(
+                &Vec::new(),
+                "slotstrike.toml".to_owned(),
+                &minimal,
+            );
+            assert!(settings.is_ok_and(|settings| settings.health_listen_addr.is_none()));
+        }
+    }
+
+    #[test]
+    fn rejects_priority_fee_above_ceiling() {
+        for (priority_fees, expected_ok) in [(99_999, true), (100_001, false)] {
+            let config = parse_sniper_config_toml(&format!(
+                r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = {}
+max_priority_fees = 100000
+tx_submission_mode = "direct"
+"#,
+                priority_fees
+            ));
+            assert!(config.is_ok());
+            if let Ok(config) = config {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert_eq!(settings.is_ok(), expected_ok);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_blockhash_max_age_below_refresh_period() {
+        for (max_age_ms, expected_ok) in [(2_000, true), (1_000, false)] {
+            let config = parse_sniper_config_toml(&format!(
+                r#"
+[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+blockhash_refresh_ms = 2000
+blockhash_max_age_ms = {}
+"#,
+                max_age_ms
+            ));
+            assert!(config.is_ok());
+            if let Ok(config) = config {
+                let settings = RuntimeSettings::from_parsed_config(
+                    &Vec::new(),
+                    "slotstrike.toml".to_owned(),
+                    &config,
+                );
+                assert_eq!(settings.is_ok(), expected_ok);
+            }
+        }
+    }
+
+    #[test]
+    fn builder_produces_validated_settings_without_a_file() {
+        let settings = RuntimeSettingsBuilder::new(
+            "keypair.json".to_owned(),
+            "https://rpc.example".to_owned(),
+            "wss://wss.example".to_owned(),
+        )
+        .priority_fees(1_000)
+        .dry_run(true)
+        .tx_submission_mode(TxSubmissionMode::Direct)
+        .build();
+
+        assert!(settings.is_ok());
+        if let Ok(settings) = settings {
+            assert_eq!(settings.config_path, "slotstrike.toml");
+            assert_eq!(settings.priority_fees.as_u64(), 1_000);
+            assert!(settings.dry_run);
+            assert_eq!(settings.tx_submission_mode, TxSubmissionMode::Direct);
+            assert_eq!(settings.jito_url, "https://rpc.example");
+            assert_eq!(settings.event_queue_capacity, 4_096);
+        }
+
+        let too_expensive = RuntimeSettingsBuilder::new(
+            "keypair.json".to_owned(),
+            "https://rpc.example".to_owned(),
+            "wss://wss.example".to_owned(),
+        )
+        .priority_fees(2_000)
+        .max_priority_fees(1_000)
+        .build();
+        assert!(matches!(
+            too_expensive,
+            Err(SettingsError::Runtime(
+                RuntimeSettingsError::PriorityFeeTooHigh { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn points_a_bad_value_at_its_config_line() {
+        let config_path = crate::test_support::temp_path("settings_bad_value", "toml");
+        let written = std::fs::write(
+            &config_path,
+            r#"[runtime]
+keypair_path = "keypair.json"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+event_queue_drop_policy = "drop_sideways"
+"#,
+        );
+        assert!(written.is_ok());
+
+        let config_path_text = config_path.display().to_string();
+        let settings =
+            RuntimeSettings::from_cli_args(&["--config".to_owned(), config_path_text.clone()]);
+        assert!(settings.is_err());
+        if let Err(error) = settings {
+            assert_eq!(error.field(), Some("runtime.event_queue_drop_policy"));
+            assert!(matches!(
+                error,
+                SettingsError::Located { ref location, ref source }
+                    if location.line == Some(7)
+                        && matches!(
+                            **source,
+                            SettingsError::Runtime(RuntimeSettingsError::InvalidEventQueueDropPolicy)
+                        )
+            ));
+            assert!(error.to_string().starts_with(&format!(
+                "{}:7 (runtime.event_queue_drop_policy): invalid runtime.event_queue_drop_policy",
+                config_path_text
+            )));
+        }
+
+        assert!(std::fs::remove_file(&config_path).is_ok());
+    }
 }
@@ -1,3 +1,5 @@
 pub mod rule_matching;
 
-pub use rule_matching::{DeployerAddressMatchSpecification, MintAddressMatchSpecification};
+pub use rule_matching::{
+    CatchAllMatchSpecification, DeployerAddressMatchSpecification, MintAddressMatchSpecification,
+};
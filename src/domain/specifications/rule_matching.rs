@@ -34,6 +34,16 @@ impl<'address> DeployerAddressMatchSpecification<'address> {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CatchAllMatchSpecification;
+
+impl CatchAllMatchSpecification {
+    #[inline(always)]
+    pub const fn select(self, rule_book: &RuleBook) -> Option<&SnipeRule> {
+        rule_book.catch_all_rule()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{DeployerAddressMatchSpecification, MintAddressMatchSpecification};
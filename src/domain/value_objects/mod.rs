@@ -2,9 +2,12 @@ pub mod rule_primitives;
 pub mod runtime;
 pub mod sol_amount;
 
-pub use rule_primitives::{RuleAddress, RuleSlippageBps, RuleSolAmount};
+pub use rule_primitives::{
+    RuleActiveWindow, RuleAddress, RuleFeeMultiplier, RuleSlippageBps, RuleSolAmount, RuleTipPct,
+};
 pub use runtime::{
-    NonEmptyText, PriorityFeesMicrolamports, ReplayBurstSize, ReplayEventCount, SofCommitmentLevel,
-    SofGossipRuntimeMode, SofIngressSource, SofTxJitoTransport, SofTxMode, SofTxReliability,
-    SofTxRoute, SofTxStrategy, TxSubmissionMode,
+    EventQueueDropPolicy, ExecutionMode, NonEmptyText, PriorityFeesMicrolamports, ReplayBurstSize,
+    ReplayEventCount, SofCommitmentLevel, SofGossipRuntimeMode, SofIngressSource,
+    SofTxJitoTransport, SofTxMode, SofTxReliability, SofTxRoute, SofTxStrategy, TxSubmissionMode,
+    WsolCloseTiming,
 };
@@ -10,6 +10,8 @@ use crate::domain::value_objects::sol_amount::Lamports;
 pub struct RuleAddress(Arc<str>);
 
 impl RuleAddress {
+    pub const CATCH_ALL: &'static str = "*";
+
     pub fn new(value: impl Into<Arc<str>>) -> Result<Self, &'static str> {
         let value = value.into();
 
@@ -24,6 +26,11 @@ impl RuleAddress {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    #[inline(always)]
+    pub fn is_catch_all(&self) -> bool {
+        self.as_str() == Self::CATCH_ALL
+    }
 }
 
 impl AsRef<str> for RuleAddress {
@@ -68,6 +75,8 @@ pub struct RuleSlippageBps(u16);
 impl RuleSlippageBps {
     pub const MAX_BPS: u16 = 10_000;
 
+    /// Parses a percent string into whole basis points. Digits below one basis point are
+    /// truncated, and a non-zero value that would truncate to 0 bps is rejected.
     pub fn from_pct_str(value: &str) -> Result<Self, &'static str> {
         let value = value.trim();
         if value.is_empty() {
@@ -107,6 +116,9 @@ impl RuleSlippageBps {
         let bps = pct_scaled_4
             .checked_div(100)
             .ok_or("invalid slippage value")?;
+        if bps == 0 && pct_scaled_4 != 0 {
+            return Err("slippage below 0.01% cannot be represented; use 0 or at least 0.01");
+        }
 
         if bps > u64::from(Self::MAX_BPS) {
             return Err("slippage must be between 0 and 100");
@@ -128,6 +140,105 @@ impl RuleSlippageBps {
     }
 }
 
+/// Share of the swap input paid as a Jito tip, in basis points.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct RuleTipPct(u16);
+
+impl RuleTipPct {
+    /// Parses a percent string like a slippage value, rejecting `0`.
+    pub fn from_pct_str(value: &str) -> Result<Self, &'static str> {
+        match RuleSlippageBps::from_pct_str(value) {
+            Ok(parsed) if parsed.as_bps() > 0 => Ok(Self(parsed.as_bps())),
+            Ok(_) | Err(_) => {
+                Err("tip percentage must be between 0.01 and 100 with up to 4 decimal places")
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub const fn as_bps(self) -> u16 {
+        self.0
+    }
+
+    /// This share of `lamports`, rounded down. At most 100%, so it never exceeds `lamports`.
+    #[inline(always)]
+    pub fn of_lamports(self, lamports: Lamports) -> Lamports {
+        lamports.checked_scale_bps(self.0).unwrap_or(lamports)
+    }
+
+    pub fn as_pct_string(self) -> String {
+        RuleSlippageBps(self.0).as_pct_string()
+    }
+}
+
+/// Per-rule scale applied to the compute unit price, in hundredths (`100` = 1x).
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct RuleFeeMultiplier(u32);
+
+impl RuleFeeMultiplier {
+    pub const ONE: Self = Self(100);
+    pub const MAX_HUNDREDTHS: u32 = 10_000;
+
+    /// Parses a decimal multiplier such as `1.5` with up to two decimal places.
+    pub fn from_decimal_str(value: &str) -> Result<Self, &'static str> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("fee multiplier must not be empty");
+        }
+
+        let (whole_raw, fractional_raw) = value.split_once('.').unwrap_or((value, "0"));
+        if fractional_raw.len() > 2 {
+            return Err("fee multiplier supports up to 2 decimal places");
+        }
+        if !fractional_raw
+            .chars()
+            .all(|character| character.is_ascii_digit())
+        {
+            return Err("invalid fee multiplier");
+        }
+
+        let whole_part = whole_raw
+            .parse::<u32>()
+            .map_err(|_parse_error| "invalid fee multiplier")?;
+        let fractional_part = format!("{fractional_raw:0<2}")
+            .parse::<u32>()
+            .map_err(|_parse_error| "invalid fee multiplier")?;
+
+        let hundredths = whole_part
+            .checked_mul(100)
+            .and_then(|scaled_value| scaled_value.checked_add(fractional_part))
+            .ok_or("fee multiplier overflow")?;
+        if hundredths == 0 || hundredths > Self::MAX_HUNDREDTHS {
+            return Err("fee multiplier must be greater than 0 and at most 100");
+        }
+
+        Ok(Self(hundredths))
+    }
+
+    #[inline(always)]
+    pub const fn as_hundredths(self) -> u32 {
+        self.0
+    }
+
+    /// Scales `micro_lamports`; the signed price is capped by `runtime.max_compute_unit_price`.
+    #[inline(always)]
+    pub const fn apply(self, micro_lamports: u64) -> u64 {
+        micro_lamports.saturating_mul(self.0 as u64) / 100
+    }
+
+    pub fn as_decimal_string(self) -> String {
+        let whole = self.0 / 100;
+        let fractional = self.0 % 100;
+        format!("{whole}.{fractional:02}")
+    }
+}
+
+impl Default for RuleFeeMultiplier {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct RuleSolAmount(Lamports);
 
@@ -148,9 +259,84 @@ impl RuleSolAmount {
     }
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RuleActiveWindow {
+    from_minute: u16,
+    until_minute: u16,
+}
+
+impl RuleActiveWindow {
+    pub const MINUTES_PER_DAY: u16 = 1_440;
+
+    pub fn from_hhmm_strs(active_from: &str, active_until: &str) -> Result<Self, &'static str> {
+        let from_minute = parse_hhmm_minute_of_day(active_from)?;
+        let until_minute = parse_hhmm_minute_of_day(active_until)?;
+        if from_minute == until_minute {
+            return Err("active window start and end must differ");
+        }
+
+        Ok(Self {
+            from_minute,
+            until_minute,
+        })
+    }
+
+    #[inline(always)]
+    pub const fn start_minute(self) -> u16 {
+        self.from_minute
+    }
+
+    #[inline(always)]
+    pub const fn end_minute(self) -> u16 {
+        self.until_minute
+    }
+
+    #[inline(always)]
+    pub const fn contains(self, minute_of_day: u16) -> bool {
+        if self.from_minute < self.until_minute {
+            minute_of_day >= self.from_minute && minute_of_day < self.until_minute
+        } else {
+            minute_of_day >= self.from_minute || minute_of_day < self.until_minute
+        }
+    }
+
+    pub fn as_hhmm_string(self) -> String {
+        format!(
+            "{:02}:{:02}-{:02}:{:02} UTC",
+            self.from_minute / 60,
+            self.from_minute % 60,
+            self.until_minute / 60,
+            self.until_minute % 60,
+        )
+    }
+}
+
+fn parse_hhmm_minute_of_day(value: &str) -> Result<u16, &'static str> {
+    let (hours_raw, minutes_raw) = value
+        .trim()
+        .split_once(':')
+        .ok_or("active window time must use HH:MM")?;
+    if hours_raw.len() != 2 || minutes_raw.len() != 2 {
+        return Err("active window time must use HH:MM");
+    }
+
+    let hours = hours_raw
+        .parse::<u16>()
+        .map_err(|_parse_error| "invalid active window hour")?;
+    let minutes = minutes_raw
+        .parse::<u16>()
+        .map_err(|_parse_error| "invalid active window minute")?;
+    if hours > 23 || minutes > 59 {
+        return Err("active window time must be between 00:00 and 23:59");
+    }
+
+    Ok(hours.saturating_mul(60).saturating_add(minutes))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{RuleAddress, RuleSlippageBps};
+    use super::{RuleActiveWindow, RuleAddress, RuleFeeMultiplier, RuleSlippageBps, RuleTipPct};
+    use crate::domain::value_objects::sol_amount::Lamports;
 
     #[test]
     fn creates_non_empty_rule_address() {
@@ -161,6 +347,28 @@ mod tests {
         assert!(empty.is_err());
     }
 
+    #[test]
+    fn parses_tip_percentages_and_takes_their_share() {
+        let parsed = RuleTipPct::from_pct_str("0.5");
+        assert!(parsed.is_ok());
+        if let Ok(pct) = parsed {
+            assert_eq!(pct.as_bps(), 50);
+            assert_eq!(pct.as_pct_string(), "0.50");
+            assert_eq!(
+                pct.of_lamports(Lamports::new(1_000_000_000)),
+                Lamports::new(5_000_000)
+            );
+            assert_eq!(pct.of_lamports(Lamports::new(199)), Lamports::new(0));
+            assert_eq!(
+                pct.of_lamports(Lamports::new(u64::MAX)),
+                Lamports::new(u64::MAX / 200)
+            );
+        }
+
+        assert!(RuleTipPct::from_pct_str("0").is_err());
+        assert!(RuleTipPct::from_pct_str("100.01").is_err());
+    }
+
     #[test]
     fn parses_slippage_from_percent_without_float_math() {
         let parsed = RuleSlippageBps::from_pct_str("1.25");
@@ -172,9 +380,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rejects_non_zero_slippage_below_one_bps() {
+        assert!(RuleSlippageBps::from_pct_str("0.001").is_err());
+        assert!(RuleSlippageBps::from_pct_str("0.0001").is_err());
+        assert_eq!(
+            RuleSlippageBps::from_pct_str("0").map(RuleSlippageBps::as_bps),
+            Ok(0)
+        );
+        assert_eq!(
+            RuleSlippageBps::from_pct_str("0.0150").map(RuleSlippageBps::as_bps),
+            Ok(1)
+        );
+    }
+
     #[test]
     fn rejects_slippage_outside_bounds() {
         assert!(RuleSlippageBps::from_pct_str("100.01").is_err());
         assert!(RuleSlippageBps::from_pct_str("abc").is_err());
     }
+
+    #[test]
+    fn fee_multiplier_scales_the_price() {
+        let multiplier = RuleFeeMultiplier::from_decimal_str("1.5");
+        assert!(multiplier.is_ok());
+
+        if let Ok(multiplier) = multiplier {
+            assert_eq!(multiplier.as_hundredths(), 150);
+            assert_eq!(multiplier.as_decimal_string(), "1.50");
+            assert_eq!(multiplier.apply(1_000), 1_500);
+            assert_eq!(multiplier.apply(u64::MAX), u64::MAX / 100);
+        }
+
+        assert_eq!(RuleFeeMultiplier::ONE.apply(1_000), 1_000);
+        assert!(RuleFeeMultiplier::from_decimal_str("0").is_err());
+        assert!(RuleFeeMultiplier::from_decimal_str("1.255").is_err());
+        assert!(RuleFeeMultiplier::from_decimal_str("100.01").is_err());
+        assert!(RuleFeeMultiplier::from_decimal_str("-1").is_err());
+    }
+
+    #[test]
+    fn parses_active_window_and_checks_bounds() {
+        let window = RuleActiveWindow::from_hhmm_strs("09:30", "17:00");
+        assert!(window.is_ok());
+
+        if let Ok(window) = window {
+            assert_eq!(window.start_minute(), 570);
+            assert_eq!(window.end_minute(), 1_020);
+            assert!(window.contains(570));
+            assert!(!window.contains(1_020));
+            assert_eq!(window.as_hhmm_string(), "09:30-17:00 UTC");
+        }
+
+        assert!(RuleActiveWindow::from_hhmm_strs("24:00", "01:00").is_err());
+        assert!(RuleActiveWindow::from_hhmm_strs("9:30", "17:00").is_err());
+        assert!(RuleActiveWindow::from_hhmm_strs("10:00", "10:00").is_err());
+    }
 }
@@ -5,7 +5,10 @@ use std::{
     sync::Arc,
 };
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+use serde::{Serialize, Serializer};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TxSubmissionMode {
     Jito,
     Direct,
@@ -36,7 +39,8 @@ impl Display for TxSubmissionMode {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofIngressSource {
     Websocket,
     Grpc,
@@ -70,7 +74,8 @@ impl Display for SofIngressSource {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofCommitmentLevel {
     Processed,
     Confirmed,
@@ -104,7 +109,8 @@ impl Display for SofCommitmentLevel {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofGossipRuntimeMode {
     Full,
     BootstrapOnly,
@@ -140,7 +146,8 @@ impl Display for SofGossipRuntimeMode {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofTxMode {
     Rpc,
     Jito,
@@ -180,7 +187,8 @@ impl Display for SofTxMode {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofTxStrategy {
     OrderedFallback,
     AllAtOnce,
@@ -211,7 +219,8 @@ impl Display for SofTxStrategy {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofTxRoute {
     Rpc,
     Jito,
@@ -245,7 +254,8 @@ impl Display for SofTxRoute {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofTxReliability {
     LowLatency,
     Balanced,
@@ -279,7 +289,8 @@ impl Display for SofTxReliability {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SofTxJitoTransport {
     JsonRpc,
     Grpc,
@@ -310,22 +321,142 @@ impl Display for SofTxJitoTransport {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventQueueDropPolicy {
+    DropNewest,
+    DropOldest,
+}
+
+impl EventQueueDropPolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "drop_newest" | "newest" => Some(Self::DropNewest),
+            "drop_oldest" | "oldest" => Some(Self::DropOldest),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::DropNewest => "drop_newest",
+            Self::DropOldest => "drop_oldest",
+        }
+    }
+}
+
+impl Display for EventQueueDropPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// When the wallet's WSOL ATA is closed (and its rent refunded) relative to the swap.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WsolCloseTiming {
+    Inline,
+    AfterConfirmation,
+    Never,
+}
+
+impl WsolCloseTiming {
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized = value.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "inline" => Some(Self::Inline),
+            "after_confirmation" | "deferred" => Some(Self::AfterConfirmation),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Inline => "inline",
+            Self::AfterConfirmation => "after_confirmation",
+            Self::Never => "never",
+        }
+    }
+}
+
+impl Display for WsolCloseTiming {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What strategies do with a signed swap transaction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionMode {
+    /// Submit it.
+    Live,
+    /// Log it and stop.
+    DryRun,
+    /// Simulate the fill against the pool reserves and book it in a virtual portfolio.
+    Paper,
+}
+
+impl ExecutionMode {
+    /// `paper_trading` takes precedence over `dry_run`; neither mode ever submits.
+    pub const fn from_flags(dry_run: bool, paper_trading: bool) -> Self {
+        if paper_trading {
+            Self::Paper
+        } else if dry_run {
+            Self::DryRun
+        } else {
+            Self::Live
+        }
+    }
+
+    #[inline(always)]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Live => "live",
+            Self::DryRun => "dry_run",
+            Self::Paper => "paper",
+        }
+    }
+}
+
+impl Display for ExecutionMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
 pub struct PriorityFeesMicrolamports(u64);
 
 impl PriorityFeesMicrolamports {
+    pub const DEFAULT_CEILING: u64 = 100_000_000;
+
     #[inline(always)]
     pub const fn new(value: u64) -> Self {
         Self(value)
     }
 
+    pub const fn try_new(value: u64, ceiling: u64) -> Result<Self, &'static str> {
+        if value > ceiling {
+            return Err("priority fee exceeds configured ceiling");
+        }
+
+        Ok(Self(value))
+    }
+
     #[inline(always)]
     pub const fn as_u64(self) -> u64 {
         self.0
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
 pub struct ReplayEventCount(NonZeroUsize);
 
 impl ReplayEventCount {
@@ -341,7 +472,8 @@ impl ReplayEventCount {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
 pub struct ReplayBurstSize(NonZeroUsize);
 
 impl ReplayBurstSize {
@@ -370,12 +502,32 @@ impl NonEmptyText {
         Ok(Self(value))
     }
 
+    /// Like `new`, but also rejects control characters such as newlines and NUL bytes, which
+    /// `Path` handling and rendered unit files cannot carry.
+    pub fn new_path(value: impl Into<Arc<str>>) -> Result<Self, &'static str> {
+        let text = Self::new(value)?;
+        if text.as_str().chars().any(char::is_control) {
+            return Err("path must not contain control characters");
+        }
+
+        Ok(text)
+    }
+
     #[inline(always)]
     pub fn as_str(&self) -> &str {
         &self.0
     }
 }
 
+impl Serialize for NonEmptyText {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl AsRef<str> for NonEmptyText {
     #[inline(always)]
     fn as_ref(&self) -> &str {
@@ -415,7 +567,7 @@ impl TryFrom<&str> for NonEmptyText {
 #[cfg(test)]
 mod tests {
     use super::{
-        NonEmptyText, PriorityFeesMicrolamports, ReplayBurstSize, ReplayEventCount,
+        ExecutionMode, NonEmptyText, PriorityFeesMicrolamports, ReplayBurstSize, ReplayEventCount,
         SofGossipRuntimeMode, TxSubmissionMode,
     };
 
@@ -436,6 +588,17 @@ mod tests {
         assert_eq!(TxSubmissionMode::parse("invalid"), None);
     }
 
+    #[test]
+    fn paper_trading_takes_precedence_over_dry_run() {
+        assert_eq!(ExecutionMode::from_flags(false, false), ExecutionMode::Live);
+        assert_eq!(
+            ExecutionMode::from_flags(true, false),
+            ExecutionMode::DryRun
+        );
+        assert_eq!(ExecutionMode::from_flags(false, true), ExecutionMode::Paper);
+        assert_eq!(ExecutionMode::from_flags(true, true), ExecutionMode::Paper);
+    }
+
     #[test]
     fn parses_gossip_runtime_modes() {
         assert_eq!(
@@ -458,12 +621,28 @@ mod tests {
         assert!(NonEmptyText::try_from(" ".to_owned()).is_err());
     }
 
+    #[test]
+    fn rejects_control_characters_in_paths() {
+        assert!(NonEmptyText::new_path("/tmp/slotstrike.sock").is_ok());
+        assert!(NonEmptyText::new_path("/tmp/slot\nstrike.sock").is_err());
+        assert!(NonEmptyText::new_path("/tmp/slotstrike\0.sock").is_err());
+        assert!(NonEmptyText::new_path(" ").is_err());
+    }
+
     #[test]
     fn keeps_priority_fee_scalar() {
         let value = PriorityFeesMicrolamports::new(42);
         assert_eq!(value.as_u64(), 42);
     }
 
+    #[test]
+    fn guards_priority_fee_ceiling() {
+        let ceiling = PriorityFeesMicrolamports::DEFAULT_CEILING;
+        assert!(PriorityFeesMicrolamports::try_new(ceiling, ceiling).is_ok());
+        assert!(PriorityFeesMicrolamports::try_new(ceiling.saturating_sub(1), ceiling).is_ok());
+        assert!(PriorityFeesMicrolamports::try_new(ceiling.saturating_add(1), ceiling).is_err());
+    }
+
     #[test]
     fn enforces_non_zero_replay_counts() {
         assert!(ReplayEventCount::new(1).is_ok());
@@ -1,6 +1,11 @@
+use serde::Serialize;
+
 const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+const SOL_DECIMALS: u8 = 9;
+const BPS_DENOMINATOR: u128 = 10_000;
 
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
 pub struct Lamports(u64);
 
 impl Lamports {
@@ -14,6 +19,29 @@ impl Lamports {
         self.0
     }
 
+    #[inline(always)]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(value) => Some(Self(value)),
+            None => None,
+        }
+    }
+
+    pub fn checked_scale_bps(self, bps: u16) -> Option<Self> {
+        let scaled = u128::from(self.0)
+            .checked_mul(u128::from(bps))?
+            .checked_div(BPS_DENOMINATOR)?;
+        u64::try_from(scaled).ok().map(Self)
+    }
+
     #[inline(always)]
     pub fn as_sol_string(self) -> String {
         let whole = self.0 / LAMPORTS_PER_SOL;
@@ -30,6 +58,47 @@ impl Lamports {
 
         format!("{whole}.{fractional_string}")
     }
+
+    pub fn as_sol_string_fixed(self, decimals: u8) -> String {
+        let whole = self.0 / LAMPORTS_PER_SOL;
+        let decimals = usize::from(decimals.min(SOL_DECIMALS));
+        if decimals == 0 {
+            return whole.to_string();
+        }
+
+        let fractional = format!("{:09}", self.0 % LAMPORTS_PER_SOL);
+        let fractional = fractional.get(..decimals).unwrap_or_default();
+        format!("{whole}.{fractional}")
+    }
+}
+
+/// Inclusive bounds on a pool's initial SOL-side liquidity; an unset bound is open.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct InitLiquidityBand {
+    pub min: Option<Lamports>,
+    pub max: Option<Lamports>,
+}
+
+impl InitLiquidityBand {
+    #[inline(always)]
+    pub const fn contains(self, amount: Lamports) -> bool {
+        let above_min = match self.min {
+            Some(min) => amount.0 >= min.0,
+            None => true,
+        };
+        let below_max = match self.max {
+            Some(max) => amount.0 <= max.0,
+            None => true,
+        };
+        above_min && below_max
+    }
+
+    pub const fn is_ordered(self) -> bool {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => min.0 <= max.0,
+            _ => true,
+        }
+    }
 }
 
 pub fn parse_positive_sol_str_to_lamports(sol: &str) -> Option<Lamports> {
@@ -118,7 +187,7 @@ fn parse_ascii_u64(value: &str) -> Option<u64> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Lamports, parse_positive_sol_str_to_lamports};
+    use super::{InitLiquidityBand, Lamports, parse_positive_sol_str_to_lamports};
 
     #[test]
     fn parses_fixed_point_sol_amounts() {
@@ -155,4 +224,73 @@ mod tests {
         assert_eq!(Lamports::new(1_230_000_000).as_sol_string(), "1.23");
         assert_eq!(Lamports::new(1).as_sol_string(), "0.000000001");
     }
+
+    #[test]
+    fn formats_sol_strings_with_fixed_decimals() {
+        let amount = Lamports::new(1_500_000_000);
+        assert_eq!(amount.as_sol_string_fixed(0), "1");
+        assert_eq!(amount.as_sol_string_fixed(2), "1.50");
+        assert_eq!(amount.as_sol_string_fixed(9), "1.500000000");
+        assert_eq!(amount.as_sol_string_fixed(12), "1.500000000");
+        assert_eq!(Lamports::new(1).as_sol_string_fixed(2), "0.00");
+    }
+
+    #[test]
+    fn checked_arithmetic_guards_overflow_and_underflow() {
+        let one = Lamports::new(1);
+        let max = Lamports::new(u64::MAX);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(
+            Lamports::new(u64::MAX.saturating_sub(1)).checked_add(one),
+            Some(Lamports::new(u64::MAX))
+        );
+        assert_eq!(Lamports::new(0).checked_sub(one), None);
+        assert_eq!(one.checked_sub(one), Some(Lamports::new(0)));
+    }
+
+    #[test]
+    fn scales_by_basis_points() {
+        let amount = Lamports::new(1_000_000_000);
+        assert_eq!(
+            amount.checked_scale_bps(150),
+            Some(Lamports::new(15_000_000))
+        );
+        assert_eq!(amount.checked_scale_bps(10_000), Some(amount));
+        assert_eq!(amount.checked_scale_bps(0), Some(Lamports::new(0)));
+        assert_eq!(
+            Lamports::new(u64::MAX).checked_scale_bps(10_000),
+            Some(Lamports::new(u64::MAX))
+        );
+        assert_eq!(Lamports::new(u64::MAX).checked_scale_bps(10_001), None);
+    }
+
+    #[test]
+    fn liquidity_band_checks_each_bound_independently() {
+        let band = InitLiquidityBand {
+            min: Some(Lamports::new(5)),
+            max: Some(Lamports::new(50)),
+        };
+        assert!(!band.contains(Lamports::new(4)));
+        assert!(band.contains(Lamports::new(5)));
+        assert!(band.contains(Lamports::new(50)));
+        assert!(!band.contains(Lamports::new(51)));
+
+        let max_only = InitLiquidityBand {
+            min: None,
+            max: Some(Lamports::new(50)),
+        };
+        assert!(max_only.contains(Lamports::new(0)));
+        assert!(!max_only.contains(Lamports::new(51)));
+        assert!(InitLiquidityBand::default().contains(Lamports::new(u64::MAX)));
+    }
+
+    #[test]
+    fn liquidity_band_rejects_inverted_bounds() {
+        let band = InitLiquidityBand {
+            min: Some(Lamports::new(50)),
+            max: Some(Lamports::new(5)),
+        };
+        assert!(!band.is_ordered());
+        assert!(InitLiquidityBand::default().is_ordered());
+    }
 }
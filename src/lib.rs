@@ -5,5 +5,7 @@ pub mod app;
 pub mod domain;
 pub mod ports;
 pub mod slices;
+#[cfg(test)]
+mod test_support;
 
 pub const MAX_RETRIES: usize = 1_000;
@@ -1 +1,2 @@
 pub mod rule_repository;
+pub mod transaction_submitter;
@@ -1,4 +1,11 @@
-use crate::domain::entities::SnipeRule;
+use std::time::Duration;
+
+use futures::stream::{self, Stream};
+
+use crate::domain::{entities::SnipeRule, value_objects::RuleAddress};
+
+/// Floor for `watch_rules` polling; a zero interval would make `tokio::time::interval` panic.
+pub const MIN_WATCH_POLL_INTERVAL: Duration = Duration::from_millis(1);
 
 #[trait_variant::make(Send + Sync)]
 pub trait RuleRepository {
@@ -7,4 +14,131 @@ pub trait RuleRepository {
         file_type: &str,
         initial: bool,
     ) -> Result<Vec<SnipeRule>, std::io::Error>;
+
+    async fn load_deployer_list(
+        &self,
+        list_type: &str,
+        initial: bool,
+    ) -> Result<Vec<RuleAddress>, std::io::Error>;
+
+    /// Yields the current `file_type` rules, then each changed set after that.
+    ///
+    /// The default polls `load_rules` every `poll_interval` and skips unchanged results;
+    /// every failed load is yielded. Intervals below [`MIN_WATCH_POLL_INTERVAL`] are raised
+    /// to it. Backends that can push changes should override it.
+    fn watch_rules<'repo>(
+        &'repo self,
+        file_type: &'repo str,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Vec<SnipeRule>, std::io::Error>> + 'repo
+    where
+        Self: Sized,
+    {
+        let ticker = tokio::time::interval(poll_interval.max(MIN_WATCH_POLL_INTERVAL));
+        stream::unfold(
+            (None::<Vec<SnipeRule>>, ticker),
+            move |(mut previous, mut ticker)| async move {
+                loop {
+                    ticker.tick().await;
+                    match self.load_rules(file_type, false).await {
+                        Ok(rules) if previous.as_ref() == Some(&rules) => {}
+                        Ok(rules) => {
+                            previous = Some(rules.clone());
+                            return Some((Ok(rules), (previous, ticker)));
+                        }
+                        Err(error) => return Some((Err(error), (previous, ticker))),
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Mutex, PoisonError},
+        time::Duration,
+    };
+
+    use futures::StreamExt as _;
+
+    use super::RuleRepository;
+    use crate::domain::{
+        entities::SnipeRule,
+        value_objects::{RuleAddress, RuleSlippageBps, RuleSolAmount, sol_amount::Lamports},
+    };
+
+    #[derive(Default)]
+    struct InMemoryRepository {
+        rules: Mutex<Vec<SnipeRule>>,
+    }
+
+    impl InMemoryRepository {
+        fn replace(&self, rules: Vec<SnipeRule>) {
+            *self.rules.lock().unwrap_or_else(PoisonError::into_inner) = rules;
+        }
+    }
+
+    impl RuleRepository for InMemoryRepository {
+        async fn load_rules(
+            &self,
+            _file_type: &str,
+            _initial: bool,
+        ) -> Result<Vec<SnipeRule>, std::io::Error> {
+            Ok(self
+                .rules
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone())
+        }
+
+        async fn load_deployer_list(
+            &self,
+            _list_type: &str,
+            _initial: bool,
+        ) -> Result<Vec<RuleAddress>, std::io::Error> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn build_rule(address: &str) -> Option<SnipeRule> {
+        let address = RuleAddress::try_from(address).ok()?;
+        let slippage = RuleSlippageBps::from_pct_str("1").ok()?;
+        Some(SnipeRule::new(
+            address,
+            RuleSolAmount::new(Lamports::new(1_000_000_000)),
+            RuleSolAmount::new(Lamports::new(100_000_000)),
+            slippage,
+        ))
+    }
+
+    #[tokio::test]
+    async fn default_watch_yields_only_when_rules_change() {
+        let repository = InMemoryRepository::default();
+        let mut changes = Box::pin(repository.watch_rules("MINTS", Duration::from_millis(10)));
+
+        let initial = changes.next().await;
+        assert!(matches!(initial, Some(Ok(ref rules)) if rules.is_empty()));
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), changes.next())
+                .await
+                .is_err()
+        );
+
+        let rule = build_rule("So11111111111111111111111111111111111111112");
+        assert!(rule.is_some());
+        repository.replace(rule.into_iter().collect());
+        let changed = tokio::time::timeout(Duration::from_secs(1), changes.next()).await;
+        assert!(matches!(changed, Ok(Some(Ok(ref rules))) if rules.len() == 1));
+    }
+
+    #[tokio::test]
+    async fn default_watch_accepts_a_zero_poll_interval() {
+        let repository = InMemoryRepository::default();
+        let mut changes = Box::pin(repository.watch_rules("MINTS", Duration::ZERO));
+
+        let initial = tokio::time::timeout(Duration::from_secs(1), changes.next()).await;
+        assert!(matches!(initial, Ok(Some(Ok(ref rules))) if rules.is_empty()));
+    }
 }
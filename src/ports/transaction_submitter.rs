@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use solana_sdk::{signature::Signature, transaction::VersionedTransaction};
+use thiserror::Error;
+
+use crate::domain::value_objects::TxSubmissionMode;
+
+#[derive(Debug, Error)]
+pub enum TransactionSubmitError {
+    #[error("failed to serialize transaction for SOF-TX: {detail}")]
+    Serialize { detail: String },
+    #[error("SOF-TX submit failed: {detail}")]
+    SofTx { detail: String },
+    #[error("{detail}")]
+    Rpc { detail: String },
+}
+
+/// Sends a signed swap transaction and returns the signature it landed under.
+#[async_trait]
+pub trait TransactionSubmitter: Send + Sync {
+    async fn submit(
+        &self,
+        transaction: &VersionedTransaction,
+        mode: TxSubmissionMode,
+    ) -> Result<Signature, TransactionSubmitError>;
+}
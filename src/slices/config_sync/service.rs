@@ -1,14 +1,25 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    pin::pin,
+    sync::Arc,
+    time::Duration,
+};
 
-use tokio::{sync::watch, time};
+use futures::{StreamExt as _, stream};
+use tokio::{sync::watch, task::JoinHandle, time};
 
 use crate::{
     domain::{aggregates::RuleBook, entities::SnipeRule, value_objects::RuleAddress},
     ports::rule_repository::RuleRepository,
 };
 
+use super::watcher::ConfigFileWatcher;
+
 const MINT_RULES: &str = "MINTS";
 const DEPLOYER_RULES: &str = "DEPLOYERS";
+const DEPLOYER_BLOCKLIST: &str = "BLOCKLIST";
+const DEPLOYER_ALLOWLIST: &str = "ALLOWLIST";
 
 pub async fn load_rulebook<R: RuleRepository>(
     repository: &R,
@@ -16,14 +27,44 @@ pub async fn load_rulebook<R: RuleRepository>(
 ) -> Result<Arc<RuleBook>, std::io::Error> {
     let mint_rules = repository.load_rules(MINT_RULES, initial).await?;
     let deployer_rules = repository.load_rules(DEPLOYER_RULES, initial).await?;
+    let blocklist = repository
+        .load_deployer_list(DEPLOYER_BLOCKLIST, initial)
+        .await?;
+    let allowlist = repository
+        .load_deployer_list(DEPLOYER_ALLOWLIST, initial)
+        .await?;
+
+    Ok(Arc::new(
+        RuleBook::new(mint_rules, deployer_rules).with_deployer_lists(blocklist, allowlist),
+    ))
+}
+
+async fn load_deployer_lists<R: RuleRepository>(
+    repository: &R,
+) -> Result<(Vec<RuleAddress>, Vec<RuleAddress>), std::io::Error> {
+    let blocklist = repository
+        .load_deployer_list(DEPLOYER_BLOCKLIST, false)
+        .await?;
+    let allowlist = repository
+        .load_deployer_list(DEPLOYER_ALLOWLIST, false)
+        .await?;
+    Ok((blocklist, allowlist))
+}
 
-    Ok(Arc::new(RuleBook::new(mint_rules, deployer_rules)))
+/// One wake-up of the polling fallback: a rule set from `watch_rules`, or the deployer
+/// list tick.
+enum PolledChange {
+    MintRules(Result<Vec<SnipeRule>, std::io::Error>),
+    DeployerRules(Result<Vec<SnipeRule>, std::io::Error>),
+    DeployerLists,
 }
 
 pub struct ConfigSyncService<R: RuleRepository> {
     repository: Arc<R>,
     sender: watch::Sender<Arc<RuleBook>>,
     previous: Arc<RuleBook>,
+    reload_interval: Duration,
+    watch_paths: Vec<PathBuf>,
 }
 
 impl<R: RuleRepository + 'static> ConfigSyncService<R> {
@@ -35,52 +76,171 @@ impl<R: RuleRepository + 'static> ConfigSyncService<R> {
         repository: Arc<R>,
         sender: watch::Sender<Arc<RuleBook>>,
         previous: Arc<RuleBook>,
+        reload_interval: Duration,
     ) -> Self {
         Self {
             repository,
             sender,
             previous,
+            reload_interval,
+            watch_paths: Vec::new(),
         }
     }
 
-    pub fn spawn(self) {
+    /// Reloads when one of `paths` changes on disk instead of polling, falling back to the
+    /// reload interval when the platform cannot watch them.
+    #[must_use]
+    pub fn with_watch_paths(mut self, paths: Vec<PathBuf>) -> Self {
+        self.watch_paths = paths;
+        self
+    }
+
+    #[must_use]
+    pub fn spawn(self) -> JoinHandle<()> {
         tokio::spawn(async move {
             self.run().await;
-        });
+        })
     }
 
     async fn run(mut self) {
-        let mut interval = time::interval(Duration::from_secs(1));
+        if let Some(mut watcher) = self.start_watcher() {
+            // Catch edits made between the initial load and the watch starting.
+            if !self.reload().await {
+                return;
+            }
+            while watcher.changed().await.is_some() {
+                if !self.reload().await {
+                    return;
+                }
+            }
+            log::warn!(
+                "Config watcher stopped; polling config files every {:?}",
+                self.reload_interval
+            );
+        }
 
-        loop {
-            interval.tick().await;
+        self.poll().await;
+    }
 
-            let next = match load_rulebook(self.repository.as_ref(), false).await {
-                Ok(value) => value,
-                Err(error) => {
+    /// Follows both rule files through `watch_rules`, so push-capable repositories publish
+    /// as soon as they change. Deployer lists are not covered by it and are reloaded on
+    /// every `reload_interval` tick.
+    async fn poll(mut self) {
+        let repository = Arc::clone(&self.repository);
+        let mint_changes = repository
+            .watch_rules(MINT_RULES, self.reload_interval)
+            .map(PolledChange::MintRules);
+        let deployer_changes = repository
+            .watch_rules(DEPLOYER_RULES, self.reload_interval)
+            .map(PolledChange::DeployerRules);
+        let list_ticks = stream::unfold(time::interval(self.reload_interval), |mut ticker| async {
+            ticker.tick().await;
+            Some((PolledChange::DeployerLists, ticker))
+        });
+        let mut changes = pin!(stream::select(
+            stream::select(mint_changes, deployer_changes),
+            list_ticks
+        ));
+
+        let mut mint_rules = None;
+        let mut deployer_rules = None;
+        while let Some(change) = changes.next().await {
+            match change {
+                PolledChange::MintRules(Ok(rules)) => mint_rules = Some(rules),
+                PolledChange::DeployerRules(Ok(rules)) => deployer_rules = Some(rules),
+                PolledChange::MintRules(Err(error)) | PolledChange::DeployerRules(Err(error)) => {
                     log::error!("Failed to refresh config files: {}", error);
                     continue;
                 }
+                PolledChange::DeployerLists => {}
+            }
+            let (Some(mints), Some(deployers)) = (&mint_rules, &deployer_rules) else {
+                continue;
             };
 
-            if next == self.previous {
-                continue;
+            let next = match load_deployer_lists(self.repository.as_ref()).await {
+                Ok((blocklist, allowlist)) => Arc::new(
+                    RuleBook::new(mints.clone(), deployers.clone())
+                        .with_deployer_lists(blocklist, allowlist),
+                ),
+                Err(error) => {
+                    log::error!("Failed to refresh config files: {}", error);
+                    continue;
+                }
+            };
+            if !self.publish(next) {
+                return;
             }
+        }
+    }
 
-            report_changes(self.previous.mint_rules(), next.mint_rules(), "MINTS");
-            report_changes(
-                self.previous.deployer_rules(),
-                next.deployer_rules(),
-                "DEPLOYERS",
-            );
+    fn start_watcher(&self) -> Option<ConfigFileWatcher> {
+        if self.watch_paths.is_empty() {
+            return None;
+        }
 
-            if self.sender.send(Arc::clone(&next)).is_err() {
-                log::warn!("Config listeners dropped. Stopping config sync service.");
-                return;
+        match ConfigFileWatcher::watch(&self.watch_paths) {
+            Ok(watcher) => {
+                log::info!(
+                    "Watching {} config file(s) for rule changes",
+                    self.watch_paths.len()
+                );
+                Some(watcher)
             }
+            Err(error) => {
+                log::warn!(
+                    "Config file watching unavailable ({}); polling every {:?}",
+                    error,
+                    self.reload_interval
+                );
+                None
+            }
+        }
+    }
+
+    /// Publishes the reloaded rulebook if it changed; `false` once no listener remains.
+    async fn reload(&mut self) -> bool {
+        match load_rulebook(self.repository.as_ref(), false).await {
+            Ok(next) => self.publish(next),
+            Err(error) => {
+                log::error!("Failed to refresh config files: {}", error);
+                true
+            }
+        }
+    }
+
+    /// Sends `next` when it differs from the last published rulebook; `false` once no
+    /// listener remains.
+    fn publish(&mut self, next: Arc<RuleBook>) -> bool {
+        if next == self.previous {
+            return true;
+        }
+
+        report_changes(self.previous.mint_rules(), next.mint_rules(), "MINTS");
+        report_changes(
+            self.previous.deployer_rules(),
+            next.deployer_rules(),
+            "DEPLOYERS",
+        );
+        report_catch_all_change(self.previous.catch_all_rule(), next.catch_all_rule());
+        report_list_changes(
+            self.previous.blocked_deployers(),
+            next.blocked_deployers(),
+            DEPLOYER_BLOCKLIST,
+        );
+        report_list_changes(
+            self.previous.allowed_deployers(),
+            next.allowed_deployers(),
+            DEPLOYER_ALLOWLIST,
+        );
 
-            self.previous = next;
+        if self.sender.send(Arc::clone(&next)).is_err() {
+            log::warn!("Config listeners dropped. Stopping config sync service.");
+            return false;
         }
+
+        self.previous = next;
+        true
     }
 }
 
@@ -124,3 +284,183 @@ fn report_changes(
         }
     }
 }
+
+fn report_list_changes(
+    old_data: &HashSet<RuleAddress>,
+    new_data: &HashSet<RuleAddress>,
+    list_name: &str,
+) {
+    for address in new_data.difference(old_data) {
+        log::info!("{} > Added - {}", list_name, address);
+    }
+    for address in old_data.difference(new_data) {
+        log::info!("{} > Removed - {}", list_name, address);
+    }
+}
+
+fn report_catch_all_change(old_rule: Option<&SnipeRule>, new_rule: Option<&SnipeRule>) {
+    match (old_rule, new_rule) {
+        (Some(old_rule), Some(new_rule)) if old_rule != new_rule => {
+            log::info!(
+                "CATCH-ALL > Updated \\n\t\tOld > Snipe height: {} SOL, Jito tip: {} SOL, Slippage: {} % \\n\t\tNew > Snipe height: {} SOL, Jito tip: {} SOL, Slippage: {} %",
+                old_rule.snipe_height().as_sol_string(),
+                old_rule.jito_tip().as_sol_string(),
+                old_rule.slippage().as_pct_string(),
+                new_rule.snipe_height().as_sol_string(),
+                new_rule.jito_tip().as_sol_string(),
+                new_rule.slippage().as_pct_string(),
+            );
+        }
+        (None, Some(new_rule)) => {
+            log::info!(
+                "CATCH-ALL > Added \\n\t\tValue > Snipe height: {} SOL, Jito tip: {} SOL, Slippage: {} %",
+                new_rule.snipe_height().as_sol_string(),
+                new_rule.jito_tip().as_sol_string(),
+                new_rule.slippage().as_pct_string(),
+            );
+        }
+        (Some(_), None) => {
+            log::info!("CATCH-ALL > Removed");
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use futures::{
+        StreamExt as _,
+        stream::{self, Stream},
+    };
+    use tokio::sync::watch;
+
+    use super::{ConfigSyncService, MINT_RULES};
+    use crate::{
+        domain::{
+            aggregates::RuleBook,
+            entities::SnipeRule,
+            value_objects::{RuleAddress, RuleSlippageBps, RuleSolAmount, sol_amount::Lamports},
+        },
+        ports::rule_repository::RuleRepository,
+    };
+
+    #[derive(Default)]
+    struct CountingRepository {
+        reloads: AtomicUsize,
+    }
+
+    impl RuleRepository for CountingRepository {
+        async fn load_rules(
+            &self,
+            file_type: &str,
+            _initial: bool,
+        ) -> Result<Vec<SnipeRule>, std::io::Error> {
+            if file_type == MINT_RULES {
+                self.reloads.fetch_add(1, Ordering::Relaxed);
+            }
+            Ok(Vec::new())
+        }
+
+        async fn load_deployer_list(
+            &self,
+            _list_type: &str,
+            _initial: bool,
+        ) -> Result<Vec<RuleAddress>, std::io::Error> {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Loads nothing, but pushes one mint rule through `watch_rules`.
+    struct PushingRepository;
+
+    impl RuleRepository for PushingRepository {
+        async fn load_rules(
+            &self,
+            _file_type: &str,
+            _initial: bool,
+        ) -> Result<Vec<SnipeRule>, std::io::Error> {
+            Ok(Vec::new())
+        }
+
+        async fn load_deployer_list(
+            &self,
+            _list_type: &str,
+            _initial: bool,
+        ) -> Result<Vec<RuleAddress>, std::io::Error> {
+            Ok(Vec::new())
+        }
+
+        fn watch_rules<'repo>(
+            &'repo self,
+            file_type: &'repo str,
+            _poll_interval: Duration,
+        ) -> impl Stream<Item = Result<Vec<SnipeRule>, std::io::Error>> + 'repo {
+            let rules = if file_type == MINT_RULES {
+                build_rule("So11111111111111111111111111111111111111112")
+                    .into_iter()
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            stream::iter([Ok::<_, std::io::Error>(rules)]).chain(stream::pending())
+        }
+    }
+
+    fn build_rule(address: &str) -> Option<SnipeRule> {
+        let address = RuleAddress::try_from(address).ok()?;
+        let slippage = RuleSlippageBps::from_pct_str("1").ok()?;
+        Some(SnipeRule::new(
+            address,
+            RuleSolAmount::new(Lamports::new(1_000_000_000)),
+            RuleSolAmount::new(Lamports::new(100_000_000)),
+            slippage,
+        ))
+    }
+
+    #[tokio::test]
+    async fn publishes_rules_pushed_through_watch_rules() {
+        let initial = Arc::new(RuleBook::default());
+        let (sender, mut receiver) = watch::channel(Arc::clone(&initial));
+
+        let reloader = ConfigSyncService::new(
+            Arc::new(PushingRepository),
+            sender,
+            initial,
+            Duration::from_secs(3_600),
+        )
+        .spawn();
+        let changed = tokio::time::timeout(Duration::from_secs(1), receiver.changed()).await;
+        reloader.abort();
+
+        assert!(matches!(changed, Ok(Ok(()))));
+        assert_eq!(receiver.borrow().mint_rules().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn reloads_on_the_configured_interval() {
+        let repository = Arc::new(CountingRepository::default());
+        let initial = Arc::new(RuleBook::default());
+        let (sender, _receiver) = watch::channel(Arc::clone(&initial));
+
+        let reloader = ConfigSyncService::new(
+            Arc::clone(&repository),
+            sender,
+            initial,
+            Duration::from_millis(20),
+        )
+        .spawn();
+        tokio::time::sleep(Duration::from_millis(110)).await;
+        reloader.abort();
+
+        let reloads = repository.reloads.load(Ordering::Relaxed);
+        assert!((3..=7).contains(&reloads), "reloads={reloads}");
+    }
+}
@@ -0,0 +1,95 @@
+use std::path::{Path, PathBuf};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+/// Filesystem notifications for the config files; dropping it stops the OS watch.
+pub struct ConfigFileWatcher {
+    _watcher: RecommendedWatcher,
+    changes: mpsc::UnboundedReceiver<()>,
+}
+
+impl ConfigFileWatcher {
+    /// Watches each file's parent directory rather than the file itself, so editors that save
+    /// by renaming a temporary file over the config are still seen.
+    pub fn watch(paths: &[PathBuf]) -> notify::Result<Self> {
+        let files = paths
+            .iter()
+            .map(std::path::absolute)
+            .collect::<Result<Vec<_>, _>>()?;
+        let directories = files
+            .iter()
+            .filter_map(|file| file.parent().map(Path::to_path_buf))
+            .collect::<Vec<_>>();
+
+        let (sender, changes) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+            let Ok(event) = result else {
+                return;
+            };
+            if is_content_change(event.kind) && event.paths.iter().any(|path| files.contains(path))
+            {
+                let _sent = sender.send(());
+            }
+        })?;
+        for directory in &directories {
+            watcher.watch(directory, RecursiveMode::NonRecursive)?;
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            changes,
+        })
+    }
+
+    /// Waits for the next change, folding in any burst already queued (editors often emit
+    /// several events per save). `None` once the watcher has stopped.
+    pub async fn changed(&mut self) -> Option<()> {
+        self.changes.recv().await?;
+        while self.changes.try_recv().is_ok() {}
+        Some(())
+    }
+}
+
+const fn is_content_change(kind: EventKind) -> bool {
+    matches!(kind, EventKind::Create(_) | EventKind::Modify(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::fs;
+
+    use super::ConfigFileWatcher;
+    use crate::test_support::temp_path;
+
+    #[tokio::test]
+    async fn reports_a_modified_config_file() {
+        let config_path = temp_path("config_watcher", "toml");
+        assert!(fs::write(&config_path, "[runtime]\n").await.is_ok());
+
+        let watcher = ConfigFileWatcher::watch(std::slice::from_ref(&config_path));
+        assert!(watcher.is_ok());
+        if let Ok(mut watcher) = watcher {
+            let unrelated_path = temp_path("config_watcher_unrelated", "toml");
+            assert!(fs::write(&unrelated_path, "x").await.is_ok());
+            assert!(
+                tokio::time::timeout(Duration::from_millis(200), watcher.changed())
+                    .await
+                    .is_err()
+            );
+
+            assert!(
+                fs::write(&config_path, "[runtime]\npriority_fees = 1\n")
+                    .await
+                    .is_ok()
+            );
+            let changed = tokio::time::timeout(Duration::from_secs(5), watcher.changed()).await;
+            assert_eq!(changed.ok().flatten(), Some(()));
+            assert!(fs::remove_file(&unrelated_path).await.is_ok());
+        }
+
+        assert!(fs::remove_file(&config_path).await.is_ok());
+    }
+}
@@ -1,62 +1,297 @@
-use std::{str::FromStr, sync::LazyLock};
+use std::{
+    str::FromStr,
+    sync::{
+        OnceLock,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 
 use solana_sdk::pubkey::Pubkey;
+use thiserror::Error;
 
-use crate::adapters::raydium::{
-    RAYDIUM_STANDARD_AMM_PROGRAM_ID, RAYDIUM_V4_PROGRAM_ID, TOKEN_PROGRAM_ID, WSOL_ADDRESS,
+use crate::{
+    adapters::raydium::{
+        RAYDIUM_STANDARD_AMM_PROGRAM_ID, RAYDIUM_V4_PROGRAM_ID, TOKEN_PROGRAM_ID, WSOL_ADDRESS,
+    },
+    domain::settings::AddressOverrides,
 };
 
-const JITO_TIP_ACCOUNT_ADDRESS: &str = "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL";
+const JITO_TIP_ACCOUNT_ADDRESSES: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+static RESOLVED_ADDRESSES: OnceLock<Option<ResolvedAddresses>> = OnceLock::new();
+static JITO_TIP_CURSOR: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressField {
+    WsolMint,
+    TokenProgram,
+    JitoTipAccount,
+    JitoTipAccounts,
+    RaydiumStandardAmmProgram,
+    RaydiumV4Program,
+}
+
+impl AddressField {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::WsolMint => "addresses.wsol_mint",
+            Self::TokenProgram => "addresses.token_program",
+            Self::JitoTipAccount => "addresses.jito_tip_account",
+            Self::JitoTipAccounts => "addresses.jito_tip_accounts",
+            Self::RaydiumStandardAmmProgram => "addresses.raydium_cpmm_program",
+            Self::RaydiumV4Program => "addresses.raydium_v4_program",
+        }
+    }
+}
 
-static WSOL_PUBKEY: LazyLock<Option<Pubkey>> =
-    LazyLock::new(|| Pubkey::from_str(WSOL_ADDRESS).ok());
-static TOKEN_PROGRAM_PUBKEY: LazyLock<Option<Pubkey>> =
-    LazyLock::new(|| Pubkey::from_str(TOKEN_PROGRAM_ID).ok());
-static JITO_TIP_PUBKEY: LazyLock<Option<Pubkey>> =
-    LazyLock::new(|| Pubkey::from_str(JITO_TIP_ACCOUNT_ADDRESS).ok());
-static RAYDIUM_STANDARD_AMM_PROGRAM_PUBKEY: LazyLock<Option<Pubkey>> =
-    LazyLock::new(|| Pubkey::from_str(RAYDIUM_STANDARD_AMM_PROGRAM_ID).ok());
-static RAYDIUM_V4_PROGRAM_PUBKEY: LazyLock<Option<Pubkey>> =
-    LazyLock::new(|| Pubkey::from_str(RAYDIUM_V4_PROGRAM_ID).ok());
+impl std::fmt::Display for AddressField {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AddressInitError {
+    #[error("invalid {field} pubkey '{value}'")]
+    InvalidPubkey { field: AddressField, value: String },
+    #[error("sniper addresses were already initialized")]
+    AlreadyInitialized,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedAddresses {
+    pub wsol: Pubkey,
+    pub token_program: Pubkey,
+    pub jito_tips: Vec<Pubkey>,
+    pub raydium_standard_amm_program: Pubkey,
+    pub raydium_v4_program: Pubkey,
+}
+
+impl ResolvedAddresses {
+    pub fn resolve(overrides: &AddressOverrides) -> Result<Self, AddressInitError> {
+        Ok(Self {
+            wsol: resolve_pubkey(
+                AddressField::WsolMint,
+                overrides.wsol_mint.as_deref(),
+                WSOL_ADDRESS,
+            )?,
+            token_program: resolve_pubkey(
+                AddressField::TokenProgram,
+                overrides.token_program.as_deref(),
+                TOKEN_PROGRAM_ID,
+            )?,
+            jito_tips: resolve_jito_tips(overrides)?,
+            raydium_standard_amm_program: resolve_pubkey(
+                AddressField::RaydiumStandardAmmProgram,
+                overrides.raydium_cpmm_program.as_deref(),
+                RAYDIUM_STANDARD_AMM_PROGRAM_ID,
+            )?,
+            raydium_v4_program: resolve_pubkey(
+                AddressField::RaydiumV4Program,
+                overrides.raydium_v4_program.as_deref(),
+                RAYDIUM_V4_PROGRAM_ID,
+            )?,
+        })
+    }
+}
+
+pub fn init_addresses(overrides: &AddressOverrides) -> Result<ResolvedAddresses, AddressInitError> {
+    let resolved = ResolvedAddresses::resolve(overrides)?;
+    RESOLVED_ADDRESSES
+        .set(Some(resolved.clone()))
+        .map_err(|_existing| AddressInitError::AlreadyInitialized)?;
+    Ok(resolved)
+}
+
+#[inline(always)]
+fn resolved_addresses() -> Option<&'static ResolvedAddresses> {
+    RESOLVED_ADDRESSES
+        .get_or_init(|| ResolvedAddresses::resolve(&AddressOverrides::default()).ok())
+        .as_ref()
+}
+
+fn resolve_pubkey(
+    field: AddressField,
+    configured: Option<&str>,
+    default: &str,
+) -> Result<Pubkey, AddressInitError> {
+    let value = configured.unwrap_or(default);
+    Pubkey::from_str(value).map_err(|_source| AddressInitError::InvalidPubkey {
+        field,
+        value: value.to_owned(),
+    })
+}
+
+fn resolve_jito_tips(overrides: &AddressOverrides) -> Result<Vec<Pubkey>, AddressInitError> {
+    if !overrides.jito_tip_accounts.is_empty() {
+        return overrides
+            .jito_tip_accounts
+            .iter()
+            .map(|value| resolve_pubkey(AddressField::JitoTipAccounts, Some(value), value))
+            .collect();
+    }
+
+    if let Some(value) = overrides.jito_tip_account.as_deref() {
+        return resolve_pubkey(AddressField::JitoTipAccount, Some(value), value)
+            .map(|pubkey| vec![pubkey]);
+    }
+
+    JITO_TIP_ACCOUNT_ADDRESSES
+        .iter()
+        .map(|value| resolve_pubkey(AddressField::JitoTipAccounts, None, value))
+        .collect()
+}
+
+#[inline(always)]
+fn jito_tip_at(tips: &[Pubkey], cursor: usize) -> Option<Pubkey> {
+    let index = cursor.checked_rem(tips.len())?;
+    tips.get(index).copied()
+}
 
 #[inline(always)]
 pub fn wsol_pubkey() -> Option<Pubkey> {
-    WSOL_PUBKEY.as_ref().copied()
+    resolved_addresses().map(|addresses| addresses.wsol)
 }
 
 #[inline(always)]
 pub fn token_program_pubkey() -> Option<Pubkey> {
-    TOKEN_PROGRAM_PUBKEY.as_ref().copied()
+    resolved_addresses().map(|addresses| addresses.token_program)
 }
 
 #[inline(always)]
-pub fn jito_tip_pubkey() -> Option<Pubkey> {
-    JITO_TIP_PUBKEY.as_ref().copied()
+pub fn next_jito_tip_pubkey() -> Option<Pubkey> {
+    let addresses = resolved_addresses()?;
+    let cursor = JITO_TIP_CURSOR.fetch_add(1, Ordering::Relaxed);
+    jito_tip_at(&addresses.jito_tips, cursor)
 }
 
 #[inline(always)]
 pub fn raydium_standard_amm_program_pubkey() -> Option<Pubkey> {
-    RAYDIUM_STANDARD_AMM_PROGRAM_PUBKEY.as_ref().copied()
+    resolved_addresses().map(|addresses| addresses.raydium_standard_amm_program)
 }
 
 #[inline(always)]
 pub fn raydium_v4_program_pubkey() -> Option<Pubkey> {
-    RAYDIUM_V4_PROGRAM_PUBKEY.as_ref().copied()
+    resolved_addresses().map(|addresses| addresses.raydium_v4_program)
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        jito_tip_pubkey, raydium_standard_amm_program_pubkey, raydium_v4_program_pubkey,
-        token_program_pubkey, wsol_pubkey,
+        AddressField, AddressInitError, ResolvedAddresses, jito_tip_at, next_jito_tip_pubkey,
+        raydium_standard_amm_program_pubkey, raydium_v4_program_pubkey, token_program_pubkey,
+        wsol_pubkey,
     };
+    use crate::domain::settings::AddressOverrides;
 
     #[test]
     fn parses_all_cached_pubkeys() {
         assert!(wsol_pubkey().is_some());
         assert!(token_program_pubkey().is_some());
-        assert!(jito_tip_pubkey().is_some());
+        assert!(next_jito_tip_pubkey().is_some());
         assert!(raydium_standard_amm_program_pubkey().is_some());
         assert!(raydium_v4_program_pubkey().is_some());
     }
+
+    #[test]
+    fn applies_configured_overrides() {
+        let overrides = AddressOverrides {
+            jito_tip_account: Some("11111111111111111111111111111111".to_owned()),
+            ..AddressOverrides::default()
+        };
+        let resolved = ResolvedAddresses::resolve(&overrides);
+        let defaults = ResolvedAddresses::resolve(&AddressOverrides::default());
+        assert!(resolved.is_ok());
+        assert!(defaults.is_ok());
+
+        if let (Ok(resolved), Ok(defaults)) = (resolved, defaults) {
+            assert_eq!(resolved.jito_tips.len(), 1);
+            assert_eq!(
+                resolved.jito_tips.first().map(ToString::to_string),
+                Some("11111111111111111111111111111111".to_owned())
+            );
+            assert_eq!(resolved.wsol, defaults.wsol);
+            assert_ne!(resolved.jito_tips, defaults.jito_tips);
+        }
+    }
+
+    #[test]
+    fn replaces_raydium_program_ids() {
+        let overrides = AddressOverrides {
+            raydium_v4_program: Some("11111111111111111111111111111111".to_owned()),
+            ..AddressOverrides::default()
+        };
+        let resolved = ResolvedAddresses::resolve(&overrides);
+        let defaults = ResolvedAddresses::resolve(&AddressOverrides::default());
+        assert!(resolved.is_ok());
+        assert!(defaults.is_ok());
+
+        if let (Ok(resolved), Ok(defaults)) = (resolved, defaults) {
+            assert_eq!(
+                resolved.raydium_v4_program.to_string(),
+                "11111111111111111111111111111111"
+            );
+            assert_eq!(
+                resolved.raydium_standard_amm_program,
+                defaults.raydium_standard_amm_program
+            );
+        }
+
+        let invalid = AddressOverrides {
+            raydium_cpmm_program: Some("CPMM".to_owned()),
+            ..AddressOverrides::default()
+        };
+        assert!(matches!(
+            ResolvedAddresses::resolve(&invalid),
+            Err(AddressInitError::InvalidPubkey {
+                field: AddressField::RaydiumStandardAmmProgram,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_override() {
+        let overrides = AddressOverrides {
+            wsol_mint: Some("not-a-pubkey".to_owned()),
+            ..AddressOverrides::default()
+        };
+        assert!(matches!(
+            ResolvedAddresses::resolve(&overrides),
+            Err(AddressInitError::InvalidPubkey { .. })
+        ));
+    }
+
+    #[test]
+    fn rotates_only_through_configured_tip_accounts() {
+        let overrides = AddressOverrides {
+            jito_tip_accounts: vec![
+                "11111111111111111111111111111111".to_owned(),
+                "So11111111111111111111111111111111111111112".to_owned(),
+            ],
+            ..AddressOverrides::default()
+        };
+        let resolved = ResolvedAddresses::resolve(&overrides);
+        assert!(resolved.is_ok());
+
+        if let Ok(resolved) = resolved {
+            let selected = (0..6)
+                .filter_map(|cursor| jito_tip_at(&resolved.jito_tips, cursor))
+                .collect::<Vec<_>>();
+            assert_eq!(selected.len(), 6);
+            assert!(selected.iter().all(|tip| resolved.jito_tips.contains(tip)));
+            assert_ne!(selected.first(), selected.get(1));
+        }
+
+        assert_eq!(jito_tip_at(&[], 3), None);
+    }
 }
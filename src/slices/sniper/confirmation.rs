@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_commitment_config::CommitmentConfig;
+use solana_sdk::{signature::Signature, transaction::TransactionError};
+use solana_transaction_status::TransactionStatus;
+
+use crate::adapters::rpc_retry::{RetryDecision, with_rpc_timeout};
+
+const MAX_CONFIRMATION_POLLS: usize = 120;
+const MAX_POLL_DELAY: tokio::time::Duration = tokio::time::Duration::from_secs(2);
+
+#[derive(Debug, Eq, PartialEq)]
+enum ConfirmationPoll {
+    Pending,
+    Done(Result<(), TransactionError>),
+}
+
+pub async fn await_confirmation(
+    rpc: &RpcClient,
+    call_timeout: Option<Duration>,
+    signature: &Signature,
+    commitment: CommitmentConfig,
+    token_address: &str,
+    label: &str,
+) -> Option<Result<(), TransactionError>> {
+    let mut delay = tokio::time::Duration::from_millis(250);
+
+    for _ in 0..MAX_CONFIRMATION_POLLS {
+        let response =
+            with_rpc_timeout(call_timeout, rpc.get_signature_statuses(&[*signature])).await;
+        let statuses = match response {
+            Ok(response) => response.value,
+            Err(error) => {
+                match error.retry_decision() {
+                    RetryDecision::Abort => {
+                        log::error!(
+                            "{} > {} > Signature status failed: {}",
+                            label,
+                            token_address,
+                            error
+                        );
+                        return None;
+                    }
+                    RetryDecision::RetrySlow => {
+                        log::warn!(
+                            "{} > {} > Signature status rate limited, backing off: {}",
+                            label,
+                            token_address,
+                            error
+                        );
+                        delay = MAX_POLL_DELAY;
+                    }
+                    RetryDecision::RetryFast => log::debug!(
+                        "{} > {} > Signature status failed, retrying: {}",
+                        label,
+                        token_address,
+                        error
+                    ),
+                }
+                Vec::new()
+            }
+        };
+
+        let status = statuses.into_iter().next().flatten();
+        if let ConfirmationPoll::Done(result) = classify_status(status.as_ref(), commitment) {
+            return Some(result);
+        }
+
+        tokio::time::sleep(delay).await;
+        if delay < MAX_POLL_DELAY {
+            delay = delay.saturating_mul(2).min(MAX_POLL_DELAY);
+        }
+    }
+
+    log::error!(
+        "{} > {} > No {:?} signature status returned before timeout",
+        label,
+        token_address,
+        commitment.commitment
+    );
+    None
+}
+
+fn classify_status(
+    status: Option<&TransactionStatus>,
+    commitment: CommitmentConfig,
+) -> ConfirmationPoll {
+    status.map_or(ConfirmationPoll::Pending, |status| {
+        poll_result(status.err.clone(), status.satisfies_commitment(commitment))
+    })
+}
+
+const fn poll_result(
+    error: Option<TransactionError>,
+    reached_commitment: bool,
+) -> ConfirmationPoll {
+    match error {
+        Some(error) => ConfirmationPoll::Done(Err(error)),
+        None if reached_commitment => ConfirmationPoll::Done(Ok(())),
+        None => ConfirmationPoll::Pending,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{ConfirmationPoll, await_confirmation, classify_status, poll_result};
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_commitment_config::CommitmentConfig;
+    use solana_sdk::{signature::Signature, transaction::TransactionError};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    #[test]
+    fn waits_until_requested_commitment_is_reached() {
+        assert_eq!(poll_result(None, true), ConfirmationPoll::Done(Ok(())));
+        assert_eq!(poll_result(None, false), ConfirmationPoll::Pending);
+        assert_eq!(
+            classify_status(None, CommitmentConfig::processed()),
+            ConfirmationPoll::Pending
+        );
+    }
+
+    #[test]
+    fn reports_failed_transactions_before_target_commitment() {
+        assert_eq!(
+            poll_result(Some(TransactionError::AccountNotFound), false),
+            ConfirmationPoll::Done(Err(TransactionError::AccountNotFound))
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_a_stalled_status_poll_after_the_call_timeout() {
+        let server = spawn_stalling_status_rpc().await;
+        assert!(server.is_ok());
+        let Ok((rpc_url, server)) = server else {
+            return;
+        };
+        let rpc = RpcClient::new(rpc_url);
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(10),
+            await_confirmation(
+                &rpc,
+                Some(Duration::from_millis(100)),
+                &Signature::new_unique(),
+                CommitmentConfig::confirmed(),
+                "mint",
+                "TEST",
+            ),
+        )
+        .await;
+
+        assert_eq!(result.ok().flatten(), Some(Ok(())));
+        assert!(server.await.is_ok());
+    }
+
+    /// Leaves the first request unanswered, then reports the signature as finalized.
+    async fn spawn_stalling_status_rpc() -> Result<(String, tokio::task::JoinHandle<()>), String> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .map_err(|error| format!("failed to bind test rpc listener: {error}"))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|error| format!("failed to read test rpc listener addr: {error}"))?;
+
+        let server = tokio::spawn(async move {
+            let mut buffer = [0_u8; 4_096];
+            let stalled = listener.accept().await;
+            assert!(stalled.is_ok());
+            let Ok((mut stalled_stream, _)) = stalled else {
+                return;
+            };
+            assert!(stalled_stream.read(&mut buffer).await.is_ok());
+
+            let accept_result = listener.accept().await;
+            assert!(accept_result.is_ok());
+            let Ok((mut stream, _)) = accept_result else {
+                return;
+            };
+            assert!(stream.read(&mut buffer).await.is_ok());
+
+            let body = serde_json::json!({
+                "jsonrpc": "2.0",
+                "result": {
+                    "context": { "slot": 7_u64 },
+                    "value": [{
+                        "slot": 7_u64,
+                        "confirmations": null,
+                        "err": null,
+                        "status": { "Ok": null },
+                        "confirmationStatus": "finalized"
+                    }]
+                },
+                "id": 1_u64
+            })
+            .to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            assert!(stream.write_all(response.as_bytes()).await.is_ok());
+        });
+
+        Ok((format!("http://{local_addr}"), server))
+    }
+}
@@ -1,22 +1,15 @@
 use std::sync::Arc;
 
-use chrono::{Local, TimeZone};
-use sof_solana_compat::TxBuilder;
-use sof_tx::SignedTx;
-use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
-    signature::Signature,
+    pubkey::Pubkey,
     signer::Signer,
-    transaction::VersionedTransaction,
 };
 use solana_system_interface::instruction::transfer;
-use solana_transaction_status::UiTransactionEncoding;
 use spl_associated_token_account::{
-    get_associated_token_address, get_associated_token_address_with_program_id,
+    get_associated_token_address_with_program_id,
     instruction::create_associated_token_account_idempotent,
 };
-use spl_token::instruction::{close_account, sync_native};
 
 use crate::{
     adapters::raydium::{
@@ -25,11 +18,23 @@ use crate::{
     app::context::ExecutionContext,
     domain::{
         aggregates::RuleBook,
-        events::{IngressMetadata, unix_timestamp_now_ns},
+        events::{IngressMetadata, ingress_latency_ns},
         services::RuleMatcher,
-        value_objects::{TxSubmissionMode, sol_amount::Lamports},
+        value_objects::sol_amount::Lamports,
+    },
+    slices::sniper::{
+        cache,
+        error::{SnipeError, SnipeErrorKind},
+        outcome::{OutcomeIngress, SnipeOutcome, SnipeStatus, record_outcome},
+        paper::PaperBuy,
+        pool_open::wait_for_pool_open,
+        send::{SwapSend, send_swap},
+        slippage::{CPMM_EXCEEDED_SLIPPAGE, SlippageEscalation},
+        tx::{
+            WsolAccount, ensure_min_amount_out, swap_input_lamports, wrapped_lamports,
+            wsol_close_instruction, wsol_top_up_instructions,
+        },
     },
-    slices::sniper::cache,
 };
 
 pub async fn handle_cpmm_candidate_structured(
@@ -37,23 +42,18 @@ pub async fn handle_cpmm_candidate_structured(
     rulebook: Arc<RuleBook>,
     transaction: Arc<solana_sdk::transaction::VersionedTransaction>,
     ingress_metadata: IngressMetadata,
-) {
-    let program_id = match cache::raydium_standard_amm_program_pubkey() {
-        Some(value) => value,
-        None => return,
-    };
-    let creation = match parse_cpmm_creation_transaction(
-        context.rpc.as_ref(),
+) -> Result<(), SnipeError> {
+    let program_id = cpmm_program_id()?;
+    let creation = parse_cpmm_creation_transaction(
+        &context.read_rpc,
+        context.rpc_call_timeout,
         transaction.as_ref(),
         program_id,
     )
     .await
-    {
-        Some(value) => value,
-        None => return,
-    };
+    .ok_or(SnipeErrorKind::CreationParse)?;
 
-    handle_cpmm_transaction(context, rulebook, ingress_metadata, creation).await;
+    handle_cpmm_transaction(context, rulebook, ingress_metadata, creation).await
 }
 
 async fn handle_cpmm_transaction(
@@ -61,32 +61,53 @@ async fn handle_cpmm_transaction(
     rulebook: Arc<RuleBook>,
     ingress_metadata: IngressMetadata,
     creation: ParsedCpmmCreation,
-) {
+) -> Result<(), SnipeError> {
     log::debug!(
         "CPMM > vault_0_amount: {}, vault_1_amount: {}",
         creation.init_amount_0,
         creation.init_amount_1
     );
 
-    let ingress_latency_ns =
-        unix_timestamp_now_ns().saturating_sub(ingress_metadata.normalized_timestamp_ns);
+    let token_address = creation
+        .token_mint()
+        .ok_or(SnipeErrorKind::MissingCreationField {
+            field: "token mint",
+        })?;
+    snipe_cpmm_token(context, rulebook, ingress_metadata, creation, token_address)
+        .await
+        .map_err(|kind| SnipeError::for_token(token_address, kind))
+}
+
+async fn snipe_cpmm_token(
+    context: Arc<ExecutionContext>,
+    rulebook: Arc<RuleBook>,
+    ingress_metadata: IngressMetadata,
+    creation: ParsedCpmmCreation,
+    token_address: Pubkey,
+) -> Result<(), SnipeErrorKind> {
+    let ingress_latency_ns = ingress_latency_ns(&ingress_metadata);
     log::debug!(
-        "CPMM > ingress source={}, normalized_ts={}ns, hw_ts={:?}, latency={}ns",
+        "CPMM > {} > ingress event_id={}, source={}, normalized_ts={}ns, hw_ts={:?}, latency={}ns",
+        token_address,
+        ingress_metadata.event_id,
         ingress_metadata.source.as_str(),
         ingress_metadata.normalized_timestamp_ns,
         ingress_metadata.hardware_timestamp_ns,
         ingress_latency_ns
     );
 
-    let token_address = match creation.token_mint() {
-        Some(value) => value,
-        None => return,
-    };
-    let token_program = match creation.token_program() {
-        Some(value) => value,
-        None => return,
-    };
+    let token_program = creation
+        .token_program()
+        .ok_or(SnipeErrorKind::MissingCreationField {
+            field: "token program",
+        })?;
     let deployer_address = creation.deployer_address;
+    log::debug!(
+        "CPMM > {} > Deployer {} read from the {}",
+        token_address,
+        deployer_address,
+        creation.deployer_source.as_str()
+    );
     let token_address_text = token_address.to_string();
     let deployer_address_text = deployer_address.to_string();
 
@@ -98,7 +119,7 @@ async fn handle_cpmm_transaction(
         Some(value) => value,
         None => {
             log::debug!("CPMM > {} > Ignoring token", token_address);
-            return;
+            return Ok(());
         }
     };
 
@@ -108,6 +129,19 @@ async fn handle_cpmm_transaction(
         matched_rule.source,
         matched_rule.cold.address
     );
+    if let Some(rule_stats) = &context.rule_stats {
+        rule_stats.record_match(&matched_rule.cold.address);
+    }
+
+    let init_liquidity = Lamports::new(creation.init_sol_amount());
+    if !context.init_liquidity_band.contains(init_liquidity) {
+        log::info!(
+            "CPMM > {} > Skipping pool with {} SOL initial liquidity outside the configured band",
+            token_address,
+            init_liquidity.as_sol_string()
+        );
+        return Ok(());
+    }
 
     log::debug!(
         "CPMM > {} > Snipe height: {} SOL, Jito tip: {} SOL, Slippage: {} %",
@@ -117,12 +151,13 @@ async fn handle_cpmm_transaction(
         matched_rule.hot.slippage().as_pct_string(),
     );
 
-    log::info!("CPMM > Found token: {}", token_address);
+    log::info!(
+        "CPMM > Found token: {} (event_id={})",
+        token_address,
+        ingress_metadata.event_id
+    );
     log::info!("CPMM > {} > Creating transaction", token_address);
-    let program_id = match cache::raydium_standard_amm_program_pubkey() {
-        Some(value) => value,
-        None => return,
-    };
+    let program_id = cpmm_program_id()?;
 
     log::debug!(
         "CPMM > {} > Authority: {}, AMM config: {}, Pool state: {}, Input vault: {}, Output vault: {}, Observation state: {}",
@@ -135,48 +170,56 @@ async fn handle_cpmm_transaction(
         creation.observation_state,
     );
 
-    let lamports = matched_rule.hot.snipe_height().as_lamports().as_u64();
-    let wsol_pubkey = match cache::wsol_pubkey() {
-        Some(value) => value,
-        None => return,
+    let (sol_reserve, token_reserve) = if creation.token_is_vault_zero() {
+        (creation.init_amount_1, creation.init_amount_0)
+    } else {
+        (creation.init_amount_0, creation.init_amount_1)
     };
-
-    let user_in_token_account =
-        get_associated_token_address(&context.keypair.pubkey(), &wsol_pubkey);
+    let lamports = swap_input_lamports(matched_rule.hot, sol_reserve, token_reserve)?;
+    if let Some(tokens) = matched_rule.hot.target_token_amount() {
+        log::info!(
+            "CPMM > {} > Buying {} tokens for {} SOL at the pool price",
+            token_address,
+            tokens,
+            Lamports::new(lamports).as_sol_string()
+        );
+    }
+    let wsol_pubkey = cache::wsol_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+        address: "WSOL mint",
+    })?;
+
+    let token_program_id =
+        cache::token_program_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+            address: "token program",
+        })?;
+
+    let wsol_account =
+        WsolAccount::associated(context.keypair.pubkey(), wsol_pubkey, token_program_id);
+    let user_in_token_account = wsol_account.address;
     let user_out_token_account = get_associated_token_address_with_program_id(
         &context.keypair.pubkey(),
         &token_address,
         &token_program,
     );
 
-    let token_program_id = match cache::token_program_pubkey() {
-        Some(value) => value,
-        None => return,
-    };
-
     let mut instructions = Vec::with_capacity(7);
 
-    instructions.push(create_associated_token_account_idempotent(
-        &context.keypair.pubkey(),
-        &context.keypair.pubkey(),
-        &wsol_pubkey,
-        &token_program_id,
-    ));
-
-    instructions.push(transfer(
-        &context.keypair.pubkey(),
-        &user_in_token_account,
-        lamports,
-    ));
-
-    let sync_instruction = match sync_native(&spl_token::ID, &user_in_token_account) {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!("CPMM > {} > sync_native failed: {}", token_address, error);
-            return;
-        }
+    let already_wrapped = if context.reuse_wrapped_sol {
+        wrapped_lamports(context.as_ref(), &wsol_account).await
+    } else {
+        None
     };
-    instructions.push(sync_instruction);
+    instructions.extend(
+        wsol_top_up_instructions(
+            &wsol_account,
+            context.wsol_wrap_lamports(lamports),
+            already_wrapped,
+        )
+        .map_err(|source| SnipeErrorKind::Instruction {
+            instruction: "WSOL wrap",
+            source,
+        })?,
+    );
 
     instructions.push(create_associated_token_account_idempotent(
         &context.keypair.pubkey(),
@@ -192,6 +235,13 @@ async fn handle_cpmm_transaction(
         creation.init_amount_1,
         creation.token_is_vault_zero(),
     );
+    let min_amount_out = ensure_min_amount_out(min_amount_out, context.allow_zero_min_amount_out)?;
+    if min_amount_out == 0 {
+        log::warn!(
+            "CPMM > {} > Sending with min_amount_out 0; the swap has no slippage protection",
+            token_address
+        );
+    }
 
     log::debug!(
         "CPMM > {} > Min amount out: {}",
@@ -199,14 +249,10 @@ async fn handle_cpmm_transaction(
         min_amount_out
     );
 
-    let mut swap_data = Vec::with_capacity(24);
-    swap_data.extend_from_slice(&STANDARD_AMM_SWAP_BASE_INPUT);
-    swap_data.extend_from_slice(&lamports.to_le_bytes());
-    swap_data.extend_from_slice(&min_amount_out.to_le_bytes());
-
+    let swap_instruction_index = instructions.len();
     instructions.push(Instruction::new_with_bytes(
         program_id,
-        &swap_data,
+        &swap_data(lamports, min_amount_out),
         vec![
             AccountMeta::new_readonly(context.keypair.pubkey(), true),
             AccountMeta::new_readonly(creation.authority, false),
@@ -224,27 +270,21 @@ async fn handle_cpmm_transaction(
         ],
     ));
 
-    let close_instruction = match close_account(
-        &token_program_id,
-        &user_in_token_account,
-        &context.keypair.pubkey(),
-        &context.keypair.pubkey(),
-        &[&context.keypair.pubkey()],
-    ) {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!("CPMM > {} > close_account failed: {}", token_address, error);
-            return;
-        }
-    };
-    instructions.push(close_instruction);
+    instructions.extend(
+        wsol_close_instruction(&wsol_account, context.wsol_close_timing).map_err(|source| {
+            SnipeErrorKind::Instruction {
+                instruction: "close_account",
+                source,
+            }
+        })?,
+    );
 
-    let jito_tip_lamports = matched_rule.hot.jito_tip().as_lamports().as_u64();
-    if context.sof_tx_uses_jito || context.tx_submission_mode == TxSubmissionMode::Jito {
-        let jito_tip_account = match cache::jito_tip_pubkey() {
-            Some(value) => value,
-            None => return,
-        };
+    let jito_tip_lamports = context.paid_jito_tip(matched_rule.hot.jito_tip_for(lamports).as_u64());
+    if context.pays_jito_tip() {
+        let jito_tip_account =
+            cache::next_jito_tip_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+                address: "Jito tip account",
+            })?;
 
         instructions.push(transfer(
             &context.keypair.pubkey(),
@@ -253,193 +293,96 @@ async fn handle_cpmm_transaction(
         ));
     }
 
-    maybe_wait_for_pool_open(creation.open_time, token_address_text.as_str(), "CPMM").await;
-
-    let blockhash = match context.latest_swap_blockhash().await {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!("CPMM > {} > {}", token_address, error);
-            return;
-        }
-    };
-
-    let swap_tx = match build_swap_transaction(context.as_ref(), instructions, blockhash) {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!("CPMM > {} > {}", token_address, error);
-            return;
-        }
-    };
-
-    let swap_signature = swap_tx.signatures.first().copied().unwrap_or_default();
-
-    if context.dry_run {
-        log::info!(
-            "CPMM > {} > Dry run built swap transaction: {} (submission skipped)",
-            token_address,
-            swap_signature
-        );
-        return;
-    }
-
-    log::info!("CPMM > {} > Starting swap", token_address);
-
-    let sent_signature = match submit_swap_transaction(context.as_ref(), &swap_tx).await {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!(
-                "CPMM > {} > Failed to send transaction: {}",
-                token_address,
-                error
-            );
-            return;
-        }
-    };
-
-    log::info!(
-        "CPMM > {} > Swap transaction signature: {}",
-        token_address,
-        sent_signature
-    );
+    let outcome = SnipeOutcome::new(
+        "cpmm",
+        token_address_text.clone(),
+        &matched_rule,
+        lamports,
+        min_amount_out,
+        OutcomeIngress {
+            metadata: ingress_metadata,
+            to_match_ns: ingress_latency_ns,
+        },
+    )
+    .with_jito_tip(jito_tip_lamports);
 
-    match wait_for_signature_status(
-        context.rpc.as_ref(),
-        &sent_signature,
+    if !wait_for_pool_open(
+        i64::try_from(creation.open_time).unwrap_or(i64::MAX),
+        context.max_pool_open_wait,
+        context.pre_open_offset,
         token_address_text.as_str(),
         "CPMM",
     )
     .await
     {
-        Some(Ok(())) => (),
-        Some(Err(error)) => {
-            log::error!(
-                "CPMM > {} > Swap transaction failed: {}",
-                token_address,
-                error
-            );
-            return;
-        }
-        None => return,
+        record_outcome(
+            &context,
+            outcome.finish(
+                SnipeStatus::Skipped,
+                Some("pool open time is beyond runtime.max_pool_open_wait_secs".to_owned()),
+            ),
+        )
+        .await;
+        return Ok(());
     }
 
-    let balance = match context.rpc.get_balance(&context.keypair.pubkey()).await {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!(
-                "CPMM > {} > Failed to fetch balance: {}",
-                token_address,
-                error
-            );
-            return;
-        }
-    };
-
-    log::info!(
-        "CPMM > {} > Successfully swapped {} SOL with {} SOL tip budget (mode={})",
-        token_address,
-        matched_rule.hot.snipe_height().as_sol_string(),
-        matched_rule.hot.jito_tip().as_sol_string(),
-        context.tx_submission_mode.as_str(),
-    );
-    log::info!(
-        "CPMM > {} > Balance: {} SOL",
-        token_address,
-        Lamports::new(balance).as_sol_string()
-    );
-}
-
-async fn wait_for_signature_status(
-    rpc: &RpcClient,
-    signature: &Signature,
-    token_address: &str,
-    label: &str,
-) -> Option<Result<(), String>> {
-    const MAX_CONFIRMATION_POLLS: usize = 120;
-    let mut delay = tokio::time::Duration::from_millis(250);
-
-    for _ in 0..MAX_CONFIRMATION_POLLS {
-        let status = match rpc.get_signature_status(signature).await {
-            Ok(value) => value,
-            Err(error) => {
-                log::error!(
-                    "{} > {} > Signature status failed: {}",
-                    label,
-                    token_address,
-                    error
-                );
-                return None;
-            }
-        };
-
-        if let Some(status) = status {
-            return Some(status.map_err(|error| error.to_string()));
-        }
-
-        tokio::time::sleep(delay).await;
-        if delay < tokio::time::Duration::from_secs(2) {
-            delay = delay
-                .saturating_mul(2)
-                .min(tokio::time::Duration::from_secs(2));
-        }
-    }
-
-    log::error!(
-        "{} > {} > No signature status returned before timeout",
-        label,
-        token_address
-    );
-    None
+    let allow_zero_min_amount_out = context.allow_zero_min_amount_out;
+    let (vault_0_amount, vault_1_amount) = (creation.init_amount_0, creation.init_amount_1);
+    let token_is_vault_zero = creation.token_is_vault_zero();
+    send_swap(
+        context.as_ref(),
+        SwapSend {
+            label: "CPMM",
+            token_address,
+            swap_program: program_id,
+            instructions,
+            swap_instruction_index,
+            exceeded_slippage_code: CPMM_EXCEEDED_SLIPPAGE,
+            priority_fees: context.rule_priority_fees(matched_rule.hot.fee_multiplier()),
+            escalation: SlippageEscalation::new(
+                context.slippage_retry_attempts,
+                matched_rule.hot.slippage(),
+                matched_rule.hot.max_slippage(),
+            ),
+            wsol_account,
+            buy: PaperBuy {
+                lamports_in: lamports,
+                tip_lamports: jito_tip_lamports,
+                sol_reserve,
+                token_reserve,
+            },
+            widen_swap: move |queued_swap: &mut Instruction, slippage_bps| {
+                let widened_min_amount_out = ensure_min_amount_out(
+                    calculate_min_amount_out(
+                        lamports,
+                        slippage_bps,
+                        vault_0_amount,
+                        vault_1_amount,
+                        token_is_vault_zero,
+                    ),
+                    allow_zero_min_amount_out,
+                )?;
+                queued_swap.data = swap_data(lamports, widened_min_amount_out);
+                Ok(widened_min_amount_out)
+            },
+        },
+        outcome,
+    )
+    .await
 }
 
-async fn submit_swap_transaction(
-    context: &ExecutionContext,
-    swap_tx: &VersionedTransaction,
-) -> Result<Signature, String> {
-    if let (Some(client), Some(plan)) = (&context.sof_tx_client, &context.sof_tx_plan) {
-        let tx_bytes = bincode::serialize(swap_tx)
-            .map_err(|error| format!("failed to serialize transaction for SOF-TX: {error}"))?;
-        let mut client = client.lock().await;
-        client
-            .submit_signed_via(SignedTx::VersionedTransactionBytes(tx_bytes), plan.clone())
-            .await
-            .map_err(|error| format!("SOF-TX submit failed: {error}"))?;
-        return Ok(swap_tx.signatures.first().copied().unwrap_or_default());
-    }
-
-    let send_config = RpcSendTransactionConfig {
-        skip_preflight: true,
-        encoding: Some(UiTransactionEncoding::Base58),
-        max_retries: Some(0),
-        ..RpcSendTransactionConfig::default()
-    };
-
-    if context.tx_submission_mode == TxSubmissionMode::Direct {
-        return context
-            .rpc
-            .send_transaction_with_config(swap_tx, send_config)
-            .await
-            .map_err(|error| error.to_string());
-    }
-
-    let jito_rpc = RpcClient::new(context.jito_url.as_ref().clone());
-    jito_rpc
-        .send_transaction_with_config(swap_tx, send_config)
-        .await
-        .map_err(|error| error.to_string())
+fn cpmm_program_id() -> Result<Pubkey, SnipeErrorKind> {
+    cache::raydium_standard_amm_program_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+        address: "Raydium CPMM program",
+    })
 }
 
-fn build_swap_transaction(
-    context: &ExecutionContext,
-    instructions: Vec<Instruction>,
-    blockhash: solana_sdk::hash::Hash,
-) -> Result<VersionedTransaction, String> {
-    let signer_refs: [&dyn Signer; 1] = [context.keypair.as_ref()];
-    TxBuilder::new(context.keypair.pubkey())
-        .with_compute_unit_limit(120_000)
-        .with_priority_fee_micro_lamports(context.priority_fees)
-        .add_instructions(instructions)
-        .build_and_sign(blockhash.to_bytes(), &signer_refs)
-        .map_err(|error| format!("failed to build/sign swap transaction: {error}"))
+fn swap_data(lamports: u64, min_amount_out: u64) -> Vec<u8> {
+    let mut swap_data = Vec::with_capacity(24);
+    swap_data.extend_from_slice(&STANDARD_AMM_SWAP_BASE_INPUT);
+    swap_data.extend_from_slice(&lamports.to_le_bytes());
+    swap_data.extend_from_slice(&min_amount_out.to_le_bytes());
+    swap_data
 }
 
 #[inline(always)]
@@ -479,37 +422,6 @@ fn calculate_min_amount_out(
         .unwrap_or(u64::MAX)
 }
 
-async fn maybe_wait_for_pool_open(pool_open_time: u64, token_address: &str, label: &str) {
-    let now = Local::now();
-
-    let Some(target_time) = Local.timestamp_opt(pool_open_time as i64, 0).single() else {
-        return;
-    };
-
-    if now >= target_time {
-        return;
-    }
-
-    let duration = target_time.signed_duration_since(now);
-    let remaining_minutes = duration.num_minutes();
-    let remaining_seconds = duration
-        .num_seconds()
-        .saturating_sub(remaining_minutes.saturating_mul(60));
-
-    log::info!(
-        "{} > {} > Pool closed. Proceeding with snipe in {}m {}s. UTC: {}",
-        label,
-        token_address,
-        remaining_minutes,
-        remaining_seconds,
-        target_time.to_rfc2822(),
-    );
-
-    if let Ok(duration) = duration.to_std() {
-        tokio::time::sleep(duration).await;
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::calculate_min_amount_out;
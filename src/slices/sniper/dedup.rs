@@ -0,0 +1,136 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    hash::Hash,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+/// Bounded set of recently seen keys; the oldest key is evicted once `capacity` is reached.
+#[derive(Debug)]
+pub struct RecentSet<T> {
+    capacity: usize,
+    inner: Mutex<RecentSetInner<T>>,
+}
+
+#[derive(Debug)]
+struct RecentSetInner<T> {
+    order: VecDeque<T>,
+    members: HashSet<T>,
+}
+
+impl<T> RecentSet<T>
+where
+    T: Clone + Eq + Hash,
+{
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            inner: Mutex::new(RecentSetInner {
+                order: VecDeque::with_capacity(capacity),
+                members: HashSet::with_capacity(capacity),
+            }),
+        }
+    }
+
+    /// Starts from previously seen `keys`, oldest first; only the newest `capacity` are kept.
+    pub fn with_keys<I>(capacity: usize, keys: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let recent = Self::new(capacity);
+        for key in keys {
+            let _inserted = recent.insert_if_absent(key);
+        }
+        recent
+    }
+
+    /// Returns `true` when `key` was not present and has been recorded.
+    pub fn insert_if_absent(&self, key: T) -> bool {
+        let mut inner = self.lock_inner();
+        if inner.members.contains(&key) {
+            return false;
+        }
+
+        while inner.order.len() >= self.capacity {
+            let Some(oldest) = inner.order.pop_front() else {
+                break;
+            };
+            inner.members.remove(&oldest);
+        }
+        inner.members.insert(key.clone());
+        inner.order.push_back(key);
+        true
+    }
+
+    pub fn contains(&self, key: &T) -> bool {
+        self.lock_inner().members.contains(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock_inner().order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock_inner().order.is_empty()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Current keys, oldest first.
+    pub fn snapshot(&self) -> Vec<T> {
+        self.lock_inner().order.iter().cloned().collect()
+    }
+
+    fn lock_inner(&self) -> MutexGuard<'_, RecentSetInner<T>> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecentSet;
+
+    #[test]
+    fn reports_absent_and_present_keys() {
+        let recent = RecentSet::new(4);
+        assert!(recent.is_empty());
+
+        assert!(recent.insert_if_absent(1_u64));
+        assert!(!recent.insert_if_absent(1_u64));
+        assert!(recent.insert_if_absent(2_u64));
+
+        assert_eq!(recent.len(), 2);
+        assert!(recent.contains(&1));
+    }
+
+    #[test]
+    fn evicts_oldest_key_on_overflow() {
+        let recent = RecentSet::new(2);
+        assert!(recent.insert_if_absent(1_u64));
+        assert!(recent.insert_if_absent(2_u64));
+        assert!(recent.insert_if_absent(3_u64));
+
+        assert_eq!(recent.len(), 2);
+        assert!(!recent.contains(&1));
+        assert!(recent.contains(&2));
+        assert!(recent.contains(&3));
+        assert!(recent.insert_if_absent(1_u64));
+        assert!(!recent.contains(&2));
+    }
+
+    #[test]
+    fn seeds_from_keys_keeping_the_newest() {
+        let recent = RecentSet::with_keys(2, [1_u64, 2, 2, 3]);
+
+        assert_eq!(recent.snapshot(), vec![2, 3]);
+        assert!(!recent.insert_if_absent(3));
+    }
+
+    #[test]
+    fn is_shareable_across_threads() {
+        const fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<RecentSet<solana_sdk::signature::Signature>>();
+    }
+}
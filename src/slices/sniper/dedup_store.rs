@@ -0,0 +1,157 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+
+use solana_sdk::signature::Signature;
+use tokio::{
+    fs,
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
+};
+
+use super::dedup::RecentSet;
+
+/// On-disk copy of the engine's recent-signature set, one base58 signature per line, so a
+/// restart does not forget candidates it already acted on.
+#[derive(Debug)]
+pub struct SignatureStore {
+    path: PathBuf,
+}
+
+impl SignatureStore {
+    pub const fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the newest `capacity` signatures. A missing file is an empty set; unreadable
+    /// lines are skipped.
+    pub async fn load(&self, capacity: usize) -> std::io::Result<Vec<Signature>> {
+        let contents = match fs::read_to_string(&self.path).await {
+            Ok(value) => value,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(error),
+        };
+
+        let signatures = contents
+            .lines()
+            .filter_map(|line| Signature::from_str(line.trim()).ok())
+            .collect::<Vec<_>>();
+        let skip = signatures.len().saturating_sub(capacity);
+        Ok(signatures.into_iter().skip(skip).collect())
+    }
+
+    /// Replaces the file with `signatures`. Writes a sibling temporary file first so a crash
+    /// mid-write leaves the previous copy intact.
+    pub async fn save(&self, signatures: &[Signature]) -> std::io::Result<()> {
+        let mut contents = String::with_capacity(signatures.len().saturating_mul(89));
+        for signature in signatures {
+            contents.push_str(&signature.to_string());
+            contents.push('\n');
+        }
+
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        fs::write(&temporary, contents).await?;
+        fs::rename(&temporary, &self.path).await
+    }
+
+    /// Saves `recent` every `period` while it has changed since the last save.
+    #[must_use]
+    pub fn spawn_flusher(
+        self: Arc<Self>,
+        recent: Arc<RecentSet<Signature>>,
+        period: Duration,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            let mut saved = recent.snapshot();
+            loop {
+                ticker.tick().await;
+                let current = recent.snapshot();
+                if current == saved {
+                    continue;
+                }
+                if let Err(error) = self.save(&current).await {
+                    log::warn!(
+                        "Signature dedup > failed to save {}: {}",
+                        self.path.display(),
+                        error
+                    );
+                    continue;
+                }
+                saved = current;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::signature::Signature;
+    use tokio::fs;
+
+    use super::SignatureStore;
+    use crate::{slices::sniper::dedup::RecentSet, test_support::temp_path};
+
+    #[tokio::test]
+    async fn round_trips_recent_signatures() {
+        let store = SignatureStore::new(temp_path("dedup_store_round_trip", "txt"));
+        let recent = RecentSet::new(8);
+        let signatures = [Signature::new_unique(), Signature::new_unique()];
+        for signature in signatures {
+            assert!(recent.insert_if_absent(signature));
+        }
+
+        assert!(store.save(&recent.snapshot()).await.is_ok());
+        let loaded = store.load(8).await;
+        assert!(loaded.is_ok());
+        if let Ok(loaded) = loaded {
+            let reloaded = RecentSet::with_keys(8, loaded);
+            assert!(
+                signatures
+                    .iter()
+                    .all(|signature| reloaded.contains(signature))
+            );
+            assert_eq!(reloaded.len(), 2);
+        }
+
+        assert!(fs::remove_file(store.path()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn prunes_to_capacity_and_skips_garbage_on_load() {
+        let store = SignatureStore::new(temp_path("dedup_store_prune", "txt"));
+        let [oldest, middle, newest] = [
+            Signature::new_unique(),
+            Signature::new_unique(),
+            Signature::new_unique(),
+        ];
+        let contents = format!("{oldest}\nnot-a-signature\n{middle}\n{newest}\n");
+        assert!(fs::write(store.path(), contents).await.is_ok());
+
+        let loaded = store.load(2).await;
+        assert!(loaded.is_ok());
+        if let Ok(loaded) = loaded {
+            assert_eq!(loaded, vec![middle, newest]);
+        }
+
+        assert!(fs::remove_file(store.path()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn treats_a_missing_file_as_empty() {
+        let store = SignatureStore::new(temp_path("dedup_store_missing", "txt"));
+        let loaded = store.load(8).await;
+        assert!(loaded.is_ok());
+        assert_eq!(loaded.ok().map(|signatures| signatures.len()), Some(0));
+    }
+}
@@ -1,70 +1,446 @@
-use std::{sync::Arc, time::Instant};
-use tokio::{
-    sync::{mpsc, watch},
-    task::JoinSet,
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use tokio::{runtime::Handle, sync::watch, task::JoinSet};
+
 use crate::{
     app::context::ExecutionContext,
     domain::{
         aggregates::RuleBook,
-        events::{RaydiumCandidateKind, SniperInputEvent, unix_timestamp_now_ns},
+        events::{RaydiumCandidateKind, SniperInputEvent, ingress_latency_ns},
     },
 };
 
-use super::{cpmm, openbook, telemetry::LatencyTelemetry};
+use super::{
+    cpmm,
+    dedup::RecentSet,
+    openbook,
+    priority::DispatchQueue,
+    queue::{QueueCloseReason, SniperEventQueue},
+    telemetry::LatencyTelemetry,
+    workers::WorkerPool,
+};
+
+/// What the engine did with a single candidate event.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProcessOutcome {
+    DroppedInvalidSignature {
+        strategy: RaydiumCandidateKind,
+    },
+    DroppedDuplicate {
+        strategy: RaydiumCandidateKind,
+        signature: Signature,
+    },
+    DroppedOversized {
+        strategy: RaydiumCandidateKind,
+        signature: Signature,
+        instructions: usize,
+    },
+    Dispatched {
+        strategy: RaydiumCandidateKind,
+        signature: Signature,
+    },
+    DeadlineExceeded {
+        strategy: RaydiumCandidateKind,
+        signature: Signature,
+    },
+    SkippedWarmup {
+        strategy: RaydiumCandidateKind,
+        signature: Signature,
+    },
+}
+
+/// Why `SniperEngine::run` returned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EngineStopReason {
+    /// The event queue was closed for a deliberate shutdown.
+    Shutdown,
+    /// Ingress stopped with an error and closed the event queue.
+    IngressFailed,
+    /// The event queue was closed without a reason.
+    IngressClosed,
+    /// Every strategy worker task exited, so no further events can be handled.
+    WorkersExited,
+}
+
+impl EngineStopReason {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Shutdown => "shutdown",
+            Self::IngressFailed => "ingress_failed",
+            Self::IngressClosed => "ingress_closed",
+            Self::WorkersExited => "workers_exited",
+        }
+    }
+
+    pub const fn is_clean(self) -> bool {
+        matches!(self, Self::Shutdown)
+    }
+}
+
+impl std::fmt::Display for EngineStopReason {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+impl From<Option<QueueCloseReason>> for EngineStopReason {
+    fn from(reason: Option<QueueCloseReason>) -> Self {
+        match reason {
+            Some(QueueCloseReason::Shutdown) => Self::Shutdown,
+            Some(QueueCloseReason::IngressFailed) => Self::IngressFailed,
+            None => Self::IngressClosed,
+        }
+    }
+}
+
+/// An admitted candidate, ready to run its strategy handler.
+struct PreparedDispatch {
+    context: Arc<ExecutionContext>,
+    rulebook: Arc<RuleBook>,
+    event: SniperInputEvent,
+    telemetry: Arc<LatencyTelemetry>,
+    strategy: RaydiumCandidateKind,
+    signature: Signature,
+    deadline: Option<Duration>,
+    priority: u8,
+    ingress_timestamp_ns: u64,
+    event_id: u64,
+}
+
+impl PreparedDispatch {
+    async fn dispatch(self) -> ProcessOutcome {
+        let Self {
+            context,
+            rulebook,
+            event,
+            telemetry,
+            strategy,
+            signature,
+            deadline,
+            event_id,
+            ..
+        } = self;
+        let handled = within_deadline(
+            deadline,
+            handle_event(context, rulebook, event, Arc::clone(&telemetry)),
+        )
+        .await;
+        if handled.is_some() {
+            return ProcessOutcome::Dispatched {
+                strategy,
+                signature,
+            };
+        }
+
+        telemetry.record_deadline_exceeded();
+        log::warn!(
+            "{} > {} > Abandoned snipe after runtime.snipe_deadline_secs ({:?}, event_id={})",
+            strategy.label(),
+            signature,
+            deadline.unwrap_or_default(),
+            event_id
+        );
+        ProcessOutcome::DeadlineExceeded {
+            strategy,
+            signature,
+        }
+    }
+}
+
+/// Runs `future` to completion, or drops it (cancelling the handler) once `deadline` elapses.
+async fn within_deadline<F>(deadline: Option<Duration>, future: F) -> Option<F::Output>
+where
+    F: Future,
+{
+    match deadline {
+        Some(deadline) => tokio::time::timeout(deadline, future).await.ok(),
+        None => Some(future.await),
+    }
+}
 
 pub struct SniperEngine {
     context: Arc<ExecutionContext>,
-    events_rx: mpsc::Receiver<SniperInputEvent>,
+    events: Arc<SniperEventQueue>,
     rulebook_rx: watch::Receiver<Arc<RuleBook>>,
     telemetry: Arc<LatencyTelemetry>,
+    strategy_runtime: Option<Handle>,
+    recent_signatures: Option<Arc<RecentSet<Signature>>>,
+    worker_tasks: usize,
+    snipe_deadline: Option<Duration>,
+    max_candidate_instructions: usize,
+    started_at: Instant,
+    startup_warmup: Duration,
 }
 
 impl SniperEngine {
-    #[expect(
-        clippy::missing_const_for_fn,
-        reason = "runtime initialization with channels and Arcs"
-    )]
     pub fn new(
         context: Arc<ExecutionContext>,
-        events_rx: mpsc::Receiver<SniperInputEvent>,
+        events: Arc<SniperEventQueue>,
         rulebook_rx: watch::Receiver<Arc<RuleBook>>,
         telemetry: Arc<LatencyTelemetry>,
+        strategy_runtime: Option<Handle>,
+        signature_dedup_capacity: usize,
     ) -> Self {
         Self {
             context,
-            events_rx,
+            events,
             rulebook_rx,
             telemetry,
+            strategy_runtime,
+            recent_signatures: (signature_dedup_capacity > 0)
+                .then(|| Arc::new(RecentSet::new(signature_dedup_capacity))),
+            worker_tasks: 0,
+            snipe_deadline: None,
+            max_candidate_instructions: usize::MAX,
+            started_at: Instant::now(),
+            startup_warmup: Duration::ZERO,
         }
     }
 
-    pub async fn run(mut self) {
+    /// Runs strategies on a fixed pool of `worker_tasks` tasks instead of one task per event.
+    ///
+    /// `0` keeps the per-event spawn capped at the adaptive in-flight limit.
+    #[must_use]
+    pub const fn with_worker_tasks(mut self, worker_tasks: usize) -> Self {
+        self.worker_tasks = worker_tasks;
+        self
+    }
+
+    /// Cancels any strategy handler still running after `deadline`; `None` lets handlers run to completion.
+    #[must_use]
+    pub const fn with_snipe_deadline(mut self, deadline: Option<Duration>) -> Self {
+        self.snipe_deadline = deadline;
+        self
+    }
+
+    /// Replaces the recent-signature set, e.g. with one reloaded from disk. Ignored when
+    /// deduplication is disabled.
+    #[must_use]
+    pub fn with_recent_signatures(mut self, recent: Arc<RecentSet<Signature>>) -> Self {
+        if self.recent_signatures.is_some() {
+            self.recent_signatures = Some(recent);
+        }
+        self
+    }
+
+    /// Drops candidates carrying more than `max_instructions` instructions before any strategy
+    /// parses them.
+    #[must_use]
+    pub const fn with_max_candidate_instructions(mut self, max_instructions: usize) -> Self {
+        self.max_candidate_instructions = max_instructions;
+        self
+    }
+
+    /// Classifies candidates for the first `warmup` after the engine is built without
+    /// dispatching them, while ingress and caches settle.
+    #[must_use]
+    pub const fn with_startup_warmup(mut self, warmup: Duration) -> Self {
+        self.startup_warmup = warmup;
+        self
+    }
+
+    /// Drains the event queue until it closes, then waits for in-flight strategies.
+    pub async fn run(self) -> EngineStopReason {
+        match self.worker_tasks {
+            0 => self.run_per_event_tasks().await,
+            worker_tasks => self.run_worker_pool(worker_tasks).await,
+        }
+    }
+
+    async fn run_worker_pool(self, worker_tasks: usize) -> EngineStopReason {
+        let pool = WorkerPool::spawn(
+            worker_tasks,
+            worker_tasks,
+            self.strategy_runtime.as_ref(),
+            |prepared: PreparedDispatch| async move {
+                let _outcome = prepared.dispatch().await;
+            },
+        );
+
+        let mut staged = DispatchQueue::new();
+        while let Some(prepared) = self.next_dispatch(&mut staged).await {
+            if !pool.submit(prepared).await {
+                pool.shutdown().await;
+                return EngineStopReason::WorkersExited;
+            }
+        }
+
+        pool.shutdown().await;
+        self.events.close_reason().into()
+    }
+
+    async fn run_per_event_tasks(self) -> EngineStopReason {
         let mut in_flight = JoinSet::new();
         let worker_limit = event_worker_limit();
+        let mut staged = DispatchQueue::new();
 
-        while let Some(event) = self.events_rx.recv().await {
+        loop {
             while in_flight.len() >= worker_limit {
                 let _ = in_flight.join_next().await;
             }
 
-            let ingress_to_engine_ns =
-                unix_timestamp_now_ns().saturating_sub(event.ingress().normalized_timestamp_ns);
-            let context = Arc::clone(&self.context);
-            let rulebook = self.rulebook_rx.borrow().clone();
-            let telemetry = Arc::clone(&self.telemetry);
+            let Some(prepared) = self.next_dispatch(&mut staged).await else {
+                break;
+            };
+
+            let task = async move {
+                let _outcome = prepared.dispatch().await;
+            };
+            match &self.strategy_runtime {
+                Some(handle) => {
+                    in_flight.spawn_on(task, handle);
+                }
+                None => {
+                    in_flight.spawn(task);
+                }
+            }
+        }
+
+        while in_flight.join_next().await.is_some() {}
+        self.events.close_reason().into()
+    }
+
+    /// Stages every queued event, then releases the highest-priority one; `None` once the
+    /// queue has closed and nothing is staged.
+    ///
+    /// Staged events count toward the queue capacity: past it, the lowest-priority staged
+    /// events are shed, with the queue's drop policy breaking ties, so staging never holds
+    /// more backlog than the queue alone would.
+    async fn next_dispatch(
+        &self,
+        staged: &mut DispatchQueue<PreparedDispatch>,
+    ) -> Option<PreparedDispatch> {
+        loop {
+            while let Some(event) = self.events.try_recv() {
+                self.stage(event, staged);
+            }
+            let shed = staged.shed_to(self.events.capacity(), self.events.drop_policy());
+            self.events.record_dropped(shed);
+            if let Some(prepared) = staged.pop() {
+                return Some(prepared);
+            }
+
+            let event = self.events.recv().await?;
+            self.stage(event, staged);
+        }
+    }
+
+    fn stage(&self, event: SniperInputEvent, staged: &mut DispatchQueue<PreparedDispatch>) {
+        if let Ok(prepared) = self.prepare(event) {
+            staged.push(prepared.priority, prepared.ingress_timestamp_ns, prepared);
+        }
+    }
+
+    /// Classifies and dispatches one event, awaiting its strategy handler.
+    ///
+    /// Applies the same signature and dedup checks as `run`, without the queue.
+    pub async fn process_one(&self, event: SniperInputEvent) -> ProcessOutcome {
+        match self.prepare(event) {
+            Ok(prepared) => prepared.dispatch().await,
+            Err(outcome) => outcome,
+        }
+    }
+
+    fn prepare(&self, event: SniperInputEvent) -> Result<PreparedDispatch, ProcessOutcome> {
+        let classify_started_at = Instant::now();
+        let strategy = event.kind();
+        let signature = event.valid_signature();
+        let ingress = event.ingress();
+        self.telemetry.record_for(
+            strategy,
+            "engine_classification_ns",
+            elapsed_ns_u64(classify_started_at.elapsed()),
+        );
+        let Some(signature) = signature else {
+            log::debug!(
+                "Sniper engine > dropping candidate without a valid signature (event_id={})",
+                ingress.event_id
+            );
+            return Err(ProcessOutcome::DroppedInvalidSignature { strategy });
+        };
+        if self
+            .recent_signatures
+            .as_ref()
+            .is_some_and(|recent| !recent.insert_if_absent(signature))
+        {
+            log::debug!(
+                "Sniper engine > {} > dropping duplicate candidate (event_id={})",
+                signature,
+                ingress.event_id
+            );
+            return Err(ProcessOutcome::DroppedDuplicate {
+                strategy,
+                signature,
+            });
+        }
+
+        let instructions = event.instruction_count();
+        if instructions > self.max_candidate_instructions {
+            log::warn!(
+                "Sniper engine > {} > dropping candidate with {} instructions (runtime.max_candidate_instructions={}, event_id={})",
+                signature,
+                instructions,
+                self.max_candidate_instructions,
+                ingress.event_id
+            );
+            return Err(ProcessOutcome::DroppedOversized {
+                strategy,
+                signature,
+                instructions,
+            });
+        }
+
+        self.telemetry.record_for(
+            strategy,
+            "ingress_to_engine_ns",
+            ingress_latency_ns(&ingress),
+        );
+        if let Some(skew_ns) = ingress.hardware_clock_skew_ns() {
             self.telemetry
-                .record("ingress_to_engine_ns", ingress_to_engine_ns);
+                .record_for(strategy, "hw_clock_skew_ns", skew_ns);
+            if ingress.hardware_clock_clamped() {
+                self.telemetry.record_hw_clock_clamp();
+            }
+        }
 
-            in_flight.spawn(async move {
-                handle_event(context, rulebook, event, telemetry).await;
+        if self.started_at.elapsed() < self.startup_warmup {
+            log::info!(
+                "{} > {} > Skipping candidate during runtime.startup_warmup_secs ({:?}, event_id={})",
+                strategy.label(),
+                signature,
+                self.startup_warmup,
+                ingress.event_id
+            );
+            return Err(ProcessOutcome::SkippedWarmup {
+                strategy,
+                signature,
             });
         }
 
-        while in_flight.join_next().await.is_some() {}
+        let rulebook = self.rulebook_rx.borrow().clone();
+        let priority = if rulebook.uses_priorities() {
+            rulebook.dispatch_priority(event.account_keys().iter().map(Pubkey::to_string))
+        } else {
+            0
+        };
 
-        log::warn!("Log event channel closed. Sniper engine stopped.");
+        Ok(PreparedDispatch {
+            context: Arc::clone(&self.context),
+            rulebook,
+            event,
+            telemetry: Arc::clone(&self.telemetry),
+            strategy,
+            signature,
+            deadline: self.snipe_deadline,
+            priority,
+            ingress_timestamp_ns: ingress.normalized_timestamp_ns,
+            event_id: ingress.event_id,
+        })
     }
 }
 
@@ -74,12 +450,10 @@ async fn handle_event(
     event: SniperInputEvent,
     telemetry: Arc<LatencyTelemetry>,
 ) {
-    let classify_started_at = Instant::now();
-
     match event {
         SniperInputEvent::RaydiumCandidate(event) => {
             let dispatch_started_at = Instant::now();
-            match event.kind {
+            let result = match event.kind {
                 RaydiumCandidateKind::Cpmm => {
                     cpmm::handle_cpmm_candidate_structured(
                         context,
@@ -87,7 +461,7 @@ async fn handle_event(
                         event.transaction,
                         event.ingress,
                     )
-                    .await;
+                    .await
                 }
                 RaydiumCandidateKind::OpenBook => {
                     openbook::handle_openbook_candidate_structured(
@@ -96,32 +470,69 @@ async fn handle_event(
                         event.transaction,
                         event.ingress,
                     )
-                    .await;
+                    .await
                 }
-            }
-            telemetry.record(
+            };
+            telemetry.record_for(
+                event.kind,
                 "strategy_dispatch_ns",
                 elapsed_ns_u64(dispatch_started_at.elapsed()),
             );
+            if let Err(error) = result {
+                log::error!(
+                    "{} > {} (event_id={})",
+                    event.kind.label(),
+                    error,
+                    event.ingress.event_id
+                );
+            }
         }
     }
-
-    telemetry.record(
-        "engine_classification_ns",
-        elapsed_ns_u64(classify_started_at.elapsed()),
-    );
 }
 
 trait SniperEventExt {
+    fn kind(&self) -> RaydiumCandidateKind;
     fn ingress(&self) -> crate::domain::events::IngressMetadata;
+    fn valid_signature(&self) -> Option<Signature>;
+    fn account_keys(&self) -> &[Pubkey];
+    fn instruction_count(&self) -> usize;
 }
 
 impl SniperEventExt for SniperInputEvent {
+    fn kind(&self) -> RaydiumCandidateKind {
+        match self {
+            Self::RaydiumCandidate(event) => event.kind,
+        }
+    }
+
     fn ingress(&self) -> crate::domain::events::IngressMetadata {
         match self {
             Self::RaydiumCandidate(event) => event.ingress,
         }
     }
+
+    fn valid_signature(&self) -> Option<Signature> {
+        match self {
+            Self::RaydiumCandidate(event) => event
+                .transaction
+                .signatures
+                .first()
+                .copied()
+                .filter(|signature| *signature != Signature::default()),
+        }
+    }
+
+    fn account_keys(&self) -> &[Pubkey] {
+        match self {
+            Self::RaydiumCandidate(event) => event.transaction.message.static_account_keys(),
+        }
+    }
+
+    fn instruction_count(&self) -> usize {
+        match self {
+            Self::RaydiumCandidate(event) => event.transaction.message.instructions().len(),
+        }
+    }
 }
 
 fn elapsed_ns_u64(duration: std::time::Duration) -> u64 {
@@ -138,3 +549,339 @@ fn event_worker_limit() -> usize {
         .unwrap_or(MIN_EVENT_WORKERS)
         .clamp(MIN_EVENT_WORKERS, MAX_EVENT_WORKERS)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_sdk::{
+        instruction::Instruction,
+        message::Message,
+        pubkey::Pubkey,
+        signature::{Keypair, Signature},
+        transaction::{Transaction, VersionedTransaction},
+    };
+    use tokio::sync::{oneshot, watch};
+
+    use super::{EngineStopReason, ProcessOutcome, SniperEngine, SniperEventExt, within_deadline};
+    use crate::{
+        adapters::tx_submitters::FakeSubmitter,
+        app::context::ExecutionContext,
+        domain::{
+            aggregates::RuleBook,
+            entities::SnipeRule,
+            events::{
+                IngressMetadata, IngressSource, RaydiumCandidateEvent, RaydiumCandidateKind,
+                SniperInputEvent,
+            },
+            value_objects::{
+                EventQueueDropPolicy, RuleAddress, RuleSlippageBps, RuleSolAmount,
+                sol_amount::Lamports,
+            },
+        },
+        slices::sniper::{
+            priority::DispatchQueue,
+            queue::{QueueCloseReason, SniperEventQueue},
+            telemetry::LatencyTelemetry,
+        },
+    };
+
+    #[test]
+    fn rejects_candidates_without_a_real_signature() {
+        let signature = Signature::from([7_u8; 64]);
+        assert_eq!(candidate_event(Vec::new()).valid_signature(), None);
+        assert_eq!(
+            candidate_event(vec![Signature::default()]).valid_signature(),
+            None
+        );
+        assert_eq!(
+            candidate_event(vec![signature]).valid_signature(),
+            Some(signature)
+        );
+    }
+
+    #[tokio::test]
+    async fn process_one_reports_dropped_candidates() {
+        let engine = engine(16);
+
+        assert_eq!(
+            engine.process_one(candidate_event(Vec::new())).await,
+            ProcessOutcome::DroppedInvalidSignature {
+                strategy: RaydiumCandidateKind::Cpmm,
+            }
+        );
+
+        let signature = Signature::from([9_u8; 64]);
+        assert!(
+            engine
+                .recent_signatures
+                .as_ref()
+                .is_some_and(|recent| recent.insert_if_absent(signature))
+        );
+        assert_eq!(
+            engine.process_one(candidate_event(vec![signature])).await,
+            ProcessOutcome::DroppedDuplicate {
+                strategy: RaydiumCandidateKind::Cpmm,
+                signature,
+            }
+        );
+    }
+
+    #[test]
+    fn drops_candidates_over_the_instruction_cap() {
+        let engine = engine(16).with_max_candidate_instructions(2);
+        let within_cap = candidate_event_with_instructions(Signature::from([4_u8; 64]), 2);
+        assert!(engine.prepare(within_cap).is_ok());
+
+        let signature = Signature::from([5_u8; 64]);
+        let oversized = candidate_event_with_instructions(signature, 3);
+        assert!(matches!(
+            engine.prepare(oversized),
+            Err(ProcessOutcome::DroppedOversized {
+                strategy: RaydiumCandidateKind::Cpmm,
+                signature: dropped,
+                instructions: 3,
+            }) if dropped == signature
+        ));
+    }
+
+    #[test]
+    fn skips_candidates_until_the_startup_warmup_ends() {
+        let warming = engine(16).with_startup_warmup(Duration::from_secs(60));
+        let signature = Signature::from([6_u8; 64]);
+        assert!(matches!(
+            warming.prepare(candidate_event_with_instructions(signature, 1)),
+            Err(ProcessOutcome::SkippedWarmup {
+                strategy: RaydiumCandidateKind::Cpmm,
+                signature: skipped,
+            }) if skipped == signature
+        ));
+
+        let mut warmed = engine(16).with_startup_warmup(Duration::from_secs(60));
+        let started_at = warmed.started_at.checked_sub(Duration::from_secs(61));
+        assert!(started_at.is_some());
+        if let Some(started_at) = started_at {
+            warmed.started_at = started_at;
+            assert!(
+                warmed
+                    .prepare(candidate_event_with_instructions(signature, 1))
+                    .is_ok()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn run_reports_why_the_queue_closed() {
+        let dropped = engine(16);
+        dropped.events.close();
+        assert_eq!(dropped.run().await, EngineStopReason::IngressClosed);
+
+        let pooled = engine(16).with_worker_tasks(2);
+        pooled.events.close_with(QueueCloseReason::Shutdown);
+        assert_eq!(pooled.run().await, EngineStopReason::Shutdown);
+
+        let failed = engine(16);
+        failed.events.close_with(QueueCloseReason::IngressFailed);
+        assert_eq!(failed.run().await, EngineStopReason::IngressFailed);
+    }
+
+    #[tokio::test]
+    async fn cancels_handlers_that_exceed_the_deadline() {
+        let (alive, cancelled) = oneshot::channel::<()>();
+        let slow_handler = async move {
+            let _alive = alive;
+            std::future::pending::<()>().await;
+        };
+
+        assert_eq!(
+            within_deadline(Some(Duration::from_millis(10)), slow_handler).await,
+            None
+        );
+        assert!(cancelled.await.is_err());
+        assert_eq!(
+            within_deadline(Some(Duration::from_secs(5)), async { 7_u8 }).await,
+            Some(7)
+        );
+        assert_eq!(within_deadline(None, async { 7_u8 }).await, Some(7));
+    }
+
+    #[tokio::test]
+    async fn dispatches_higher_priority_rules_first_under_contention() {
+        let watched_mint = Pubkey::new_unique();
+        let address = RuleAddress::try_from(watched_mint.to_string());
+        let slippage = RuleSlippageBps::from_pct_str("1");
+        assert!(address.is_ok());
+        assert!(slippage.is_ok());
+        let (Ok(address), Ok(slippage)) = (address, slippage) else {
+            return;
+        };
+        let rule = SnipeRule::new(
+            address,
+            RuleSolAmount::new(Lamports::new(1_000_000_000)),
+            RuleSolAmount::new(Lamports::new(0)),
+            slippage,
+        )
+        .with_priority(9);
+        let engine = engine_with(
+            16,
+            RuleBook::new(vec![rule], Vec::new()),
+            8,
+            EventQueueDropPolicy::DropNewest,
+        );
+
+        let first = Signature::from([1_u8; 64]);
+        let second = Signature::from([2_u8; 64]);
+        let watched = Signature::from([3_u8; 64]);
+        for (signature, key, ingress_ns) in [
+            (second, Pubkey::new_unique(), 20),
+            (first, Pubkey::new_unique(), 10),
+            (watched, watched_mint, 30),
+        ] {
+            let pushed = engine
+                .events
+                .push(candidate_event_at(signature, key, ingress_ns));
+            assert!(!pushed.dropped_event());
+        }
+
+        let mut staged = DispatchQueue::new();
+        let mut order = Vec::new();
+        for _ in 0..3 {
+            if let Some(prepared) = engine.next_dispatch(&mut staged).await {
+                order.push(prepared.signature);
+            }
+        }
+        assert_eq!(order, vec![watched, first, second]);
+    }
+
+    #[tokio::test]
+    async fn staged_events_count_toward_the_queue_capacity() {
+        let engine = engine_with(
+            16,
+            RuleBook::new(Vec::new(), Vec::new()),
+            2,
+            EventQueueDropPolicy::DropOldest,
+        );
+        let signatures: Vec<Signature> =
+            (1_u8..=4).map(|byte| Signature::from([byte; 64])).collect();
+        let mut staged = DispatchQueue::new();
+        let push = |index: usize, ingress_ns: u64| {
+            if let Some(signature) = signatures.get(index) {
+                let pushed = engine.events.push(candidate_event_at(
+                    *signature,
+                    Pubkey::new_unique(),
+                    ingress_ns,
+                ));
+                assert!(!pushed.dropped_event());
+            }
+        };
+
+        push(0, 10);
+        push(1, 20);
+        let first = engine.next_dispatch(&mut staged).await;
+        assert_eq!(
+            first.map(|prepared| prepared.signature),
+            signatures.first().copied()
+        );
+        assert_eq!(staged.len(), 1);
+
+        push(2, 30);
+        push(3, 40);
+        let next = engine.next_dispatch(&mut staged).await;
+        assert_eq!(
+            next.map(|prepared| prepared.signature),
+            signatures.get(2).copied()
+        );
+        assert_eq!(staged.len(), 1);
+        assert_eq!(engine.events.dropped_events(), 1);
+    }
+
+    fn engine(signature_dedup_capacity: usize) -> SniperEngine {
+        engine_with(
+            signature_dedup_capacity,
+            RuleBook::new(Vec::new(), Vec::new()),
+            1,
+            EventQueueDropPolicy::DropNewest,
+        )
+    }
+
+    fn engine_with(
+        signature_dedup_capacity: usize,
+        rulebook: RuleBook,
+        queue_capacity: usize,
+        drop_policy: EventQueueDropPolicy,
+    ) -> SniperEngine {
+        let telemetry = Arc::new(LatencyTelemetry::disabled());
+        let events = Arc::new(SniperEventQueue::new(
+            queue_capacity,
+            drop_policy,
+            Arc::clone(&telemetry),
+        ));
+        let (_rulebook_tx, rulebook_rx) = watch::channel(Arc::new(rulebook));
+        let context = Arc::new(ExecutionContext::new(
+            Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())),
+            Arc::new(Keypair::new()),
+            Arc::new(FakeSubmitter::default()),
+        ));
+
+        SniperEngine::new(
+            context,
+            events,
+            rulebook_rx,
+            telemetry,
+            None,
+            signature_dedup_capacity,
+        )
+    }
+
+    fn candidate_event_at(
+        signature: Signature,
+        payer: Pubkey,
+        ingress_ns: u64,
+    ) -> SniperInputEvent {
+        let mut transaction =
+            VersionedTransaction::from(Transaction::new_unsigned(Message::new(&[], Some(&payer))));
+        transaction.signatures = vec![signature];
+
+        SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+            kind: RaydiumCandidateKind::Cpmm,
+            transaction: Arc::new(transaction),
+            ingress: IngressMetadata::from_receive_clock(IngressSource::Websocket, ingress_ns),
+        })
+    }
+
+    fn candidate_event_with_instructions(
+        signature: Signature,
+        instructions: usize,
+    ) -> SniperInputEvent {
+        let payer = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let instructions = (0..instructions)
+            .map(|_index| Instruction::new_with_bytes(program, &[], Vec::new()))
+            .collect::<Vec<_>>();
+        let mut transaction = VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+            &instructions,
+            Some(&payer),
+        )));
+        transaction.signatures = vec![signature];
+
+        SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+            kind: RaydiumCandidateKind::Cpmm,
+            transaction: Arc::new(transaction),
+            ingress: IngressMetadata::from_receive_clock(IngressSource::Websocket, 1),
+        })
+    }
+
+    fn candidate_event(signatures: Vec<Signature>) -> SniperInputEvent {
+        let payer = Pubkey::new_unique();
+        let mut transaction =
+            VersionedTransaction::from(Transaction::new_unsigned(Message::new(&[], Some(&payer))));
+        transaction.signatures = signatures;
+
+        SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+            kind: RaydiumCandidateKind::Cpmm,
+            transaction: Arc::new(transaction),
+            ingress: IngressMetadata::from_receive_clock(IngressSource::Websocket, 1),
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+
+use solana_sdk::{program_error::ProgramError, pubkey::Pubkey, signature::Signature};
+use thiserror::Error;
+
+use crate::{
+    adapters::raydium::AuthorityDerivationError, domain::value_objects::sol_amount::Lamports,
+    ports::transaction_submitter::TransactionSubmitError,
+};
+
+/// Why a strategy handler abandoned a candidate.
+#[derive(Debug, Error)]
+pub enum SnipeErrorKind {
+    #[error("{address} is unavailable; program addresses were not initialized")]
+    AddressesUninitialized { address: &'static str },
+    #[error("failed to parse the pool creation instruction")]
+    CreationParse,
+    #[error("pool creation has no {field}")]
+    MissingCreationField { field: &'static str },
+    #[error(
+        "min_amount_out is 0 (zero reserves or slippage of 100%); set runtime.allow_zero_min_amount_out to send unprotected swaps"
+    )]
+    ZeroMinAmountOut,
+    #[error("pool reserves cannot price a buy of {tokens} tokens")]
+    TargetTokenAmount { tokens: u64 },
+    #[error(
+        "buying {tokens} tokens costs {} SOL, above the rule's {} SOL max_snipe_height_sol",
+        .cost.as_sol_string(),
+        .cap.as_sol_string()
+    )]
+    TargetTokenAmountOverCap {
+        tokens: u64,
+        cost: Lamports,
+        cap: Lamports,
+    },
+    #[error("failed to fetch OpenBook market {market_id}")]
+    MarketFetch { market_id: Pubkey },
+    #[error(transparent)]
+    MarketAuthority(#[from] AuthorityDerivationError),
+    #[error("failed to build {instruction} instruction: {source}")]
+    Instruction {
+        instruction: &'static str,
+        source: ProgramError,
+    },
+    #[error("{detail}")]
+    Blockhash { detail: String },
+    #[error("{detail}")]
+    BuildTransaction { detail: String },
+    #[error("signed transaction is {size} bytes, over the {limit}-byte packet limit")]
+    TransactionTooLarge { size: usize, limit: usize },
+    #[error("failed to send transaction: {0}")]
+    Submit(#[from] TransactionSubmitError),
+    #[error("swap transaction {signature} failed: {detail}")]
+    SwapFailed {
+        signature: Signature,
+        detail: String,
+    },
+    #[error("swap transaction {signature} was not confirmed")]
+    Unconfirmed { signature: Signature },
+}
+
+/// A strategy handler failure, tagged with the mint once the handler has resolved it.
+#[derive(Debug)]
+pub struct SnipeError {
+    pub token_address: Option<Pubkey>,
+    pub kind: SnipeErrorKind,
+}
+
+impl SnipeError {
+    pub const fn for_token(token_address: Pubkey, kind: SnipeErrorKind) -> Self {
+        Self {
+            token_address: Some(token_address),
+            kind,
+        }
+    }
+}
+
+impl From<SnipeErrorKind> for SnipeError {
+    fn from(kind: SnipeErrorKind) -> Self {
+        Self {
+            token_address: None,
+            kind,
+        }
+    }
+}
+
+impl Display for SnipeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.token_address {
+            Some(token_address) => write!(f, "{} > {}", token_address, self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for SnipeError {}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::{SnipeError, SnipeErrorKind};
+
+    #[test]
+    fn prefixes_the_mint_once_known() {
+        let token_address = Pubkey::new_unique();
+
+        assert_eq!(
+            SnipeError::from(SnipeErrorKind::CreationParse).to_string(),
+            "failed to parse the pool creation instruction"
+        );
+        assert_eq!(
+            SnipeError::for_token(
+                token_address,
+                SnipeErrorKind::MissingCreationField {
+                    field: "token program"
+                },
+            )
+            .to_string(),
+            format!("{token_address} > pool creation has no token program")
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use tokio::{
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
+};
+
+use crate::domain::value_objects::sol_amount::Lamports;
+
+/// Running wallet balance drift since startup, fed by the balance read after each
+/// confirmed swap.
+#[derive(Debug)]
+pub struct SessionLedger {
+    inner: Mutex<LedgerSnapshot>,
+}
+
+/// Ledger totals at one point in time. `spent_lamports` and `received_lamports` sum the
+/// decreases and increases between consecutive balance reads.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LedgerSnapshot {
+    pub starting_lamports: u64,
+    pub current_lamports: u64,
+    pub spent_lamports: u64,
+    pub received_lamports: u64,
+    pub swaps: u64,
+}
+
+impl LedgerSnapshot {
+    pub const fn is_net_loss(&self) -> bool {
+        self.current_lamports < self.starting_lamports
+    }
+
+    /// Signed SOL change since startup, e.g. `-0.25`.
+    pub fn net_change_sol_string(&self) -> String {
+        let sign = if self.is_net_loss() { "-" } else { "+" };
+        let change = self.current_lamports.abs_diff(self.starting_lamports);
+        format!("{}{}", sign, Lamports::new(change).as_sol_string())
+    }
+}
+
+impl SessionLedger {
+    pub const fn new(starting_lamports: u64) -> Self {
+        Self {
+            inner: Mutex::new(LedgerSnapshot {
+                starting_lamports,
+                current_lamports: starting_lamports,
+                spent_lamports: 0,
+                received_lamports: 0,
+                swaps: 0,
+            }),
+        }
+    }
+
+    /// Records the wallet balance read after a confirmed swap.
+    pub fn record_swap_balance(&self, balance_lamports: u64) -> LedgerSnapshot {
+        let mut ledger = self.lock_inner();
+        if balance_lamports < ledger.current_lamports {
+            let spent = ledger.current_lamports.saturating_sub(balance_lamports);
+            ledger.spent_lamports = ledger.spent_lamports.saturating_add(spent);
+        } else {
+            let received = balance_lamports.saturating_sub(ledger.current_lamports);
+            ledger.received_lamports = ledger.received_lamports.saturating_add(received);
+        }
+        ledger.current_lamports = balance_lamports;
+        ledger.swaps = ledger.swaps.saturating_add(1);
+        *ledger
+    }
+
+    pub fn snapshot(&self) -> LedgerSnapshot {
+        *self.lock_inner()
+    }
+
+    #[must_use]
+    pub fn spawn_reporter(self: Arc<Self>, period: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                self.log_report();
+            }
+        })
+    }
+
+    pub fn log_report(&self) {
+        let snapshot = self.snapshot();
+        log::info!(
+            "Session ledger > swaps={} start={} SOL current={} SOL spent={} SOL received={} SOL net={} SOL",
+            snapshot.swaps,
+            Lamports::new(snapshot.starting_lamports).as_sol_string(),
+            Lamports::new(snapshot.current_lamports).as_sol_string(),
+            Lamports::new(snapshot.spent_lamports).as_sol_string(),
+            Lamports::new(snapshot.received_lamports).as_sol_string(),
+            snapshot.net_change_sol_string(),
+        );
+    }
+
+    fn lock_inner(&self) -> MutexGuard<'_, LedgerSnapshot> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SessionLedger;
+
+    #[test]
+    fn tracks_spend_and_net_change_across_swaps() {
+        let ledger = SessionLedger::new(2_000_000_000);
+
+        let after_buy = ledger.record_swap_balance(1_500_000_000);
+        assert_eq!(after_buy.spent_lamports, 500_000_000);
+        assert_eq!(after_buy.net_change_sol_string(), "-0.5");
+
+        let after_refund = ledger.record_swap_balance(1_600_000_000);
+        assert_eq!(after_refund.received_lamports, 100_000_000);
+        assert_eq!(after_refund.spent_lamports, 500_000_000);
+
+        let after_second_buy = ledger.record_swap_balance(1_250_000_000);
+        assert_eq!(after_second_buy.spent_lamports, 850_000_000);
+        assert_eq!(after_second_buy.swaps, 3);
+        assert!(after_second_buy.is_net_loss());
+        assert_eq!(after_second_buy.net_change_sol_string(), "-0.75");
+        assert_eq!(ledger.snapshot(), after_second_buy);
+    }
+
+    #[test]
+    fn reports_gains_with_a_plus_sign() {
+        let ledger = SessionLedger::new(1_000_000_000);
+        assert_eq!(ledger.snapshot().net_change_sol_string(), "+0");
+
+        let snapshot = ledger.record_swap_balance(1_250_000_000);
+        assert!(!snapshot.is_net_loss());
+        assert_eq!(snapshot.net_change_sol_string(), "+0.25");
+    }
+}
@@ -1,6 +1,21 @@
 pub mod cache;
+pub mod confirmation;
 pub mod cpmm;
+pub mod dedup;
+pub mod dedup_store;
 pub mod engine;
+pub mod error;
+pub mod ledger;
 pub mod openbook;
+pub mod outcome;
+pub mod paper;
+pub mod pool_open;
+pub mod priority;
+pub mod queue;
 pub mod replay;
+pub mod rule_stats;
+pub mod send;
+pub mod slippage;
 pub mod telemetry;
+pub mod tx;
+pub mod workers;
@@ -1,35 +1,45 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use chrono::{Local, TimeZone};
-use sof_solana_compat::TxBuilder;
-use sof_tx::SignedTx;
-use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
-    signature::Signature,
+    pubkey::Pubkey,
     signer::Signer,
-    transaction::VersionedTransaction,
 };
 use solana_system_interface::instruction::transfer;
-use solana_transaction_status::UiTransactionEncoding;
 use spl_associated_token_account::{
-    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
 };
-use spl_token::instruction::{close_account, sync_native};
 
 use crate::{
-    adapters::raydium::{
-        ParsedOpenbookCreation, SwapInstructionBaseIn, get_associated_authority,
-        get_market_accounts, parse_openbook_creation_transaction,
+    adapters::{
+        raydium::{
+            DEFAULT_MARKET_AUTHORITY_MAX_NONCE, ParsedOpenbookCreation, SwapInstructionBaseIn,
+            get_associated_authority, get_market_accounts, parse_openbook_creation_transaction,
+        },
+        rpc_retry::with_rpc_timeout,
     },
     app::context::ExecutionContext,
     domain::{
         aggregates::RuleBook,
-        events::{IngressMetadata, unix_timestamp_now_ns},
+        events::{IngressMetadata, ingress_latency_ns},
         services::RuleMatcher,
-        value_objects::{TxSubmissionMode, sol_amount::Lamports},
+        value_objects::sol_amount::Lamports,
+    },
+    slices::sniper::{
+        cache,
+        error::{SnipeError, SnipeErrorKind},
+        outcome::{OutcomeIngress, SnipeOutcome, SnipeStatus, record_outcome},
+        paper::PaperBuy,
+        pool_open::wait_for_pool_open,
+        send::{SwapSend, send_swap},
+        slippage::{RAYDIUM_V4_EXCEEDED_SLIPPAGE, SlippageEscalation},
+        tx::{
+            WsolAccount, ensure_min_amount_out, swap_input_lamports, wrapped_lamports,
+            wsol_close_instruction, wsol_top_up_instructions,
+        },
     },
-    slices::sniper::cache,
 };
 
 pub async fn handle_openbook_candidate_structured(
@@ -37,23 +47,18 @@ pub async fn handle_openbook_candidate_structured(
     rulebook: Arc<RuleBook>,
     transaction: Arc<solana_sdk::transaction::VersionedTransaction>,
     ingress_metadata: IngressMetadata,
-) {
-    let program_id = match cache::raydium_v4_program_pubkey() {
-        Some(value) => value,
-        None => return,
-    };
-    let creation = match parse_openbook_creation_transaction(
-        context.rpc.as_ref(),
+) -> Result<(), SnipeError> {
+    let program_id = raydium_v4_program_id()?;
+    let creation = parse_openbook_creation_transaction(
+        &context.read_rpc,
+        context.rpc_call_timeout,
         transaction.as_ref(),
         program_id,
     )
     .await
-    {
-        Some(value) => value,
-        None => return,
-    };
+    .ok_or(SnipeErrorKind::CreationParse)?;
 
-    handle_openbook_transaction(context, rulebook, ingress_metadata, creation).await;
+    handle_openbook_transaction(context, rulebook, ingress_metadata, creation).await
 }
 
 async fn handle_openbook_transaction(
@@ -61,7 +66,7 @@ async fn handle_openbook_transaction(
     rulebook: Arc<RuleBook>,
     ingress_metadata: IngressMetadata,
     creation: ParsedOpenbookCreation,
-) {
+) -> Result<(), SnipeError> {
     log::debug!(
         "OpenBook > init_pc_amount: {}, init_coin_amount: {}, open_time: {}",
         creation.init_pc_amount,
@@ -69,20 +74,34 @@ async fn handle_openbook_transaction(
         creation.open_time
     );
 
-    let ingress_latency_ns =
-        unix_timestamp_now_ns().saturating_sub(ingress_metadata.normalized_timestamp_ns);
+    let token_address = creation
+        .token_mint()
+        .ok_or(SnipeErrorKind::MissingCreationField {
+            field: "token mint",
+        })?;
+    snipe_openbook_token(context, rulebook, ingress_metadata, creation, token_address)
+        .await
+        .map_err(|kind| SnipeError::for_token(token_address, kind))
+}
+
+async fn snipe_openbook_token(
+    context: Arc<ExecutionContext>,
+    rulebook: Arc<RuleBook>,
+    ingress_metadata: IngressMetadata,
+    creation: ParsedOpenbookCreation,
+    token_address: Pubkey,
+) -> Result<(), SnipeErrorKind> {
+    let ingress_latency_ns = ingress_latency_ns(&ingress_metadata);
     log::debug!(
-        "OpenBook > ingress source={}, normalized_ts={}ns, hw_ts={:?}, latency={}ns",
+        "OpenBook > {} > ingress event_id={}, source={}, normalized_ts={}ns, hw_ts={:?}, latency={}ns",
+        token_address,
+        ingress_metadata.event_id,
         ingress_metadata.source.as_str(),
         ingress_metadata.normalized_timestamp_ns,
         ingress_metadata.hardware_timestamp_ns,
         ingress_latency_ns
     );
 
-    let token_address = match creation.token_mint() {
-        Some(value) => value,
-        None => return,
-    };
     let token_address_text = token_address.to_string();
     let deployer_address_text = creation.deployer_address.to_string();
 
@@ -94,7 +113,7 @@ async fn handle_openbook_transaction(
         Some(value) => value,
         None => {
             log::debug!("OpenBook > {} > Ignoring token", token_address);
-            return;
+            return Ok(());
         }
     };
 
@@ -104,6 +123,19 @@ async fn handle_openbook_transaction(
         matched_rule.source,
         matched_rule.cold.address
     );
+    if let Some(rule_stats) = &context.rule_stats {
+        rule_stats.record_match(&matched_rule.cold.address);
+    }
+
+    let init_liquidity = Lamports::new(creation.init_sol_amount());
+    if !context.init_liquidity_band.contains(init_liquidity) {
+        log::info!(
+            "OpenBook > {} > Skipping pool with {} SOL initial liquidity outside the configured band",
+            token_address,
+            init_liquidity.as_sol_string()
+        );
+        return Ok(());
+    }
 
     log::debug!(
         "OpenBook > {} > Snipe height: {} SOL, Jito tip: {} SOL, Slippage: {} %",
@@ -113,7 +145,11 @@ async fn handle_openbook_transaction(
         matched_rule.hot.slippage().as_pct_string()
     );
 
-    log::info!("OpenBook > {} > Found token", token_address);
+    log::info!(
+        "OpenBook > {} > Found token (event_id={})",
+        token_address,
+        ingress_metadata.event_id
+    );
 
     log::debug!(
         "OpenBook > {} > ID: {}, Authority: {}, Open orders: {}, Base vault: {}, Quote vault: {}, Target orders: {}, Market program ID: {}, Market ID: {}",
@@ -128,70 +164,89 @@ async fn handle_openbook_transaction(
         creation.market_id,
     );
 
-    let market = match get_market_accounts(&context.rpc, &creation.market_id).await {
-        Some(value) => value,
-        None => return,
-    };
-
-    let lamports = matched_rule.hot.snipe_height().as_lamports().as_u64();
-
-    let wsol_pubkey = match cache::wsol_pubkey() {
-        Some(value) => value,
-        None => return,
-    };
+    let market = get_market_accounts(
+        &context.read_rpc,
+        context.rpc_call_timeout,
+        &creation.market_id,
+    )
+    .await
+    .ok_or(SnipeErrorKind::MarketFetch {
+        market_id: creation.market_id,
+    })?;
 
-    let token_program_id = match cache::token_program_pubkey() {
-        Some(value) => value,
-        None => return,
+    let reserves = resolve_pool_reserves(&context, &creation, &token_address_text).await;
+    let (sol_reserve, token_reserve) = if creation.token_is_coin_mint() {
+        (reserves.pc_amount, reserves.coin_amount)
+    } else {
+        (reserves.coin_amount, reserves.pc_amount)
     };
+    let lamports = swap_input_lamports(matched_rule.hot, sol_reserve, token_reserve)?;
+    if let Some(tokens) = matched_rule.hot.target_token_amount() {
+        log::info!(
+            "OpenBook > {} > Buying {} tokens for {} SOL at the pool price",
+            token_address,
+            tokens,
+            Lamports::new(lamports).as_sol_string()
+        );
+    }
 
-    let user_in_token_account =
-        get_associated_token_address(&context.keypair.pubkey(), &wsol_pubkey);
-    let user_out_token_account =
-        get_associated_token_address(&context.keypair.pubkey(), &token_address);
+    let wsol_pubkey = cache::wsol_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+        address: "WSOL mint",
+    })?;
 
-    let mut instructions = Vec::with_capacity(7);
+    let token_program_id =
+        cache::token_program_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+            address: "token program",
+        })?;
 
-    instructions.push(create_associated_token_account_idempotent(
-        &context.keypair.pubkey(),
+    let wsol_account =
+        WsolAccount::associated(context.keypair.pubkey(), wsol_pubkey, token_program_id);
+    let user_in_token_account = wsol_account.address;
+    let user_out_token_account = get_associated_token_address_with_program_id(
         &context.keypair.pubkey(),
-        &wsol_pubkey,
-        &token_program_id,
-    ));
+        &token_address,
+        &creation.token_program,
+    );
 
-    instructions.push(transfer(
-        &context.keypair.pubkey(),
-        &user_in_token_account,
-        lamports,
-    ));
+    let mut instructions = Vec::with_capacity(7);
 
-    let sync_instruction = match sync_native(&spl_token::ID, &user_in_token_account) {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!(
-                "OpenBook > {} > sync_native failed: {}",
-                token_address,
-                error
-            );
-            return;
-        }
+    let already_wrapped = if context.reuse_wrapped_sol {
+        wrapped_lamports(context.as_ref(), &wsol_account).await
+    } else {
+        None
     };
-    instructions.push(sync_instruction);
+    instructions.extend(
+        wsol_top_up_instructions(
+            &wsol_account,
+            context.wsol_wrap_lamports(lamports),
+            already_wrapped,
+        )
+        .map_err(|source| SnipeErrorKind::Instruction {
+            instruction: "WSOL wrap",
+            source,
+        })?,
+    );
 
     instructions.push(create_associated_token_account_idempotent(
         &context.keypair.pubkey(),
         &context.keypair.pubkey(),
         &token_address,
-        &token_program_id,
+        &creation.token_program,
     ));
 
     let min_amount_out = calculate_min_amount_out(
         lamports,
         matched_rule.hot.slippage().as_bps(),
-        creation.init_pc_amount,
-        creation.init_coin_amount,
+        reserves,
         creation.token_is_coin_mint(),
     );
+    let min_amount_out = ensure_min_amount_out(min_amount_out, context.allow_zero_min_amount_out)?;
+    if min_amount_out == 0 {
+        log::warn!(
+            "OpenBook > {} > Sending with min_amount_out 0; the swap has no slippage protection",
+            token_address
+        );
+    }
 
     log::debug!(
         "OpenBook > {} > Min amount out: {}",
@@ -199,22 +254,15 @@ async fn handle_openbook_transaction(
         min_amount_out
     );
 
-    let market_authority =
-        match get_associated_authority(&market.program_id, &market.state.own_address) {
-            Ok(value) => value.0,
-            Err(_) => return,
-        };
+    let (market_authority, _nonce) = get_associated_authority(
+        &market.program_id,
+        &market.state.own_address,
+        DEFAULT_MARKET_AUTHORITY_MAX_NONCE,
+    )?;
 
     let swap_instruction = Instruction::new_with_borsh(
-        match cache::raydium_v4_program_pubkey() {
-            Some(value) => value,
-            None => return,
-        },
-        &SwapInstructionBaseIn {
-            discriminator: 9,
-            amount_in: lamports,
-            minimum_amount_out: min_amount_out,
-        },
+        raydium_v4_program_id()?,
+        &swap_base_in(lamports, min_amount_out),
         vec![
             AccountMeta::new_readonly(token_program_id, false),
             AccountMeta::new(creation.id, false),
@@ -236,33 +284,24 @@ async fn handle_openbook_transaction(
             AccountMeta::new_readonly(context.keypair.pubkey(), true),
         ],
     );
+    let swap_instruction_index = instructions.len();
     instructions.push(swap_instruction);
 
-    let close_instruction = match close_account(
-        &token_program_id,
-        &user_in_token_account,
-        &context.keypair.pubkey(),
-        &context.keypair.pubkey(),
-        &[&context.keypair.pubkey()],
-    ) {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!(
-                "OpenBook > {} > close_account failed: {}",
-                token_address,
-                error
-            );
-            return;
-        }
-    };
-    instructions.push(close_instruction);
+    instructions.extend(
+        wsol_close_instruction(&wsol_account, context.wsol_close_timing).map_err(|source| {
+            SnipeErrorKind::Instruction {
+                instruction: "close_account",
+                source,
+            }
+        })?,
+    );
 
-    let jito_tip_lamports = matched_rule.hot.jito_tip().as_lamports().as_u64();
-    if context.sof_tx_uses_jito || context.tx_submission_mode == TxSubmissionMode::Jito {
-        let jito_tip_account = match cache::jito_tip_pubkey() {
-            Some(value) => value,
-            None => return,
-        };
+    let jito_tip_lamports = context.paid_jito_tip(matched_rule.hot.jito_tip_for(lamports).as_u64());
+    if context.pays_jito_tip() {
+        let jito_tip_account =
+            cache::next_jito_tip_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+                address: "Jito tip account",
+            })?;
 
         instructions.push(transfer(
             &context.keypair.pubkey(),
@@ -271,201 +310,197 @@ async fn handle_openbook_transaction(
         ));
     }
 
-    maybe_wait_for_pool_open(creation.open_time, token_address_text.as_str(), "OpenBook").await;
+    let outcome = SnipeOutcome::new(
+        "openbook",
+        token_address_text.clone(),
+        &matched_rule,
+        lamports,
+        min_amount_out,
+        OutcomeIngress {
+            metadata: ingress_metadata,
+            to_match_ns: ingress_latency_ns,
+        },
+    )
+    .with_jito_tip(jito_tip_lamports);
 
-    let blockhash = match context.latest_swap_blockhash().await {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!("OpenBook > {} > {}", token_address, error);
-            return;
-        }
-    };
+    if !wait_for_pool_open(
+        creation.open_time,
+        context.max_pool_open_wait,
+        context.pre_open_offset,
+        token_address_text.as_str(),
+        "OpenBook",
+    )
+    .await
+    {
+        record_outcome(
+            &context,
+            outcome.finish(
+                SnipeStatus::Skipped,
+                Some("pool open time is beyond runtime.max_pool_open_wait_secs".to_owned()),
+            ),
+        )
+        .await;
+        return Ok(());
+    }
 
-    let swap_tx = match build_swap_transaction(context.as_ref(), instructions, blockhash) {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!("OpenBook > {} > {}", token_address, error);
-            return;
-        }
-    };
+    let allow_zero_min_amount_out = context.allow_zero_min_amount_out;
+    let token_is_coin_mint = creation.token_is_coin_mint();
+    send_swap(
+        context.as_ref(),
+        SwapSend {
+            label: "OpenBook",
+            token_address,
+            swap_program: raydium_v4_program_id()?,
+            instructions,
+            swap_instruction_index,
+            exceeded_slippage_code: RAYDIUM_V4_EXCEEDED_SLIPPAGE,
+            priority_fees: context.rule_priority_fees(matched_rule.hot.fee_multiplier()),
+            escalation: SlippageEscalation::new(
+                context.slippage_retry_attempts,
+                matched_rule.hot.slippage(),
+                matched_rule.hot.max_slippage(),
+            ),
+            wsol_account,
+            buy: PaperBuy {
+                lamports_in: lamports,
+                tip_lamports: jito_tip_lamports,
+                sol_reserve,
+                token_reserve,
+            },
+            widen_swap: move |queued_swap: &mut Instruction, slippage_bps| {
+                let widened_min_amount_out = ensure_min_amount_out(
+                    calculate_min_amount_out(lamports, slippage_bps, reserves, token_is_coin_mint),
+                    allow_zero_min_amount_out,
+                )?;
+                *queued_swap = Instruction::new_with_borsh(
+                    queued_swap.program_id,
+                    &swap_base_in(lamports, widened_min_amount_out),
+                    std::mem::take(&mut queued_swap.accounts),
+                );
+                Ok(widened_min_amount_out)
+            },
+        },
+        outcome,
+    )
+    .await
+}
 
-    let swap_signature = swap_tx.signatures.first().copied().unwrap_or_default();
+fn raydium_v4_program_id() -> Result<Pubkey, SnipeErrorKind> {
+    cache::raydium_v4_program_pubkey().ok_or(SnipeErrorKind::AddressesUninitialized {
+        address: "Raydium V4 program",
+    })
+}
 
-    if context.dry_run {
-        log::info!(
-            "OpenBook > {} > Dry run built swap transaction: {} (submission skipped)",
-            token_address,
-            swap_signature
-        );
-        return;
+const fn swap_base_in(lamports: u64, min_amount_out: u64) -> SwapInstructionBaseIn {
+    SwapInstructionBaseIn {
+        discriminator: 9,
+        amount_in: lamports,
+        minimum_amount_out: min_amount_out,
     }
+}
 
-    let sent_signature = match submit_swap_transaction(context.as_ref(), &swap_tx).await {
-        Ok(value) => value,
-        Err(error) => {
-            log::error!(
-                "OpenBook > {} > Failed to send transaction: {}",
-                token_address,
-                error
-            );
-            return;
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct PoolReserves {
+    pc_amount: u64,
+    coin_amount: u64,
+}
+
+impl PoolReserves {
+    const fn initial(creation: &ParsedOpenbookCreation) -> Self {
+        Self {
+            pc_amount: creation.init_pc_amount,
+            coin_amount: creation.init_coin_amount,
         }
-    };
+    }
+}
 
-    log::info!(
-        "OpenBook > {} > Swap transaction signature: {}",
-        token_address,
-        sent_signature
-    );
+async fn resolve_pool_reserves(
+    context: &ExecutionContext,
+    creation: &ParsedOpenbookCreation,
+    token_address: &str,
+) -> PoolReserves {
+    let initial = PoolReserves::initial(creation);
+    if !context.use_live_reserves {
+        return initial;
+    }
 
-    match wait_for_signature_status(
-        context.rpc.as_ref(),
-        &sent_signature,
-        token_address_text.as_str(),
-        "OpenBook",
-    )
-    .await
-    {
-        Some(Ok(())) => {}
-        Some(Err(error)) => {
-            log::error!(
-                "OpenBook > {} > Swap transaction failed: {}",
+    let (rpc_index, rpc) = context.read_rpc.active();
+    match fetch_live_reserves(rpc, context.rpc_call_timeout, creation).await {
+        Ok(reserves) => {
+            context.read_rpc.record_success(rpc_index);
+            log::debug!(
+                "OpenBook > {} > live reserves pc={} coin={} (init pc={} coin={})",
                 token_address,
-                error
+                reserves.pc_amount,
+                reserves.coin_amount,
+                initial.pc_amount,
+                initial.coin_amount
             );
-            return;
+            reserves
         }
-        None => return,
-    }
-
-    let balance = match context.rpc.get_balance(&context.keypair.pubkey()).await {
-        Ok(value) => value,
         Err(error) => {
-            log::error!(
-                "OpenBook > {} > Failed to fetch balance: {}",
+            context.read_rpc.record_failure(rpc_index);
+            log::warn!(
+                "OpenBook > {} > live reserve fetch failed, using init amounts: {}",
                 token_address,
                 error
             );
-            return;
+            initial
         }
-    };
-
-    log::info!(
-        "OpenBook > {} > Successfully swapped {} SOL with {} SOL tip budget (mode={})",
-        token_address,
-        matched_rule.hot.snipe_height().as_sol_string(),
-        matched_rule.hot.jito_tip().as_sol_string(),
-        context.tx_submission_mode.as_str(),
-    );
-    log::info!(
-        "OpenBook > {} > Balance: {} SOL",
-        token_address,
-        Lamports::new(balance).as_sol_string()
-    );
+    }
 }
 
-async fn wait_for_signature_status(
+async fn fetch_live_reserves(
     rpc: &RpcClient,
-    signature: &Signature,
-    token_address: &str,
-    label: &str,
-) -> Option<Result<(), String>> {
-    const MAX_CONFIRMATION_POLLS: usize = 120;
-    let mut delay = tokio::time::Duration::from_millis(250);
-
-    for _ in 0..MAX_CONFIRMATION_POLLS {
-        let status = match rpc.get_signature_status(signature).await {
-            Ok(value) => value,
-            Err(error) => {
-                log::error!(
-                    "{} > {} > Signature status failed: {}",
-                    label,
-                    token_address,
-                    error
-                );
-                return None;
-            }
-        };
-
-        if let Some(status) = status {
-            return Some(status.map_err(|error| error.to_string()));
-        }
-
-        tokio::time::sleep(delay).await;
-        if delay < tokio::time::Duration::from_secs(2) {
-            delay = delay
-                .saturating_mul(2)
-                .min(tokio::time::Duration::from_secs(2));
-        }
-    }
-
-    log::error!(
-        "{} > {} > No signature status returned before timeout",
-        label,
-        token_address
+    call_timeout: Option<Duration>,
+    creation: &ParsedOpenbookCreation,
+) -> Result<PoolReserves, String> {
+    let (coin_balance, pc_balance) = tokio::join!(
+        with_rpc_timeout(
+            call_timeout,
+            rpc.get_token_account_balance(&creation.base_vault)
+        ),
+        with_rpc_timeout(
+            call_timeout,
+            rpc.get_token_account_balance(&creation.quote_vault)
+        )
     );
-    None
-}
+    let coin_balance =
+        coin_balance.map_err(|error| format!("base vault balance failed: {error}"))?;
+    let pc_balance = pc_balance.map_err(|error| format!("quote vault balance failed: {error}"))?;
 
-async fn submit_swap_transaction(
-    context: &ExecutionContext,
-    swap_tx: &VersionedTransaction,
-) -> Result<Signature, String> {
-    if let (Some(client), Some(plan)) = (&context.sof_tx_client, &context.sof_tx_plan) {
-        let tx_bytes = bincode::serialize(swap_tx)
-            .map_err(|error| format!("failed to serialize transaction for SOF-TX: {error}"))?;
-        let mut client = client.lock().await;
-        client
-            .submit_signed_via(SignedTx::VersionedTransactionBytes(tx_bytes), plan.clone())
-            .await
-            .map_err(|error| format!("SOF-TX submit failed: {error}"))?;
-        return Ok(swap_tx.signatures.first().copied().unwrap_or_default());
-    }
+    live_reserves(&pc_balance.amount, &coin_balance.amount)
+}
 
-    let send_config = RpcSendTransactionConfig {
-        skip_preflight: true,
-        encoding: Some(UiTransactionEncoding::Base58),
-        max_retries: Some(0),
-        ..RpcSendTransactionConfig::default()
+fn live_reserves(pc_amount: &str, coin_amount: &str) -> Result<PoolReserves, String> {
+    let reserves = PoolReserves {
+        pc_amount: parse_raw_token_amount(pc_amount)?,
+        coin_amount: parse_raw_token_amount(coin_amount)?,
     };
-
-    if context.tx_submission_mode == TxSubmissionMode::Direct {
-        return context
-            .rpc
-            .send_transaction_with_config(swap_tx, send_config)
-            .await
-            .map_err(|error| error.to_string());
+    if reserves.pc_amount == 0 || reserves.coin_amount == 0 {
+        return Err("pool vault is empty".to_owned());
     }
 
-    let jito_rpc = RpcClient::new(context.jito_url.as_ref().clone());
-    jito_rpc
-        .send_transaction_with_config(swap_tx, send_config)
-        .await
-        .map_err(|error| error.to_string())
+    Ok(reserves)
 }
 
-fn build_swap_transaction(
-    context: &ExecutionContext,
-    instructions: Vec<Instruction>,
-    blockhash: solana_sdk::hash::Hash,
-) -> Result<VersionedTransaction, String> {
-    let signer_refs: [&dyn Signer; 1] = [context.keypair.as_ref()];
-    TxBuilder::new(context.keypair.pubkey())
-        .with_compute_unit_limit(120_000)
-        .with_priority_fee_micro_lamports(context.priority_fees)
-        .add_instructions(instructions)
-        .build_and_sign(blockhash.to_bytes(), &signer_refs)
-        .map_err(|error| format!("failed to build/sign swap transaction: {error}"))
+fn parse_raw_token_amount(amount: &str) -> Result<u64, String> {
+    amount
+        .parse::<u64>()
+        .map_err(|_source| format!("invalid token amount '{amount}'"))
 }
 
 #[inline(always)]
 fn calculate_min_amount_out(
     lamports: u64,
     slippage_bps: u16,
-    init_pc_amount: u64,
-    init_coin_amount: u64,
+    reserves: PoolReserves,
     token_is_coin_mint: bool,
 ) -> u64 {
+    let PoolReserves {
+        pc_amount: init_pc_amount,
+        coin_amount: init_coin_amount,
+    } = reserves;
     if init_pc_amount == 0 || init_coin_amount == 0 {
         return 0;
     }
@@ -495,58 +530,50 @@ fn calculate_min_amount_out(
         .unwrap_or(u64::MAX)
 }
 
-async fn maybe_wait_for_pool_open(open_timestamp: i64, token_address: &str, label: &str) {
-    let now = Local::now();
-    let Some(target_time) = Local.timestamp_opt(open_timestamp, 0).single() else {
-        return;
-    };
-
-    if now >= target_time {
-        return;
-    }
-
-    let duration = target_time.signed_duration_since(now);
-    let remaining_minutes = duration.num_minutes();
-    let remaining_seconds = duration
-        .num_seconds()
-        .saturating_sub(remaining_minutes.saturating_mul(60));
-
-    log::info!(
-        "{} > {} > Pool closed. Proceeding with snipe in {}m {}s. UTC: {}",
-        label,
-        token_address,
-        remaining_minutes,
-        remaining_seconds,
-        target_time.to_rfc2822(),
-    );
-
-    if let Ok(duration) = duration.to_std() {
-        tokio::time::sleep(duration).await;
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use super::calculate_min_amount_out;
+    use super::{PoolReserves, calculate_min_amount_out, live_reserves};
 
     #[test]
     fn min_amount_out_uses_integer_fixed_point_math() {
-        let min = calculate_min_amount_out(1_000, 100, 20_000, 10_000, true);
+        let min = calculate_min_amount_out(1_000, 100, reserves(20_000, 10_000), true);
         assert_eq!(min, 495);
     }
 
     #[test]
     fn min_amount_out_returns_zero_for_invalid_bounds() {
-        assert_eq!(calculate_min_amount_out(1_000, 0, 0, 10_000, true), 0);
         assert_eq!(
-            calculate_min_amount_out(1_000, 10_000, 20_000, 10_000, true),
+            calculate_min_amount_out(1_000, 0, reserves(0, 10_000), true),
+            0
+        );
+        assert_eq!(
+            calculate_min_amount_out(1_000, 10_000, reserves(20_000, 10_000), true),
             0
         );
     }
 
     #[test]
     fn min_amount_out_saturates_on_internal_overflow() {
-        let min = calculate_min_amount_out(u64::MAX, 1, u64::MAX, 1, false);
+        let min = calculate_min_amount_out(u64::MAX, 1, reserves(u64::MAX, 1), false);
         assert_eq!(min, u64::MAX);
     }
+
+    #[test]
+    fn min_amount_out_follows_live_vault_balances() {
+        let live = live_reserves("40000", "10000");
+        assert_eq!(live, Ok(reserves(40_000, 10_000)));
+        if let Ok(live) = live {
+            assert_eq!(calculate_min_amount_out(1_000, 100, live, true), 247);
+        }
+
+        assert!(live_reserves("0", "10000").is_err());
+        assert!(live_reserves("not-a-number", "10000").is_err());
+    }
+
+    const fn reserves(pc_amount: u64, coin_amount: u64) -> PoolReserves {
+        PoolReserves {
+            pc_amount,
+            coin_amount,
+        }
+    }
 }
@@ -0,0 +1,365 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+use solana_sdk::signature::Signature;
+use thiserror::Error;
+use tokio::{
+    fs::{File, OpenOptions},
+    io::AsyncWriteExt,
+    sync::Mutex,
+};
+
+use crate::{
+    adapters::rpc_retry::with_rpc_timeout,
+    app::context::ExecutionContext,
+    domain::{
+        events::{IngressMetadata, ingress_latency_ns, unix_timestamp_now_ns},
+        services::MatchedRule,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnipeStatus {
+    Pending,
+    Skipped,
+    BuildFailed,
+    DryRun,
+    Paper,
+    SubmitFailed,
+    Unconfirmed,
+    Failed,
+    Confirmed,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct SnipeOutcome {
+    pub token: String,
+    pub strategy: &'static str,
+    pub rule_source: &'static str,
+    pub rule_address: String,
+    pub amount_lamports: u64,
+    pub min_amount_out: u64,
+    pub jito_tip_lamports: u64,
+    pub signature: Option<String>,
+    pub status: SnipeStatus,
+    pub error: Option<String>,
+    pub event_id: u64,
+    pub ingress_source: &'static str,
+    pub ingress_timestamp_ns: u64,
+    pub ingress_to_match_ns: u64,
+    pub ingress_to_submit_ns: Option<u64>,
+    pub creation_slot: Option<u64>,
+    pub creation_block_time: Option<i64>,
+    pub block_time_to_ingress_ms: Option<i64>,
+    pub recorded_at_ns: u64,
+    #[serde(skip)]
+    ingress: IngressMetadata,
+}
+
+/// The candidate's ingress metadata and how long it took to reach a rule match.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutcomeIngress {
+    pub metadata: IngressMetadata,
+    pub to_match_ns: u64,
+}
+
+impl SnipeOutcome {
+    pub fn new(
+        strategy: &'static str,
+        token: String,
+        matched_rule: &MatchedRule,
+        amount_lamports: u64,
+        min_amount_out: u64,
+        ingress: OutcomeIngress,
+    ) -> Self {
+        let OutcomeIngress {
+            metadata: ingress,
+            to_match_ns: ingress_to_match_ns,
+        } = ingress;
+        Self {
+            token,
+            strategy,
+            rule_source: matched_rule.source.as_str(),
+            rule_address: matched_rule.cold.address.as_str().to_owned(),
+            amount_lamports,
+            min_amount_out,
+            jito_tip_lamports: 0,
+            signature: None,
+            status: SnipeStatus::Pending,
+            error: None,
+            event_id: ingress.event_id,
+            ingress_source: ingress.source.as_str(),
+            ingress_timestamp_ns: ingress.normalized_timestamp_ns,
+            ingress_to_match_ns,
+            ingress_to_submit_ns: None,
+            creation_slot: ingress.slot,
+            creation_block_time: None,
+            block_time_to_ingress_ms: None,
+            recorded_at_ns: 0,
+            ingress,
+        }
+    }
+
+    /// Records the Jito tip the swap transaction actually transfers.
+    #[must_use]
+    pub const fn with_jito_tip(mut self, jito_tip_lamports: u64) -> Self {
+        self.jito_tip_lamports = jito_tip_lamports;
+        self
+    }
+
+    #[must_use]
+    pub fn with_signature(mut self, signature: &Signature) -> Self {
+        self.signature = Some(signature.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn with_submitted_now(mut self) -> Self {
+        self.ingress_to_submit_ns = Some(ingress_latency_ns(&self.ingress));
+        self
+    }
+
+    /// Attaches the block time of the creation slot and how long after it ingress saw the
+    /// transaction.
+    #[must_use]
+    pub fn with_creation_block_time(mut self, block_time_secs: i64) -> Self {
+        self.creation_block_time = Some(block_time_secs);
+        self.block_time_to_ingress_ms =
+            block_time_to_ingress_ms(block_time_secs, self.ingress.normalized_timestamp_ns);
+        self
+    }
+
+    #[must_use]
+    pub fn finish(mut self, status: SnipeStatus, error: Option<String>) -> Self {
+        self.status = status;
+        self.error = error;
+        self.recorded_at_ns = unix_timestamp_now_ns();
+        self
+    }
+
+    pub fn to_json_line(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self).map(|mut line| {
+            line.push('\n');
+            line
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SnipeOutcomeLogError {
+    #[error("failed to open snipe outcome log at {path}")]
+    Open {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Debug)]
+pub struct SnipeOutcomeLog {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl SnipeOutcomeLog {
+    pub async fn open(path: PathBuf) -> Result<Self, SnipeOutcomeLogError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .map_err(|source| SnipeOutcomeLogError::Open {
+                path: path.clone(),
+                source,
+            })?;
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    pub async fn append(&self, outcome: &SnipeOutcome) {
+        let line = match outcome.to_json_line() {
+            Ok(value) => value,
+            Err(error) => {
+                log::warn!(
+                    "Snipe outcome > {} > failed to serialize: {}",
+                    outcome.token,
+                    error
+                );
+                return;
+            }
+        };
+
+        let mut file = self.file.lock().await;
+        if let Err(error) = file.write_all(line.as_bytes()).await {
+            log::warn!(
+                "Snipe outcome > failed to append to {}: {}",
+                self.path.display(),
+                error
+            );
+        }
+    }
+}
+
+/// Ingress time minus the creation slot's block time. Block times have one-second
+/// resolution, so values under 1000 (or negative) only say "within the same second".
+pub fn block_time_to_ingress_ms(block_time_secs: i64, ingress_timestamp_ns: u64) -> Option<i64> {
+    let ingress_ms = i64::try_from(ingress_timestamp_ns / 1_000_000).ok()?;
+    ingress_ms.checked_sub(block_time_secs.checked_mul(1_000)?)
+}
+
+/// Block times are looked up only here, after the snipe has finished, so the extra RPC
+/// call stays off the submit path.
+pub async fn record_outcome(context: &ExecutionContext, outcome: SnipeOutcome) {
+    if let Some(rule_stats) = &context.rule_stats {
+        rule_stats.record_status(&outcome.rule_address, outcome.status);
+    }
+    let Some(outcome_log) = &context.outcome_log else {
+        return;
+    };
+
+    let timeout = context.rpc_call_timeout;
+    let outcome = match outcome.creation_slot {
+        Some(slot) => match with_rpc_timeout(timeout, context.rpc.get_block_time(slot)).await {
+            Ok(block_time) => {
+                let outcome = outcome.with_creation_block_time(block_time);
+                if let Some(delta_ms) = outcome.block_time_to_ingress_ms {
+                    log::info!(
+                        "{} > {} > Ingress {}ms after the block time of creation slot {}",
+                        outcome.strategy,
+                        outcome.token,
+                        delta_ms,
+                        slot
+                    );
+                }
+                outcome
+            }
+            Err(error) => {
+                log::debug!(
+                    "{} > {} > Block time for slot {} unavailable: {}",
+                    outcome.strategy,
+                    outcome.token,
+                    slot,
+                    error
+                );
+                outcome
+            }
+        },
+        None => outcome,
+    };
+    outcome_log.append(&outcome).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SnipeOutcome, SnipeStatus, block_time_to_ingress_ms};
+    use crate::domain::events::{IngressMetadata, IngressSource};
+
+    #[test]
+    fn serializes_outcome_as_single_json_line() {
+        let outcome = SnipeOutcome {
+            token: "Mint111".to_owned(),
+            strategy: "cpmm",
+            rule_source: "deployer",
+            rule_address: "Deployer111".to_owned(),
+            amount_lamports: 1_000_000_000,
+            min_amount_out: 42,
+            jito_tip_lamports: 1_000_000,
+            signature: Some("sig".to_owned()),
+            status: SnipeStatus::SubmitFailed,
+            error: Some("rpc unavailable".to_owned()),
+            event_id: 3,
+            ingress_source: "sof_grpc",
+            ingress_timestamp_ns: 10,
+            ingress_to_match_ns: 20,
+            ingress_to_submit_ns: None,
+            creation_slot: Some(7),
+            creation_block_time: None,
+            block_time_to_ingress_ms: None,
+            recorded_at_ns: 30,
+            ingress: IngressMetadata::from_receive_clock(IngressSource::Grpc, 10),
+        };
+
+        let line = outcome.to_json_line();
+        assert!(line.is_ok());
+        if let Ok(line) = line {
+            assert!(line.ends_with('\n'));
+            assert_eq!(line.matches('\n').count(), 1);
+
+            let parsed = serde_json::from_str::<serde_json::Value>(line.trim_end());
+            assert!(parsed.is_ok());
+            if let Ok(parsed) = parsed {
+                assert_eq!(
+                    parsed.get("strategy"),
+                    Some(&serde_json::Value::from("cpmm"))
+                );
+                assert_eq!(
+                    parsed.get("rule_source"),
+                    Some(&serde_json::Value::from("deployer"))
+                );
+                assert_eq!(
+                    parsed.get("status"),
+                    Some(&serde_json::Value::from("submit_failed"))
+                );
+                assert_eq!(
+                    parsed.get("min_amount_out"),
+                    Some(&serde_json::Value::from(42))
+                );
+                assert_eq!(
+                    parsed.get("ingress_to_submit_ns"),
+                    Some(&serde_json::Value::Null)
+                );
+                assert!(parsed.get("ingress").is_none());
+                assert_eq!(
+                    parsed.get("creation_slot"),
+                    Some(&serde_json::Value::from(7))
+                );
+                assert_eq!(parsed.get("event_id"), Some(&serde_json::Value::from(3)));
+            }
+        }
+    }
+
+    #[test]
+    fn measures_ingress_against_the_creation_block_time() {
+        let ingress = IngressMetadata::from_receive_clock(
+            IngressSource::PrivateShred,
+            1_700_000_000_250_000_000,
+        )
+        .with_slot(42);
+        let outcome = SnipeOutcome {
+            token: "Mint111".to_owned(),
+            strategy: "openbook",
+            rule_source: "mint",
+            rule_address: "Mint111".to_owned(),
+            amount_lamports: 1,
+            min_amount_out: 1,
+            jito_tip_lamports: 0,
+            signature: None,
+            status: SnipeStatus::Confirmed,
+            error: None,
+            event_id: ingress.event_id,
+            ingress_source: ingress.source.as_str(),
+            ingress_timestamp_ns: ingress.normalized_timestamp_ns,
+            ingress_to_match_ns: 0,
+            ingress_to_submit_ns: None,
+            creation_slot: ingress.slot,
+            creation_block_time: None,
+            block_time_to_ingress_ms: None,
+            recorded_at_ns: 0,
+            ingress,
+        }
+        .with_creation_block_time(1_700_000_000);
+
+        assert_eq!(outcome.creation_slot, Some(42));
+        assert_eq!(outcome.creation_block_time, Some(1_700_000_000));
+        assert_eq!(outcome.block_time_to_ingress_ms, Some(250));
+        assert_eq!(
+            block_time_to_ingress_ms(1_700_000_001, 1_700_000_000_250_000_000),
+            Some(-750)
+        );
+        assert_eq!(block_time_to_ingress_ms(i64::MAX, 0), None);
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::domain::value_objects::sol_amount::Lamports;
+
+/// Raydium's standard 0.25% trade fee, taken from the input side of every simulated swap.
+pub const PAPER_TRADE_FEE_BPS: u16 = 25;
+
+/// A simulated buy against a constant-product pool.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PaperFill {
+    pub lamports_in: u64,
+    pub tokens_out: u64,
+    /// What selling `tokens_out` straight back into the post-fill pool would return.
+    pub exit_lamports: u64,
+}
+
+/// `None` when a reserve is empty or the buy rounds down to no tokens.
+pub fn simulate_buy(lamports_in: u64, sol_reserve: u64, token_reserve: u64) -> Option<PaperFill> {
+    if sol_reserve == 0 || token_reserve == 0 {
+        return None;
+    }
+
+    let tokens_out = constant_product_out(lamports_in, sol_reserve, token_reserve)?;
+    if tokens_out == 0 {
+        return None;
+    }
+    let sol_after = sol_reserve.checked_add(lamports_in)?;
+    let token_after = token_reserve.checked_sub(tokens_out)?;
+
+    Some(PaperFill {
+        lamports_in,
+        tokens_out,
+        exit_lamports: constant_product_out(tokens_out, token_after, sol_after)?,
+    })
+}
+
+fn constant_product_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Option<u64> {
+    let fee_adjusted_in = u128::from(amount_in)
+        .checked_mul(10_000_u128.saturating_sub(u128::from(PAPER_TRADE_FEE_BPS)))?
+        / 10_000;
+    let numerator = fee_adjusted_in.checked_mul(u128::from(reserve_out))?;
+    let denominator = u128::from(reserve_in).checked_add(fee_adjusted_in)?;
+    u64::try_from(numerator.checked_div(denominator)?).ok()
+}
+
+/// Virtual wallet for `runtime.paper_trading`: simulated buys spend virtual SOL and open
+/// positions valued at their immediate exit price.
+#[derive(Debug)]
+pub struct PaperPortfolio {
+    inner: Mutex<PaperBook>,
+}
+
+#[derive(Debug)]
+struct PaperBook {
+    starting_lamports: u64,
+    sol_lamports: u64,
+    positions: HashMap<Pubkey, PaperPosition>,
+    fills: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PaperPosition {
+    pub tokens: u64,
+    pub cost_lamports: u64,
+    pub exit_lamports: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PaperSnapshot {
+    pub starting_lamports: u64,
+    pub sol_lamports: u64,
+    pub cost_lamports: u64,
+    pub exit_lamports: u64,
+    pub positions: usize,
+    pub fills: u64,
+}
+
+impl PaperSnapshot {
+    /// Virtual SOL plus the exit value of every open position.
+    pub const fn equity_lamports(&self) -> u64 {
+        self.sol_lamports.saturating_add(self.exit_lamports)
+    }
+
+    /// Signed SOL change in equity since startup, e.g. `-0.25`.
+    pub fn pnl_sol_string(&self) -> String {
+        let equity = self.equity_lamports();
+        let sign = if equity < self.starting_lamports {
+            "-"
+        } else {
+            "+"
+        };
+        let change = equity.abs_diff(self.starting_lamports);
+        format!("{}{}", sign, Lamports::new(change).as_sol_string())
+    }
+}
+
+impl PaperPortfolio {
+    pub fn new(starting_lamports: u64) -> Self {
+        Self {
+            inner: Mutex::new(PaperBook {
+                starting_lamports,
+                sol_lamports: starting_lamports,
+                positions: HashMap::new(),
+                fills: 0,
+            }),
+        }
+    }
+
+    /// Books `fill` plus any tip paid alongside it. `None`, with nothing booked, when the
+    /// virtual SOL balance cannot cover both.
+    pub fn record_buy(
+        &self,
+        token: Pubkey,
+        fill: PaperFill,
+        tip_lamports: u64,
+    ) -> Option<PaperSnapshot> {
+        let cost = fill.lamports_in.checked_add(tip_lamports)?;
+        let mut book = self.lock_inner();
+        book.sol_lamports = book.sol_lamports.checked_sub(cost)?;
+        let position = book.positions.entry(token).or_default();
+        position.tokens = position.tokens.saturating_add(fill.tokens_out);
+        position.cost_lamports = position.cost_lamports.saturating_add(cost);
+        position.exit_lamports = position.exit_lamports.saturating_add(fill.exit_lamports);
+        book.fills = book.fills.saturating_add(1);
+        Some(book.snapshot())
+    }
+
+    pub fn position(&self, token: &Pubkey) -> Option<PaperPosition> {
+        self.lock_inner().positions.get(token).copied()
+    }
+
+    pub fn snapshot(&self) -> PaperSnapshot {
+        self.lock_inner().snapshot()
+    }
+
+    pub fn log_report(&self) {
+        let snapshot = self.snapshot();
+        log::info!(
+            "Paper portfolio > fills={} positions={} sol={} SOL cost={} SOL exit_value={} SOL pnl={} SOL",
+            snapshot.fills,
+            snapshot.positions,
+            Lamports::new(snapshot.sol_lamports).as_sol_string(),
+            Lamports::new(snapshot.cost_lamports).as_sol_string(),
+            Lamports::new(snapshot.exit_lamports).as_sol_string(),
+            snapshot.pnl_sol_string(),
+        );
+    }
+
+    fn lock_inner(&self) -> MutexGuard<'_, PaperBook> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl PaperBook {
+    fn snapshot(&self) -> PaperSnapshot {
+        let (cost_lamports, exit_lamports) =
+            self.positions
+                .values()
+                .fold((0_u64, 0_u64), |(cost, exit), position| {
+                    (
+                        cost.saturating_add(position.cost_lamports),
+                        exit.saturating_add(position.exit_lamports),
+                    )
+                });
+        PaperSnapshot {
+            starting_lamports: self.starting_lamports,
+            sol_lamports: self.sol_lamports,
+            cost_lamports,
+            exit_lamports,
+            positions: self.positions.len(),
+            fills: self.fills,
+        }
+    }
+}
+
+/// The swap a paper buy simulates: its SOL input and tip, against the pool's reserves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PaperBuy {
+    pub lamports_in: u64,
+    pub tip_lamports: u64,
+    pub sol_reserve: u64,
+    pub token_reserve: u64,
+}
+
+/// Paper-mode stand-in for submission: simulates the buy and logs the running P&L.
+pub fn book_paper_buy(
+    portfolio: Option<&PaperPortfolio>,
+    strategy: &str,
+    token: Pubkey,
+    buy: PaperBuy,
+) {
+    let Some(portfolio) = portfolio else {
+        return;
+    };
+    let PaperBuy {
+        lamports_in,
+        tip_lamports,
+        sol_reserve,
+        token_reserve,
+    } = buy;
+    let Some(fill) = simulate_buy(lamports_in, sol_reserve, token_reserve) else {
+        log::warn!(
+            "{} > {} > Paper fill skipped: pool reserves sol={} token={} cannot fill {} SOL",
+            strategy,
+            token,
+            sol_reserve,
+            token_reserve,
+            Lamports::new(lamports_in).as_sol_string()
+        );
+        return;
+    };
+    let Some(snapshot) = portfolio.record_buy(token, fill, tip_lamports) else {
+        log::warn!(
+            "{} > {} > Paper fill skipped: virtual balance {} SOL cannot cover it",
+            strategy,
+            token,
+            Lamports::new(portfolio.snapshot().sol_lamports).as_sol_string()
+        );
+        return;
+    };
+
+    log::info!(
+        "{} > {} > Paper fill: {} SOL -> {} tokens (exit value {} SOL); virtual pnl={} SOL",
+        strategy,
+        token,
+        Lamports::new(fill.lamports_in).as_sol_string(),
+        fill.tokens_out,
+        Lamports::new(fill.exit_lamports).as_sol_string(),
+        snapshot.pnl_sol_string()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::{PaperPortfolio, simulate_buy};
+
+    #[test]
+    fn simulates_a_constant_product_buy_with_fees() {
+        let fill = simulate_buy(1_000_000_000, 10_000_000_000, 1_000_000);
+        assert!(fill.is_some());
+        if let Some(fill) = fill {
+            // 0.9975 SOL in after fees: 997_500_000 * 1_000_000 / 10_997_500_000.
+            assert_eq!(fill.tokens_out, 90_702);
+            // Selling 90_702 back into an 11 SOL / 909_298 token pool, fees again.
+            assert_eq!(fill.exit_lamports, 995_450_967);
+            assert!(fill.exit_lamports < fill.lamports_in);
+        }
+
+        assert_eq!(simulate_buy(1, 0, 1_000), None);
+        assert_eq!(simulate_buy(1, 10_000_000_000, 1_000_000), None);
+    }
+
+    #[test]
+    fn tracks_virtual_balance_and_pnl() {
+        let portfolio = PaperPortfolio::new(2_000_000_000);
+        let token = Pubkey::new_unique();
+        let fill = simulate_buy(1_000_000_000, 10_000_000_000, 1_000_000);
+        assert!(fill.is_some());
+        if let Some(fill) = fill {
+            let snapshot = portfolio.record_buy(token, fill, 10_000_000);
+            assert!(snapshot.is_some());
+            if let Some(snapshot) = snapshot {
+                assert_eq!(snapshot.sol_lamports, 990_000_000);
+                assert_eq!(snapshot.cost_lamports, 1_010_000_000);
+                assert_eq!(snapshot.equity_lamports(), 1_985_450_967);
+                assert_eq!(snapshot.pnl_sol_string(), "-0.014549033");
+            }
+            assert_eq!(
+                portfolio.position(&token).map(|position| position.tokens),
+                Some(90_702)
+            );
+
+            assert_eq!(portfolio.record_buy(token, fill, 0), None);
+            assert_eq!(portfolio.snapshot().fills, 1);
+        }
+    }
+}
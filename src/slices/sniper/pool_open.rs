@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoolOpenWait {
+    Open,
+    Sleep(Duration),
+    Abandon(Duration),
+}
+
+/// Time left until `open_timestamp` (unix seconds), or `None` once the pool is open.
+pub fn time_until_open(open_timestamp: i64, now: DateTime<Utc>) -> Option<Duration> {
+    let open_at = Utc.timestamp_opt(open_timestamp, 0).single()?;
+    open_at
+        .signed_duration_since(now)
+        .to_std()
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
+
+/// `pre_open_offset` wakes up that much before the open time; a wait shorter than the
+/// offset sends immediately.
+pub fn plan_pool_open_wait(
+    open_timestamp: i64,
+    now: DateTime<Utc>,
+    max_wait: Option<Duration>,
+    pre_open_offset: Duration,
+) -> PoolOpenWait {
+    let Some(remaining) = time_until_open(open_timestamp, now) else {
+        return PoolOpenWait::Open;
+    };
+
+    if max_wait.is_some_and(|max_wait| remaining > max_wait) {
+        return PoolOpenWait::Abandon(remaining);
+    }
+
+    let sleep = remaining.saturating_sub(pre_open_offset);
+    if sleep.is_zero() {
+        return PoolOpenWait::Open;
+    }
+
+    PoolOpenWait::Sleep(sleep)
+}
+
+/// Sleeps until the pool opens. Returns `false`, without sleeping, when the open is
+/// further out than `max_wait` and the snipe should be abandoned.
+pub async fn wait_for_pool_open(
+    open_timestamp: i64,
+    max_wait: Option<Duration>,
+    pre_open_offset: Duration,
+    token_address: &str,
+    label: &str,
+) -> bool {
+    match plan_pool_open_wait(open_timestamp, Utc::now(), max_wait, pre_open_offset) {
+        PoolOpenWait::Open => true,
+        PoolOpenWait::Abandon(remaining) => {
+            log::warn!(
+                "{} > {} > Pool opens in {}s, beyond the {}s wait limit. Abandoning snipe",
+                label,
+                token_address,
+                remaining.as_secs(),
+                max_wait.map_or(0, |max_wait| max_wait.as_secs()),
+            );
+            false
+        }
+        PoolOpenWait::Sleep(remaining) => {
+            let remaining_secs = remaining.as_secs();
+            log::info!(
+                "{} > {} > Pool closed. Proceeding with snipe in {}m {}s. UTC: {}",
+                label,
+                token_address,
+                remaining_secs.saturating_div(60),
+                remaining_secs.checked_rem(60).unwrap_or(0),
+                Utc.timestamp_opt(open_timestamp, 0)
+                    .single()
+                    .map_or_else(String::new, |open_at| open_at.to_rfc2822()),
+            );
+            tokio::time::sleep(remaining).await;
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::{PoolOpenWait, plan_pool_open_wait, time_until_open};
+
+    #[test]
+    fn reports_time_until_open() {
+        let now = Utc.timestamp_opt(1_000, 0).single();
+        assert!(now.is_some());
+        if let Some(now) = now {
+            assert_eq!(time_until_open(1_090, now), Some(Duration::from_secs(90)));
+            assert_eq!(time_until_open(1_000, now), None);
+            assert_eq!(time_until_open(10, now), None);
+        }
+    }
+
+    #[test]
+    fn abandons_pools_opening_beyond_max_wait() {
+        let now = Utc.timestamp_opt(1_000, 0).single();
+        assert!(now.is_some());
+        if let Some(now) = now {
+            let max_wait = Some(Duration::from_secs(60));
+            assert_eq!(
+                plan_pool_open_wait(1_030, now, max_wait, Duration::ZERO),
+                PoolOpenWait::Sleep(Duration::from_secs(30))
+            );
+            assert_eq!(
+                plan_pool_open_wait(4_600, now, max_wait, Duration::ZERO),
+                PoolOpenWait::Abandon(Duration::from_secs(3_600))
+            );
+            assert_eq!(
+                plan_pool_open_wait(4_600, now, None, Duration::ZERO),
+                PoolOpenWait::Sleep(Duration::from_secs(3_600))
+            );
+            assert_eq!(
+                plan_pool_open_wait(900, now, max_wait, Duration::ZERO),
+                PoolOpenWait::Open
+            );
+        }
+    }
+
+    #[test]
+    fn pre_open_offset_shortens_the_wait_and_clamps_at_zero() {
+        let now = Utc.timestamp_opt(1_000, 0).single();
+        assert!(now.is_some());
+        if let Some(now) = now {
+            assert_eq!(
+                plan_pool_open_wait(1_030, now, None, Duration::from_millis(400)),
+                PoolOpenWait::Sleep(Duration::from_millis(29_600))
+            );
+            assert_eq!(
+                plan_pool_open_wait(1_001, now, None, Duration::from_secs(5)),
+                PoolOpenWait::Open
+            );
+            assert_eq!(
+                plan_pool_open_wait(1_001, now, None, Duration::from_secs(1)),
+                PoolOpenWait::Open
+            );
+        }
+    }
+}
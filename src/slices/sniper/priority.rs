@@ -0,0 +1,192 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use crate::domain::value_objects::EventQueueDropPolicy;
+
+/// Staged items released highest priority first, then earliest ingress timestamp, then
+/// arrival order.
+#[derive(Debug)]
+pub struct DispatchQueue<T> {
+    heap: BinaryHeap<Staged<T>>,
+    next_sequence: u64,
+}
+
+#[derive(Debug)]
+struct Staged<T> {
+    priority: u8,
+    ingress_timestamp_ns: u64,
+    sequence: u64,
+    item: T,
+}
+
+impl<T> Staged<T> {
+    fn cmp_key(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.ingress_timestamp_ns.cmp(&self.ingress_timestamp_ns))
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<T> PartialEq for Staged<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp_key(other) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Staged<T> {}
+
+impl<T> PartialOrd for Staged<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Staged<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_key(other)
+    }
+}
+
+impl<T> DispatchQueue<T> {
+    pub const fn new() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+            next_sequence: 0,
+        }
+    }
+
+    pub fn push(&mut self, priority: u8, ingress_timestamp_ns: u64, item: T) {
+        self.heap.push(Staged {
+            priority,
+            ingress_timestamp_ns,
+            sequence: self.next_sequence,
+            item,
+        });
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|staged| staged.item)
+    }
+
+    /// Drops staged items past `capacity`, lowest priority first. Within a priority level the
+    /// event queue's drop policy picks: the latest arrivals for `DropNewest`, the earliest
+    /// ingress timestamps for `DropOldest`. Returns how many were dropped.
+    pub fn shed_to(&mut self, capacity: usize, drop_policy: EventQueueDropPolicy) -> usize {
+        let excess = self.heap.len().saturating_sub(capacity);
+        if excess == 0 {
+            return 0;
+        }
+
+        let mut staged = std::mem::take(&mut self.heap).into_vec();
+        match drop_policy {
+            EventQueueDropPolicy::DropNewest => {
+                staged.sort_unstable_by_key(|item| (Reverse(item.priority), item.sequence));
+            }
+            EventQueueDropPolicy::DropOldest => staged.sort_unstable_by_key(|item| {
+                (
+                    Reverse(item.priority),
+                    Reverse((item.ingress_timestamp_ns, item.sequence)),
+                )
+            }),
+        }
+        staged.truncate(capacity);
+        self.heap = BinaryHeap::from(staged);
+        excess
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+impl<T> Default for DispatchQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DispatchQueue;
+    use crate::domain::value_objects::EventQueueDropPolicy;
+
+    #[test]
+    fn releases_higher_priority_first() {
+        let mut queue = DispatchQueue::new();
+        queue.push(0, 1, "catch-all");
+        queue.push(5, 3, "watched mint");
+        queue.push(2, 2, "deployer");
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop(), Some("watched mint"));
+        assert_eq!(queue.pop(), Some("deployer"));
+        assert_eq!(queue.pop(), Some("catch-all"));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn breaks_ties_by_ingress_timestamp_then_arrival() {
+        let mut queue = DispatchQueue::new();
+        queue.push(1, 30, "late");
+        queue.push(1, 10, "early");
+        queue.push(1, 20, "middle first");
+        queue.push(1, 20, "middle second");
+
+        assert_eq!(queue.pop(), Some("early"));
+        assert_eq!(queue.pop(), Some("middle first"));
+        assert_eq!(queue.pop(), Some("middle second"));
+        assert_eq!(queue.pop(), Some("late"));
+    }
+
+    #[test]
+    fn sheds_staged_items_by_drop_policy() {
+        let mut newest = DispatchQueue::new();
+        newest.push(0, 10, "low first");
+        newest.push(9, 5, "high early");
+        newest.push(0, 20, "low second");
+        newest.push(9, 40, "high late");
+        assert_eq!(newest.shed_to(3, EventQueueDropPolicy::DropNewest), 1);
+        assert_eq!(newest.pop(), Some("high early"));
+        assert_eq!(newest.pop(), Some("high late"));
+        assert_eq!(newest.pop(), Some("low first"));
+        assert_eq!(newest.pop(), None);
+
+        let mut oldest = DispatchQueue::new();
+        oldest.push(0, 10, "low first");
+        oldest.push(9, 5, "high early");
+        oldest.push(0, 20, "low second");
+        oldest.push(9, 40, "high late");
+        assert_eq!(oldest.shed_to(3, EventQueueDropPolicy::DropOldest), 1);
+        assert_eq!(oldest.shed_to(3, EventQueueDropPolicy::DropOldest), 0);
+        assert_eq!(oldest.pop(), Some("high early"));
+        assert_eq!(oldest.pop(), Some("high late"));
+        assert_eq!(oldest.pop(), Some("low second"));
+        assert_eq!(oldest.pop(), None);
+    }
+
+    #[test]
+    fn sheds_lower_priority_items_before_either_policy_applies() {
+        for drop_policy in [
+            EventQueueDropPolicy::DropNewest,
+            EventQueueDropPolicy::DropOldest,
+        ] {
+            let mut queue = DispatchQueue::new();
+            queue.push(9, 5, "high oldest");
+            queue.push(1, 20, "low");
+            queue.push(9, 30, "high newest");
+            assert_eq!(queue.shed_to(2, drop_policy), 1);
+            assert_eq!(queue.pop(), Some("high oldest"));
+            assert_eq!(queue.pop(), Some("high newest"));
+            assert_eq!(queue.pop(), None);
+        }
+    }
+}
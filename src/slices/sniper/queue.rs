@@ -0,0 +1,239 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+};
+
+use tokio::sync::Notify;
+
+use crate::domain::{events::SniperInputEvent, value_objects::EventQueueDropPolicy};
+
+use super::telemetry::LatencyTelemetry;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QueuePushOutcome {
+    Enqueued,
+    DroppedNewest,
+    DroppedOldest,
+    Closed,
+}
+
+impl QueuePushOutcome {
+    #[inline(always)]
+    pub const fn dropped_event(self) -> bool {
+        matches!(self, Self::DroppedNewest | Self::DroppedOldest)
+    }
+}
+
+/// Why the owner closed the queue; a plain `close` leaves it unset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QueueCloseReason {
+    Shutdown,
+    IngressFailed,
+}
+
+#[derive(Debug)]
+pub struct SniperEventQueue {
+    capacity: usize,
+    drop_policy: EventQueueDropPolicy,
+    events: Mutex<VecDeque<SniperInputEvent>>,
+    notify: Notify,
+    closed: AtomicBool,
+    close_reason: Mutex<Option<QueueCloseReason>>,
+    dropped_events: AtomicU64,
+    telemetry: Arc<LatencyTelemetry>,
+}
+
+impl SniperEventQueue {
+    pub fn new(
+        capacity: usize,
+        drop_policy: EventQueueDropPolicy,
+        telemetry: Arc<LatencyTelemetry>,
+    ) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            drop_policy,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+            notify: Notify::new(),
+            closed: AtomicBool::new(false),
+            close_reason: Mutex::new(None),
+            dropped_events: AtomicU64::new(0),
+            telemetry,
+        }
+    }
+
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub const fn drop_policy(&self) -> EventQueueDropPolicy {
+        self.drop_policy
+    }
+
+    pub fn push(&self, event: SniperInputEvent) -> QueuePushOutcome {
+        if self.closed.load(Ordering::Acquire) {
+            return QueuePushOutcome::Closed;
+        }
+
+        let outcome = {
+            let mut events = self.lock_events();
+            if events.len() < self.capacity {
+                events.push_back(event);
+                QueuePushOutcome::Enqueued
+            } else {
+                match self.drop_policy {
+                    EventQueueDropPolicy::DropNewest => QueuePushOutcome::DroppedNewest,
+                    EventQueueDropPolicy::DropOldest => {
+                        let _oldest = events.pop_front();
+                        events.push_back(event);
+                        QueuePushOutcome::DroppedOldest
+                    }
+                }
+            }
+        };
+
+        if outcome.dropped_event() {
+            self.record_dropped(1);
+        }
+        if outcome != QueuePushOutcome::DroppedNewest {
+            self.notify.notify_one();
+        }
+
+        outcome
+    }
+
+    pub async fn recv(&self) -> Option<SniperInputEvent> {
+        loop {
+            if let Some(event) = self.try_recv() {
+                return Some(event);
+            }
+            if self.closed.load(Ordering::Acquire) {
+                return self.try_recv();
+            }
+
+            self.notify.notified().await;
+        }
+    }
+
+    pub fn try_recv(&self) -> Option<SniperInputEvent> {
+        self.lock_events().pop_front()
+    }
+
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.notify.notify_one();
+    }
+
+    pub fn close_with(&self, reason: QueueCloseReason) {
+        *self
+            .close_reason
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(reason);
+        self.close();
+    }
+
+    pub fn close_reason(&self) -> Option<QueueCloseReason> {
+        *self
+            .close_reason
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Counts events dropped after they left the queue, e.g. shed by the engine's staging.
+    pub fn record_dropped(&self, dropped: usize) {
+        for _ in 0..dropped {
+            self.dropped_events.fetch_add(1, Ordering::Relaxed);
+            self.telemetry.record_event_queue_drop();
+        }
+    }
+
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped_events.load(Ordering::Relaxed)
+    }
+
+    fn lock_events(&self) -> MutexGuard<'_, VecDeque<SniperInputEvent>> {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use solana_sdk::{
+        message::Message,
+        pubkey::Pubkey,
+        transaction::{Transaction, VersionedTransaction},
+    };
+
+    use super::{QueuePushOutcome, SniperEventQueue};
+    use crate::{
+        domain::{
+            events::{
+                IngressMetadata, IngressSource, RaydiumCandidateEvent, RaydiumCandidateKind,
+                SniperInputEvent,
+            },
+            value_objects::EventQueueDropPolicy,
+        },
+        slices::sniper::telemetry::LatencyTelemetry,
+    };
+
+    #[test]
+    fn drop_newest_keeps_queued_events() {
+        let queue = queue(EventQueueDropPolicy::DropNewest);
+        assert_eq!(queue.push(event(1)), QueuePushOutcome::Enqueued);
+        assert_eq!(queue.push(event(2)), QueuePushOutcome::DroppedNewest);
+
+        assert_eq!(queue.dropped_events(), 1);
+        assert_eq!(queue.try_recv().as_ref().map(received_ns), Some(1));
+        assert!(queue.try_recv().is_none());
+    }
+
+    #[test]
+    fn drop_oldest_keeps_latest_event() {
+        let queue = queue(EventQueueDropPolicy::DropOldest);
+        assert_eq!(queue.push(event(1)), QueuePushOutcome::Enqueued);
+        assert_eq!(queue.push(event(2)), QueuePushOutcome::DroppedOldest);
+
+        assert_eq!(queue.dropped_events(), 1);
+        assert_eq!(queue.try_recv().as_ref().map(received_ns), Some(2));
+        assert!(queue.try_recv().is_none());
+    }
+
+    #[test]
+    fn rejects_events_after_close() {
+        let queue = queue(EventQueueDropPolicy::DropNewest);
+        queue.close();
+
+        assert_eq!(queue.push(event(1)), QueuePushOutcome::Closed);
+        assert_eq!(queue.dropped_events(), 0);
+    }
+
+    fn queue(drop_policy: EventQueueDropPolicy) -> SniperEventQueue {
+        SniperEventQueue::new(1, drop_policy, Arc::new(LatencyTelemetry::disabled()))
+    }
+
+    fn event(received_timestamp_ns: u64) -> SniperInputEvent {
+        let payer = Pubkey::new_unique();
+        let transaction =
+            VersionedTransaction::from(Transaction::new_unsigned(Message::new(&[], Some(&payer))));
+
+        SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+            kind: RaydiumCandidateKind::Cpmm,
+            transaction: Arc::new(transaction),
+            ingress: IngressMetadata::from_receive_clock(
+                IngressSource::Websocket,
+                received_timestamp_ns,
+            ),
+        })
+    }
+
+    fn received_ns(event: &SniperInputEvent) -> u64 {
+        match event {
+            SniperInputEvent::RaydiumCandidate(event) => event.ingress.received_timestamp_ns,
+        }
+    }
+}
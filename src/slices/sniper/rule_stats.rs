@@ -0,0 +1,174 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use tokio::{
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
+};
+
+use crate::{domain::value_objects::RuleAddress, slices::sniper::outcome::SnipeStatus};
+
+/// How far each rule got with the candidates it matched.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RuleCounters {
+    pub matched: u64,
+    /// Swaps built and sent, or simulated in dry-run and paper modes.
+    pub fired: u64,
+    pub confirmed: u64,
+    /// Fired swaps that failed to send, failed on chain or never confirmed.
+    pub failed: u64,
+}
+
+/// Per-rule match and fire counts for `runtime.rule_stats_report_secs`, keyed by rule
+/// address; the deployer catch-all counts under `*`.
+#[derive(Debug, Default)]
+pub struct RuleStats {
+    counters: Mutex<HashMap<RuleAddress, RuleCounters>>,
+}
+
+impl RuleStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_match(&self, rule: &RuleAddress) {
+        let mut counters = self.lock_counters();
+        let entry = counters.entry(rule.clone()).or_default();
+        entry.matched = entry.matched.saturating_add(1);
+    }
+
+    /// Counts the final status of a snipe from a rule already recorded by `record_match`.
+    pub fn record_status(&self, rule_address: &str, status: SnipeStatus) {
+        let mut counters = self.lock_counters();
+        let Some(entry) = counters.get_mut(rule_address) else {
+            return;
+        };
+        match status {
+            SnipeStatus::Pending | SnipeStatus::Skipped | SnipeStatus::BuildFailed => {}
+            SnipeStatus::DryRun | SnipeStatus::Paper => {
+                entry.fired = entry.fired.saturating_add(1);
+            }
+            SnipeStatus::Confirmed => {
+                entry.fired = entry.fired.saturating_add(1);
+                entry.confirmed = entry.confirmed.saturating_add(1);
+            }
+            SnipeStatus::SubmitFailed | SnipeStatus::Unconfirmed | SnipeStatus::Failed => {
+                entry.fired = entry.fired.saturating_add(1);
+                entry.failed = entry.failed.saturating_add(1);
+            }
+        }
+    }
+
+    pub fn counters(&self, rule_address: &str) -> RuleCounters {
+        self.lock_counters()
+            .get(rule_address)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Every rule that has matched at least once, ordered by address.
+    pub fn snapshot(&self) -> Vec<(RuleAddress, RuleCounters)> {
+        let mut rules = self
+            .lock_counters()
+            .iter()
+            .map(|(rule, counters)| (rule.clone(), *counters))
+            .collect::<Vec<_>>();
+        rules.sort_by(|left, right| left.0.cmp(&right.0));
+        rules
+    }
+
+    #[must_use]
+    pub fn spawn_reporter(self: Arc<Self>, period: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = interval(period);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                self.log_report();
+            }
+        })
+    }
+
+    pub fn log_report(&self) {
+        let rules = self.snapshot();
+        if rules.is_empty() {
+            log::info!("Rule stats > no rule has matched yet");
+            return;
+        }
+        for (rule, counters) in rules {
+            log::info!(
+                "Rule stats > rule={} matched={} fired={} confirmed={} failed={}",
+                rule,
+                counters.matched,
+                counters.fired,
+                counters.confirmed,
+                counters.failed
+            );
+        }
+    }
+
+    fn lock_counters(&self) -> MutexGuard<'_, HashMap<RuleAddress, RuleCounters>> {
+        self.counters.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RuleCounters, RuleStats};
+    use crate::{domain::value_objects::RuleAddress, slices::sniper::outcome::SnipeStatus};
+
+    #[test]
+    fn counts_matches_and_fired_snipes_per_rule() {
+        let stats = RuleStats::new();
+        let mint = RuleAddress::try_from("So11111111111111111111111111111111111111112");
+        let catch_all = RuleAddress::try_from(RuleAddress::CATCH_ALL);
+        assert!(mint.is_ok() && catch_all.is_ok());
+
+        if let (Ok(mint), Ok(catch_all)) = (mint, catch_all) {
+            stats.record_match(&mint);
+            assert_eq!(stats.counters(mint.as_str()).matched, 1);
+
+            stats.record_status(mint.as_str(), SnipeStatus::Confirmed);
+            stats.record_match(&mint);
+            stats.record_status(mint.as_str(), SnipeStatus::Unconfirmed);
+            stats.record_match(&catch_all);
+            stats.record_status(catch_all.as_str(), SnipeStatus::Skipped);
+
+            assert_eq!(
+                stats.counters(mint.as_str()),
+                RuleCounters {
+                    matched: 2,
+                    fired: 2,
+                    confirmed: 1,
+                    failed: 1,
+                }
+            );
+            assert_eq!(
+                stats.counters(RuleAddress::CATCH_ALL),
+                RuleCounters {
+                    matched: 1,
+                    ..RuleCounters::default()
+                }
+            );
+            assert_eq!(
+                stats
+                    .snapshot()
+                    .iter()
+                    .map(|(rule, _counters)| rule.as_str())
+                    .collect::<Vec<_>>(),
+                vec![RuleAddress::CATCH_ALL, mint.as_str()]
+            );
+        }
+    }
+
+    #[test]
+    fn ignores_statuses_for_rules_that_never_matched() {
+        let stats = RuleStats::new();
+        stats.record_status("unknown", SnipeStatus::DryRun);
+        assert!(stats.snapshot().is_empty());
+    }
+}
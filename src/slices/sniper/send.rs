@@ -0,0 +1,289 @@
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signer::Signer};
+
+use crate::{
+    adapters::rpc_retry::with_rpc_timeout,
+    app::context::ExecutionContext,
+    domain::value_objects::{ExecutionMode, sol_amount::Lamports},
+    slices::sniper::{
+        confirmation::await_confirmation,
+        error::SnipeErrorKind,
+        outcome::{SnipeOutcome, SnipeStatus, record_outcome},
+        paper::{PaperBuy, book_paper_buy},
+        slippage::SlippageEscalation,
+        tx::{
+            WsolAccount, build_swap_transaction, close_wsol_account_after_swap,
+            compiled_instruction_index, ensure_transaction_fits, log_transaction_dump,
+        },
+    },
+};
+
+/// A strategy's built swap, ready for the shared send/confirm loop.
+pub struct SwapSend<F> {
+    pub label: &'static str,
+    pub token_address: Pubkey,
+    pub swap_program: Pubkey,
+    pub instructions: Vec<Instruction>,
+    /// Position of the swap in `instructions`, rewritten by `widen_swap` before a resend.
+    pub swap_instruction_index: usize,
+    /// The swap program's `ExceededSlippage` code.
+    pub exceeded_slippage_code: u32,
+    pub priority_fees: u64,
+    pub escalation: SlippageEscalation,
+    pub wsol_account: WsolAccount,
+    /// SOL input and the tip actually paid, also what paper mode simulates.
+    pub buy: PaperBuy,
+    /// Rewrites the swap instruction for the given slippage and returns its new `min_amount_out`.
+    pub widen_swap: F,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Settled {
+    Confirmed,
+    NotSubmitted,
+}
+
+/// Builds, sends and confirms the swap, resending with escalated slippage while the
+/// rule allows. The outcome is recorded once, after the last attempt, whichever way
+/// the snipe ends.
+pub async fn send_swap<F>(
+    context: &ExecutionContext,
+    mut swap: SwapSend<F>,
+    outcome: SnipeOutcome,
+) -> Result<(), SnipeErrorKind>
+where
+    F: FnMut(&mut Instruction, u16) -> Result<u64, SnipeErrorKind>,
+{
+    let (outcome, settled) = settle_swap(context, &mut swap, outcome).await;
+    record_outcome(context, outcome).await;
+    if settled? == Settled::NotSubmitted {
+        return Ok(());
+    }
+
+    let label = swap.label;
+    let token_address = swap.token_address;
+    close_wsol_account_after_swap(
+        context,
+        &swap.wsol_account,
+        token_address.to_string().as_str(),
+        label,
+    )
+    .await;
+
+    let balance = match with_rpc_timeout(
+        context.rpc_call_timeout,
+        context.rpc.get_balance(&context.keypair.pubkey()),
+    )
+    .await
+    {
+        Ok(value) => value,
+        Err(error) => {
+            log::error!(
+                "{} > {} > Failed to fetch balance: {}",
+                label,
+                token_address,
+                error
+            );
+            return Ok(());
+        }
+    };
+    if let Some(ledger) = &context.session_ledger {
+        let _snapshot = ledger.record_swap_balance(balance);
+    }
+
+    log::info!(
+        "{} > {} > Successfully swapped {} SOL with {} SOL Jito tip (mode={})",
+        label,
+        token_address,
+        Lamports::new(swap.buy.lamports_in).as_sol_string(),
+        Lamports::new(swap.buy.tip_lamports).as_sol_string(),
+        context.tx_submission_mode.as_str(),
+    );
+    log::info!(
+        "{} > {} > Balance: {} SOL",
+        label,
+        token_address,
+        Lamports::new(balance).as_sol_string()
+    );
+    Ok(())
+}
+
+/// The send/confirm loop; returns the finished outcome alongside the result so the caller
+/// records it exactly once.
+async fn settle_swap<F>(
+    context: &ExecutionContext,
+    swap: &mut SwapSend<F>,
+    mut outcome: SnipeOutcome,
+) -> (SnipeOutcome, Result<Settled, SnipeErrorKind>)
+where
+    F: FnMut(&mut Instruction, u16) -> Result<u64, SnipeErrorKind>,
+{
+    let label = swap.label;
+    let token_address = swap.token_address;
+    let token_address_text = token_address.to_string();
+
+    loop {
+        let blockhash = match context.latest_swap_blockhash().await {
+            Ok(value) => value,
+            Err(error) => {
+                return (
+                    outcome.finish(SnipeStatus::BuildFailed, Some(error.clone())),
+                    Err(SnipeErrorKind::Blockhash { detail: error }),
+                );
+            }
+        };
+
+        let swap_tx = match build_swap_transaction(
+            context,
+            swap.instructions.clone(),
+            blockhash,
+            swap.priority_fees,
+        ) {
+            Ok(value) => value,
+            Err(error) => {
+                return (
+                    outcome.finish(SnipeStatus::BuildFailed, Some(error.clone())),
+                    Err(SnipeErrorKind::BuildTransaction { detail: error }),
+                );
+            }
+        };
+        log_transaction_dump(label, token_address_text.as_str(), &swap_tx);
+        if let Err(error) = ensure_transaction_fits(&swap_tx) {
+            return (
+                outcome.finish(SnipeStatus::BuildFailed, Some(error.to_string())),
+                Err(error),
+            );
+        }
+
+        let swap_signature = swap_tx.signatures.first().copied().unwrap_or_default();
+
+        match context.execution_mode {
+            ExecutionMode::Live => {}
+            ExecutionMode::DryRun => {
+                log::info!(
+                    "{} > {} > Dry run built swap transaction: {} (submission skipped)",
+                    label,
+                    token_address,
+                    swap_signature
+                );
+                return (
+                    outcome
+                        .with_signature(&swap_signature)
+                        .finish(SnipeStatus::DryRun, None),
+                    Ok(Settled::NotSubmitted),
+                );
+            }
+            ExecutionMode::Paper => {
+                book_paper_buy(
+                    context.paper_portfolio.as_deref(),
+                    label,
+                    token_address,
+                    swap.buy,
+                );
+                return (
+                    outcome
+                        .with_signature(&swap_signature)
+                        .finish(SnipeStatus::Paper, None),
+                    Ok(Settled::NotSubmitted),
+                );
+            }
+        }
+
+        log::info!("{} > {} > Starting swap", label, token_address);
+
+        let sent_signature = match context
+            .submitter
+            .submit(&swap_tx, context.tx_submission_mode)
+            .await
+        {
+            Ok(value) => value,
+            Err(error) => {
+                return (
+                    outcome
+                        .with_signature(&swap_signature)
+                        .with_submitted_now()
+                        .finish(SnipeStatus::SubmitFailed, Some(error.to_string())),
+                    Err(error.into()),
+                );
+            }
+        };
+        outcome = outcome.with_signature(&sent_signature).with_submitted_now();
+
+        log::info!(
+            "{} > {} > Swap transaction signature: {} (event_id={})",
+            label,
+            token_address,
+            sent_signature,
+            outcome.event_id
+        );
+
+        let error = match await_confirmation(
+            context.rpc.as_ref(),
+            context.rpc_call_timeout,
+            &sent_signature,
+            context.confirmation_commitment,
+            token_address_text.as_str(),
+            label,
+        )
+        .await
+        {
+            Some(Ok(())) => {
+                return (
+                    outcome.finish(SnipeStatus::Confirmed, None),
+                    Ok(Settled::Confirmed),
+                );
+            }
+            Some(Err(error)) => error,
+            None => {
+                return (
+                    outcome.finish(SnipeStatus::Unconfirmed, None),
+                    Err(SnipeErrorKind::Unconfirmed {
+                        signature: sent_signature,
+                    }),
+                );
+            }
+        };
+
+        let detail = error.to_string();
+        let widened = compiled_instruction_index(&swap_tx, &swap.swap_program)
+            .and_then(|index| {
+                swap.escalation
+                    .next(&error, index, swap.exceeded_slippage_code)
+            })
+            .and_then(|slippage_bps| {
+                swap.instructions
+                    .get_mut(swap.swap_instruction_index)
+                    .map(|instruction| {
+                        (swap.widen_swap)(instruction, slippage_bps)
+                            .map(|min_amount_out| (slippage_bps, min_amount_out))
+                    })
+            });
+        match widened {
+            Some(Ok((slippage_bps, min_amount_out))) => {
+                log::warn!(
+                    "{} > {} > Swap {} exceeded slippage; resending with {} bps slippage, min amount out {}",
+                    label,
+                    token_address,
+                    sent_signature,
+                    slippage_bps,
+                    min_amount_out
+                );
+                outcome.min_amount_out = min_amount_out;
+            }
+            Some(Err(widen_error)) => {
+                return (
+                    outcome.finish(SnipeStatus::Failed, Some(detail)),
+                    Err(widen_error),
+                );
+            }
+            None => {
+                return (
+                    outcome.finish(SnipeStatus::Failed, Some(detail.clone())),
+                    Err(SnipeErrorKind::SwapFailed {
+                        signature: sent_signature,
+                        detail,
+                    }),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+use crate::domain::value_objects::RuleSlippageBps;
+
+/// Raydium CPMM `ExceededSlippage` (`0x1775`).
+pub const CPMM_EXCEEDED_SLIPPAGE: u32 = 6_005;
+/// Raydium AMM v4 `ExceededSlippage` (`0x1e`).
+pub const RAYDIUM_V4_EXCEEDED_SLIPPAGE: u32 = 30;
+
+/// Whether a confirmation error is the swap program rejecting `min_amount_out`: the
+/// program's `error_code` raised by the instruction at `swap_instruction`, so the same code
+/// from another program in the transaction is not mistaken for slippage.
+pub fn is_slippage_failure(
+    error: &TransactionError,
+    swap_instruction: usize,
+    error_code: u32,
+) -> bool {
+    matches!(
+        error,
+        TransactionError::InstructionError(index, InstructionError::Custom(code))
+            if usize::from(*index) == swap_instruction && *code == error_code
+    )
+}
+
+/// Slippage for the next resend: double `current_bps`, capped at `max_bps`. `None` once
+/// the cap is reached.
+pub fn escalated_slippage_bps(current_bps: u16, max_bps: u16) -> Option<u16> {
+    let next = current_bps
+        .saturating_mul(2)
+        .max(current_bps.saturating_add(1))
+        .min(max_bps)
+        .min(RuleSlippageBps::MAX_BPS);
+    (next > current_bps).then_some(next)
+}
+
+/// Per-snipe budget for `runtime.slippage_retry_attempts`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SlippageEscalation {
+    attempts_left: u8,
+    current_bps: u16,
+    max_bps: Option<u16>,
+}
+
+impl SlippageEscalation {
+    pub fn new(
+        attempts: u8,
+        slippage: RuleSlippageBps,
+        max_slippage: Option<RuleSlippageBps>,
+    ) -> Self {
+        Self {
+            attempts_left: attempts,
+            current_bps: slippage.as_bps(),
+            max_bps: max_slippage.map(RuleSlippageBps::as_bps),
+        }
+    }
+
+    /// The slippage to resend with after a swap failed with `error`, spending one attempt.
+    /// `None` when the failure was not slippage, no attempts are left or the rule's cap is
+    /// already reached.
+    pub fn next(
+        &mut self,
+        error: &TransactionError,
+        swap_instruction: usize,
+        error_code: u32,
+    ) -> Option<u16> {
+        if self.attempts_left == 0 || !is_slippage_failure(error, swap_instruction, error_code) {
+            return None;
+        }
+        let next = escalated_slippage_bps(self.current_bps, self.max_bps?)?;
+        self.attempts_left = self.attempts_left.saturating_sub(1);
+        self.current_bps = next;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+    use super::{
+        CPMM_EXCEEDED_SLIPPAGE, RAYDIUM_V4_EXCEEDED_SLIPPAGE, SlippageEscalation,
+        escalated_slippage_bps, is_slippage_failure,
+    };
+    use crate::domain::value_objects::RuleSlippageBps;
+
+    const fn custom_error(instruction: u8, code: u32) -> TransactionError {
+        TransactionError::InstructionError(instruction, InstructionError::Custom(code))
+    }
+
+    #[test]
+    fn doubles_slippage_up_to_the_cap() {
+        assert_eq!(escalated_slippage_bps(100, 500), Some(200));
+        assert_eq!(escalated_slippage_bps(300, 500), Some(500));
+        assert_eq!(escalated_slippage_bps(500, 500), None);
+        assert_eq!(escalated_slippage_bps(0, 50), Some(1));
+        assert_eq!(escalated_slippage_bps(6_000, 10_000), Some(10_000));
+    }
+
+    #[test]
+    fn detects_the_exceeded_slippage_error_of_each_program() {
+        let cpmm = custom_error(3, CPMM_EXCEEDED_SLIPPAGE);
+        assert!(is_slippage_failure(&cpmm, 3, CPMM_EXCEEDED_SLIPPAGE));
+        assert!(!is_slippage_failure(&cpmm, 3, RAYDIUM_V4_EXCEEDED_SLIPPAGE));
+        assert!(is_slippage_failure(
+            &custom_error(2, RAYDIUM_V4_EXCEEDED_SLIPPAGE),
+            2,
+            RAYDIUM_V4_EXCEEDED_SLIPPAGE
+        ));
+        assert!(!is_slippage_failure(
+            &TransactionError::InstructionError(0, InstructionError::InsufficientFunds),
+            0,
+            CPMM_EXCEEDED_SLIPPAGE
+        ));
+        assert!(!is_slippage_failure(
+            &TransactionError::BlockhashNotFound,
+            3,
+            CPMM_EXCEEDED_SLIPPAGE
+        ));
+    }
+
+    #[test]
+    fn ignores_the_slippage_code_from_other_instructions() {
+        // Code 30 from the tip transfer or an ATA instruction is not the swap's slippage check.
+        let other_instruction = custom_error(1, RAYDIUM_V4_EXCEEDED_SLIPPAGE);
+        assert!(!is_slippage_failure(
+            &other_instruction,
+            4,
+            RAYDIUM_V4_EXCEEDED_SLIPPAGE
+        ));
+    }
+
+    #[test]
+    fn stops_after_the_attempt_budget_or_cap() {
+        let slippage = RuleSlippageBps::from_pct_str("1");
+        let max_slippage = RuleSlippageBps::from_pct_str("3");
+        assert!(slippage.is_ok() && max_slippage.is_ok());
+        let (Ok(slippage), Ok(max_slippage)) = (slippage, max_slippage) else {
+            return;
+        };
+        let failure = custom_error(3, CPMM_EXCEEDED_SLIPPAGE);
+
+        let mut escalation = SlippageEscalation::new(5, slippage, Some(max_slippage));
+        assert_eq!(
+            escalation.next(
+                &TransactionError::BlockhashNotFound,
+                3,
+                CPMM_EXCEEDED_SLIPPAGE
+            ),
+            None
+        );
+        assert_eq!(escalation.next(&failure, 2, CPMM_EXCEEDED_SLIPPAGE), None);
+        assert_eq!(
+            escalation.next(&failure, 3, CPMM_EXCEEDED_SLIPPAGE),
+            Some(200)
+        );
+        assert_eq!(
+            escalation.next(&failure, 3, CPMM_EXCEEDED_SLIPPAGE),
+            Some(300)
+        );
+        assert_eq!(escalation.next(&failure, 3, CPMM_EXCEEDED_SLIPPAGE), None);
+
+        let mut single_attempt = SlippageEscalation::new(1, slippage, Some(max_slippage));
+        assert_eq!(
+            single_attempt.next(&failure, 3, CPMM_EXCEEDED_SLIPPAGE),
+            Some(200)
+        );
+        assert_eq!(
+            single_attempt.next(&failure, 3, CPMM_EXCEEDED_SLIPPAGE),
+            None
+        );
+
+        let mut uncapped = SlippageEscalation::new(5, slippage, None);
+        assert_eq!(uncapped.next(&failure, 3, CPMM_EXCEEDED_SLIPPAGE), None);
+    }
+}
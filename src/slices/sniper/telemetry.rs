@@ -1,6 +1,20 @@
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::{
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    time::Instant,
+};
 
-use tokio::time::{Duration, interval};
+use tokio::{
+    task::JoinHandle,
+    time::{Duration, interval},
+};
+
+use crate::domain::events::RaydiumCandidateKind;
+
+pub type HopLatencySnapshot = (&'static str, &'static str, HopLatencyStats);
+
+/// Hardware-vs-receive clock skew is reported like a hop but is not a latency, so the
+/// latency SLO does not apply to it.
+const HW_CLOCK_SKEW_HOP: &str = "hw_clock_skew_ns";
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct HopLatencyStats {
@@ -10,37 +24,105 @@ pub struct HopLatencyStats {
     pub max_ns: u64,
 }
 
+/// Which samples a hop window reports on.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SampleWindowPolicy {
+    /// The last `sample_capacity` samples, regardless of age.
+    #[default]
+    Count,
+    /// The last `sample_capacity` samples recorded within the given age.
+    MaxAge(Duration),
+}
+
+impl SampleWindowPolicy {
+    /// `0` keeps the count-based default.
+    pub const fn from_max_age_ms(max_age_ms: u64) -> Self {
+        if max_age_ms == 0 {
+            Self::Count
+        } else {
+            Self::MaxAge(Duration::from_millis(max_age_ms))
+        }
+    }
+
+    fn max_age_ns(self) -> Option<u64> {
+        match self {
+            Self::Count => None,
+            Self::MaxAge(max_age) => Some(u64::try_from(max_age.as_nanos()).unwrap_or(u64::MAX)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum SloAlert {
+    BreachStarted,
+    StillBreached { periods: u64 },
+    Recovered { periods: u64 },
+}
+
 #[derive(Debug)]
 struct AtomicSampleWindow {
+    strategy: &'static str,
     hop: &'static str,
     capacity: usize,
     write_index: AtomicUsize,
     sample_len: AtomicUsize,
     samples: Box<[AtomicU64]>,
+    epoch: Instant,
+    max_age_ns: Option<u64>,
+    recorded_at_ns: Box<[AtomicU64]>,
+    slo_breach_periods: AtomicU64,
 }
 
 impl AtomicSampleWindow {
-    fn new(hop: &'static str, capacity: usize) -> Self {
+    fn new(
+        strategy: &'static str,
+        hop: &'static str,
+        capacity: usize,
+        policy: SampleWindowPolicy,
+    ) -> Self {
+        let max_age_ns = policy.max_age_ns();
         let mut samples = Vec::with_capacity(capacity);
         for _ in 0..capacity {
             samples.push(AtomicU64::new(0));
         }
+        let timestamp_slots = if max_age_ns.is_some() { capacity } else { 0 };
+        let mut recorded_at_ns = Vec::with_capacity(timestamp_slots);
+        for _ in 0..timestamp_slots {
+            recorded_at_ns.push(AtomicU64::new(0));
+        }
 
         Self {
+            strategy,
             hop,
             capacity,
             write_index: AtomicUsize::new(0),
             sample_len: AtomicUsize::new(0),
             samples: samples.into_boxed_slice(),
+            epoch: Instant::now(),
+            max_age_ns,
+            recorded_at_ns: recorded_at_ns.into_boxed_slice(),
+            slo_breach_periods: AtomicU64::new(0),
         }
     }
 
     fn record(&self, duration_ns: u64) {
+        let recorded_at_ns = if self.max_age_ns.is_some() {
+            self.elapsed_ns()
+        } else {
+            0
+        };
+        self.record_at(duration_ns, recorded_at_ns);
+    }
+
+    fn record_at(&self, duration_ns: u64, recorded_at_ns: u64) {
         let write = self.write_index.fetch_add(1, Ordering::Relaxed);
         let slot = modulo_index(write, self.capacity);
         if let Some(sample) = self.samples.get(slot) {
             sample.store(duration_ns, Ordering::Relaxed);
         }
+        if let Some(timestamp) = self.recorded_at_ns.get(slot) {
+            timestamp.store(recorded_at_ns, Ordering::Relaxed);
+        }
 
         let _update = self
             .sample_len
@@ -53,7 +135,25 @@ impl AtomicSampleWindow {
             });
     }
 
-    fn snapshot_stats(&self) -> Option<(&'static str, HopLatencyStats)> {
+    fn elapsed_ns(&self) -> u64 {
+        u64::try_from(self.epoch.elapsed().as_nanos()).unwrap_or(u64::MAX)
+    }
+
+    /// Samples recorded since startup, including those the ring has overwritten.
+    fn total_recorded(&self) -> usize {
+        self.write_index.load(Ordering::Relaxed)
+    }
+
+    fn snapshot_stats(&self) -> Option<HopLatencySnapshot> {
+        let now_ns = if self.max_age_ns.is_some() {
+            self.elapsed_ns()
+        } else {
+            0
+        };
+        self.snapshot_stats_at(now_ns)
+    }
+
+    fn snapshot_stats_at(&self, now_ns: u64) -> Option<HopLatencySnapshot> {
         let len = self.sample_len.load(Ordering::Acquire).min(self.capacity);
         if len == 0 {
             return None;
@@ -65,6 +165,9 @@ impl AtomicSampleWindow {
         let mut values = Vec::with_capacity(len);
         for offset in 0..len {
             let index = modulo_index(start.saturating_add(offset), self.capacity);
+            if !self.is_within_max_age(index, now_ns) {
+                continue;
+            }
             let value = self
                 .samples
                 .get(index)
@@ -72,119 +175,343 @@ impl AtomicSampleWindow {
             values.push(value);
         }
 
-        Some((self.hop, stats_from_samples(&values)))
+        if values.is_empty() {
+            return None;
+        }
+
+        Some((self.strategy, self.hop, stats_from_samples(&values)))
+    }
+
+    fn is_within_max_age(&self, index: usize, now_ns: u64) -> bool {
+        let Some(max_age_ns) = self.max_age_ns else {
+            return true;
+        };
+        self.recorded_at_ns.get(index).is_some_and(|timestamp| {
+            now_ns.saturating_sub(timestamp.load(Ordering::Relaxed)) <= max_age_ns
+        })
+    }
+
+    fn observe_slo(&self, breached: bool, repeat_periods: u64) -> Option<SloAlert> {
+        let previous = self.slo_breach_periods.load(Ordering::Relaxed);
+        let (next, alert) = slo_transition(previous, breached, repeat_periods);
+        self.slo_breach_periods.store(next, Ordering::Relaxed);
+        alert
     }
 }
 
+/// One sample window per hop for a single strategy.
 #[derive(Debug)]
-pub struct LatencyTelemetry {
-    enabled: bool,
-    slo_threshold_ns: u64,
+struct StrategyWindows {
     ingress_to_engine: AtomicSampleWindow,
     engine_classification: AtomicSampleWindow,
     strategy_dispatch: AtomicSampleWindow,
+    hw_clock_skew: AtomicSampleWindow,
+}
+
+impl StrategyWindows {
+    fn new(strategy: RaydiumCandidateKind, capacity: usize, policy: SampleWindowPolicy) -> Self {
+        let label = strategy.as_str();
+        let window = |hop| AtomicSampleWindow::new(label, hop, capacity, policy);
+        Self {
+            ingress_to_engine: window("ingress_to_engine_ns"),
+            engine_classification: window("engine_classification_ns"),
+            strategy_dispatch: window("strategy_dispatch_ns"),
+            hw_clock_skew: window(HW_CLOCK_SKEW_HOP),
+        }
+    }
+
+    fn window(&self, hop: &str) -> Option<&AtomicSampleWindow> {
+        match hop {
+            "ingress_to_engine_ns" => Some(&self.ingress_to_engine),
+            "engine_classification_ns" => Some(&self.engine_classification),
+            "strategy_dispatch_ns" => Some(&self.strategy_dispatch),
+            HW_CLOCK_SKEW_HOP => Some(&self.hw_clock_skew),
+            _ => None,
+        }
+    }
+
+    const fn windows(&self) -> [&AtomicSampleWindow; 4] {
+        [
+            &self.ingress_to_engine,
+            &self.engine_classification,
+            &self.strategy_dispatch,
+            &self.hw_clock_skew,
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub struct LatencyTelemetry {
+    enabled: bool,
+    slo_threshold_ns: u64,
+    slo_alert_repeat_periods: u64,
+    cpmm: StrategyWindows,
+    openbook: StrategyWindows,
+    hw_clock_clamps: AtomicU64,
+    event_queue_drops: AtomicU64,
+    deadline_exceeded: AtomicU64,
     dropped_unknown_hops: AtomicU64,
+    final_reported: AtomicBool,
 }
 
 impl LatencyTelemetry {
-    pub fn new(sample_capacity: usize, slo_threshold_ns: u64) -> Self {
-        Self::with_mode(true, sample_capacity, slo_threshold_ns)
+    pub fn new(
+        sample_capacity: usize,
+        slo_threshold_ns: u64,
+        sample_policy: SampleWindowPolicy,
+    ) -> Self {
+        Self::with_mode(true, sample_capacity, slo_threshold_ns, sample_policy)
     }
 
     pub fn disabled() -> Self {
-        Self::with_mode(false, 1, 0)
+        Self::with_mode(false, 1, 0, SampleWindowPolicy::Count)
     }
 
-    fn with_mode(enabled: bool, sample_capacity: usize, slo_threshold_ns: u64) -> Self {
+    fn with_mode(
+        enabled: bool,
+        sample_capacity: usize,
+        slo_threshold_ns: u64,
+        sample_policy: SampleWindowPolicy,
+    ) -> Self {
         let capacity = sample_capacity.max(1);
         Self {
             enabled,
             slo_threshold_ns,
-            ingress_to_engine: AtomicSampleWindow::new("ingress_to_engine_ns", capacity),
-            engine_classification: AtomicSampleWindow::new("engine_classification_ns", capacity),
-            strategy_dispatch: AtomicSampleWindow::new("strategy_dispatch_ns", capacity),
+            slo_alert_repeat_periods: 0,
+            cpmm: StrategyWindows::new(RaydiumCandidateKind::Cpmm, capacity, sample_policy),
+            openbook: StrategyWindows::new(RaydiumCandidateKind::OpenBook, capacity, sample_policy),
+            hw_clock_clamps: AtomicU64::new(0),
+            event_queue_drops: AtomicU64::new(0),
+            deadline_exceeded: AtomicU64::new(0),
             dropped_unknown_hops: AtomicU64::new(0),
+            final_reported: AtomicBool::new(false),
         }
     }
 
+    /// Re-emits the in-breach notice every `periods` report periods; `0` only alerts on transitions.
+    pub const fn with_slo_alert_repeat_periods(mut self, periods: u64) -> Self {
+        self.slo_alert_repeat_periods = periods;
+        self
+    }
+
     pub const fn is_enabled(&self) -> bool {
         self.enabled
     }
 
-    pub fn record(&self, hop: &'static str, duration_ns: u64) {
+    pub fn record_for(&self, strategy: RaydiumCandidateKind, hop: &'static str, duration_ns: u64) {
         if !self.enabled {
             return;
         }
 
-        match hop {
-            "ingress_to_engine_ns" => self.ingress_to_engine.record(duration_ns),
-            "engine_classification_ns" => self.engine_classification.record(duration_ns),
-            "strategy_dispatch_ns" => self.strategy_dispatch.record(duration_ns),
-            _ => {
+        match self.window(strategy, hop) {
+            Some(window) => window.record(duration_ns),
+            None => {
                 self.dropped_unknown_hops.fetch_add(1, Ordering::Relaxed);
             }
         }
     }
 
-    pub fn snapshot_all(&self) -> Vec<(&'static str, HopLatencyStats)> {
+    fn window(&self, strategy: RaydiumCandidateKind, hop: &str) -> Option<&AtomicSampleWindow> {
+        self.strategy_windows(strategy).window(hop)
+    }
+
+    fn all_windows(&self) -> impl Iterator<Item = &AtomicSampleWindow> {
+        self.cpmm
+            .windows()
+            .into_iter()
+            .chain(self.openbook.windows())
+    }
+
+    const fn strategy_windows(&self, strategy: RaydiumCandidateKind) -> &StrategyWindows {
+        match strategy {
+            RaydiumCandidateKind::Cpmm => &self.cpmm,
+            RaydiumCandidateKind::OpenBook => &self.openbook,
+        }
+    }
+
+    pub fn record_hw_clock_clamp(&self) {
         if !self.enabled {
-            return Vec::new();
+            return;
         }
 
-        let mut stats = Vec::with_capacity(3);
+        self.hw_clock_clamps.fetch_add(1, Ordering::Relaxed);
+    }
 
-        if let Some(value) = self.ingress_to_engine.snapshot_stats() {
-            stats.push(value);
+    pub fn hw_clock_clamps(&self) -> u64 {
+        self.hw_clock_clamps.load(Ordering::Relaxed)
+    }
+
+    pub fn record_event_queue_drop(&self) {
+        if !self.enabled {
+            return;
         }
-        if let Some(value) = self.engine_classification.snapshot_stats() {
-            stats.push(value);
+
+        self.event_queue_drops.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn event_queue_drops(&self) -> u64 {
+        self.event_queue_drops.load(Ordering::Relaxed)
+    }
+
+    pub fn record_deadline_exceeded(&self) {
+        if !self.enabled {
+            return;
         }
-        if let Some(value) = self.strategy_dispatch.snapshot_stats() {
-            stats.push(value);
+
+        self.deadline_exceeded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn deadline_exceeded(&self) -> u64 {
+        self.deadline_exceeded.load(Ordering::Relaxed)
+    }
+
+    pub fn snapshot_all(&self) -> Vec<HopLatencySnapshot> {
+        if !self.enabled {
+            return Vec::new();
         }
 
-        stats.sort_by(|left, right| left.0.cmp(right.0));
+        let mut stats = self
+            .all_windows()
+            .filter_map(AtomicSampleWindow::snapshot_stats)
+            .collect::<Vec<_>>();
+
+        stats.sort_by(|left, right| (left.0, left.1).cmp(&(right.0, right.1)));
         stats
     }
 
-    pub fn spawn_reporter(self: std::sync::Arc<Self>, period: Duration) {
+    /// `None` when telemetry is disabled and there is nothing to report.
+    #[must_use]
+    pub fn spawn_reporter(self: std::sync::Arc<Self>, period: Duration) -> Option<JoinHandle<()>> {
         if !self.enabled {
-            return;
+            return None;
         }
 
-        tokio::spawn(async move {
+        Some(tokio::spawn(async move {
             let mut ticker = interval(period);
             loop {
                 ticker.tick().await;
                 self.emit_periodic_report();
             }
-        });
+        }))
+    }
+
+    /// Emits one last report plus run totals at shutdown, so runs shorter than a
+    /// report period still log their numbers. Returns `false` when telemetry is
+    /// disabled or the final report was already emitted.
+    pub fn final_report(&self) -> bool {
+        if !self.enabled || self.final_reported.swap(true, Ordering::AcqRel) {
+            return false;
+        }
+
+        self.emit_periodic_report();
+        for window in self.all_windows() {
+            let recorded = window.total_recorded();
+            if recorded == 0 {
+                continue;
+            }
+            log::info!(
+                "Latency telemetry > final > strategy={} hop={} total_samples={}",
+                window.strategy,
+                window.hop,
+                recorded
+            );
+        }
+        log::info!(
+            "Latency telemetry > final > hw_clock_clamps={} event_queue_drops={} deadline_exceeded={}",
+            self.hw_clock_clamps(),
+            self.event_queue_drops(),
+            self.deadline_exceeded()
+        );
+        true
     }
 
     fn emit_periodic_report(&self) {
-        let stats = self.snapshot_all();
-        for (hop, hop_stats) in stats {
+        for window in self.all_windows() {
+            let Some((strategy, hop, hop_stats)) = window.snapshot_stats() else {
+                // No samples in the window means nothing is over the SLO any more.
+                if let Some(SloAlert::Recovered { periods }) =
+                    window.observe_slo(false, self.slo_alert_repeat_periods)
+                {
+                    log::info!(
+                        "Latency SLO alert > recovered > strategy={} hop={} after_periods={} threshold={}ns count=0",
+                        window.strategy,
+                        window.hop,
+                        periods,
+                        self.slo_threshold_ns
+                    );
+                }
+                continue;
+            };
             log::info!(
-                "Latency telemetry > hop={} count={} p50={}ns p99={}ns max={}ns",
+                "Latency telemetry > strategy={} hop={} count={} p50={}ns p99={}ns max={}ns",
+                strategy,
                 hop,
                 hop_stats.sample_count,
                 hop_stats.p50_ns,
                 hop_stats.p99_ns,
                 hop_stats.max_ns
             );
+            if hop == HW_CLOCK_SKEW_HOP {
+                continue;
+            }
 
-            if hop_stats.p99_ns > self.slo_threshold_ns || hop_stats.max_ns > self.slo_threshold_ns
-            {
-                log::warn!(
-                    "Latency SLO alert > hop={} threshold={}ns p99={}ns max={}ns",
+            let breached = hop_stats.p99_ns > self.slo_threshold_ns
+                || hop_stats.max_ns > self.slo_threshold_ns;
+            match window.observe_slo(breached, self.slo_alert_repeat_periods) {
+                Some(SloAlert::BreachStarted) => log::warn!(
+                    "Latency SLO alert > breach started > strategy={} hop={} threshold={}ns p99={}ns max={}ns",
+                    strategy,
+                    hop,
+                    self.slo_threshold_ns,
+                    hop_stats.p99_ns,
+                    hop_stats.max_ns
+                ),
+                Some(SloAlert::StillBreached { periods }) => log::warn!(
+                    "Latency SLO alert > still in breach > strategy={} hop={} periods={} threshold={}ns p99={}ns max={}ns",
+                    strategy,
+                    hop,
+                    periods,
+                    self.slo_threshold_ns,
+                    hop_stats.p99_ns,
+                    hop_stats.max_ns
+                ),
+                Some(SloAlert::Recovered { periods }) => log::info!(
+                    "Latency SLO alert > recovered > strategy={} hop={} after_periods={} threshold={}ns p99={}ns max={}ns",
+                    strategy,
                     hop,
+                    periods,
                     self.slo_threshold_ns,
                     hop_stats.p99_ns,
                     hop_stats.max_ns
-                );
+                ),
+                None => {}
             }
         }
 
+        let hw_clock_clamps = self.hw_clock_clamps();
+        if hw_clock_clamps > 0 {
+            log::warn!(
+                "Latency telemetry > clamped outlier hardware timestamps={}",
+                hw_clock_clamps
+            );
+        }
+
+        let event_queue_drops = self.event_queue_drops();
+        if event_queue_drops > 0 {
+            log::warn!(
+                "Latency telemetry > dropped sniper queue events={}",
+                event_queue_drops
+            );
+        }
+
+        let deadline_exceeded = self.deadline_exceeded();
+        if deadline_exceeded > 0 {
+            log::warn!(
+                "Latency telemetry > snipes abandoned at runtime.snipe_deadline_secs={}",
+                deadline_exceeded
+            );
+        }
+
         let dropped_unknown_hops = self.dropped_unknown_hops.load(Ordering::Relaxed);
         if dropped_unknown_hops > 0 {
             log::warn!(
@@ -195,6 +522,38 @@ impl LatencyTelemetry {
     }
 }
 
+/// Advances the consecutive-breach counter for one report period and picks the alert, if any.
+const fn slo_transition(
+    breach_periods: u64,
+    breached: bool,
+    repeat_periods: u64,
+) -> (u64, Option<SloAlert>) {
+    if !breached {
+        if breach_periods == 0 {
+            return (0, None);
+        }
+        return (
+            0,
+            Some(SloAlert::Recovered {
+                periods: breach_periods,
+            }),
+        );
+    }
+
+    if breach_periods == 0 {
+        return (1, Some(SloAlert::BreachStarted));
+    }
+
+    let periods = breach_periods.saturating_add(1);
+    let repeat_due =
+        repeat_periods > 0 && matches!(breach_periods.checked_rem(repeat_periods), Some(0));
+    if repeat_due {
+        (periods, Some(SloAlert::StillBreached { periods }))
+    } else {
+        (periods, None)
+    }
+}
+
 fn modulo_index(value: usize, modulus: usize) -> usize {
     value.checked_rem(modulus).unwrap_or(0)
 }
@@ -244,20 +603,25 @@ fn percentile_bps(sorted_samples: &[u64], bps: u16) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use super::LatencyTelemetry;
+    use std::{sync::atomic::Ordering, time::Duration};
+
+    use super::{
+        AtomicSampleWindow, LatencyTelemetry, SampleWindowPolicy, SloAlert, slo_transition,
+    };
+    use crate::domain::events::RaydiumCandidateKind;
 
     #[test]
     fn computes_p50_p99_and_max() {
-        let telemetry = LatencyTelemetry::new(64, 1_000_000);
+        let telemetry = LatencyTelemetry::new(64, 1_000_000, SampleWindowPolicy::Count);
         for value in [10_u64, 20, 30, 40, 50, 60, 70, 80, 90, 100] {
-            telemetry.record("ingress_to_engine_ns", value);
+            telemetry.record_for(RaydiumCandidateKind::Cpmm, "ingress_to_engine_ns", value);
         }
 
         let snapshots = telemetry.snapshot_all();
         assert_eq!(snapshots.len(), 1);
         assert!(!snapshots.is_empty());
 
-        if let Some((_, stats)) = snapshots.first().copied() {
+        if let Some((_, _, stats)) = snapshots.first().copied() {
             assert_eq!(stats.sample_count, 10);
             assert_eq!(stats.p50_ns, 50);
             assert_eq!(stats.p99_ns, 90);
@@ -267,16 +631,16 @@ mod tests {
 
     #[test]
     fn keeps_only_recent_samples_per_hop() {
-        let telemetry = LatencyTelemetry::new(3, 1_000_000);
-        telemetry.record("ingress_to_engine_ns", 1);
-        telemetry.record("ingress_to_engine_ns", 2);
-        telemetry.record("ingress_to_engine_ns", 3);
-        telemetry.record("ingress_to_engine_ns", 4);
+        let telemetry = LatencyTelemetry::new(3, 1_000_000, SampleWindowPolicy::Count);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "ingress_to_engine_ns", 1);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "ingress_to_engine_ns", 2);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "ingress_to_engine_ns", 3);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "ingress_to_engine_ns", 4);
 
         let snapshots = telemetry.snapshot_all();
         assert_eq!(snapshots.len(), 1);
         assert!(!snapshots.is_empty());
-        if let Some((_, stats)) = snapshots.first().copied() {
+        if let Some((_, _, stats)) = snapshots.first().copied() {
             assert_eq!(stats.sample_count, 3);
             assert_eq!(stats.max_ns, 4);
         }
@@ -285,11 +649,195 @@ mod tests {
     #[test]
     fn disabled_telemetry_is_noop() {
         let telemetry = LatencyTelemetry::disabled();
-        telemetry.record("ingress_to_engine_ns", 1_000);
-        telemetry.record("engine_classification_ns", 2_000);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "ingress_to_engine_ns", 1_000);
+        telemetry.record_for(
+            RaydiumCandidateKind::Cpmm,
+            "engine_classification_ns",
+            2_000,
+        );
 
         let snapshots = telemetry.snapshot_all();
         assert!(snapshots.is_empty());
         assert!(!telemetry.is_enabled());
     }
+
+    #[test]
+    fn tracks_hw_clock_skew_and_clamps() {
+        let telemetry = LatencyTelemetry::new(8, 1_000_000, SampleWindowPolicy::Count);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "hw_clock_skew_ns", 250_000);
+        telemetry.record_hw_clock_clamp();
+        telemetry.record_hw_clock_clamp();
+
+        let snapshots = telemetry.snapshot_all();
+        assert_eq!(snapshots.len(), 1);
+        if let Some((strategy, hop, stats)) = snapshots.first().copied() {
+            assert_eq!(strategy, "cpmm");
+            assert_eq!(hop, "hw_clock_skew_ns");
+            assert_eq!(stats.max_ns, 250_000);
+        }
+        assert_eq!(telemetry.hw_clock_clamps(), 2);
+
+        let disabled = LatencyTelemetry::disabled();
+        disabled.record_hw_clock_clamp();
+        assert_eq!(disabled.hw_clock_clamps(), 0);
+    }
+
+    #[test]
+    fn final_report_runs_once_and_only_when_enabled() {
+        let telemetry = LatencyTelemetry::new(8, 1_000_000, SampleWindowPolicy::Count);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "strategy_dispatch_ns", 2_000);
+        assert!(telemetry.final_report());
+        assert!(!telemetry.final_report());
+
+        let disabled = LatencyTelemetry::disabled();
+        disabled.record_for(RaydiumCandidateKind::Cpmm, "strategy_dispatch_ns", 2_000);
+        assert!(!disabled.final_report());
+    }
+
+    #[test]
+    fn slo_alerts_only_on_breach_transitions() {
+        assert_eq!(slo_transition(0, false, 0), (0, None));
+        assert_eq!(
+            slo_transition(0, true, 0),
+            (1, Some(SloAlert::BreachStarted))
+        );
+        assert_eq!(slo_transition(1, true, 0), (2, None));
+        assert_eq!(slo_transition(2, true, 0), (3, None));
+        assert_eq!(
+            slo_transition(3, false, 0),
+            (0, Some(SloAlert::Recovered { periods: 3 }))
+        );
+    }
+
+    #[test]
+    fn slo_in_breach_notices_repeat_every_n_periods() {
+        let mut periods = 0;
+        let mut alerts = Vec::new();
+        for _ in 0..7 {
+            let (next, alert) = slo_transition(periods, true, 3);
+            periods = next;
+            alerts.push(alert);
+        }
+
+        assert_eq!(
+            alerts,
+            vec![
+                Some(SloAlert::BreachStarted),
+                None,
+                None,
+                Some(SloAlert::StillBreached { periods: 4 }),
+                None,
+                None,
+                Some(SloAlert::StillBreached { periods: 7 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn tracks_breach_state_per_hop() {
+        let telemetry = LatencyTelemetry::new(8, 1_000, SampleWindowPolicy::Count);
+        let ingress = telemetry.window(RaydiumCandidateKind::Cpmm, "ingress_to_engine_ns");
+        let dispatch = telemetry.window(RaydiumCandidateKind::Cpmm, "strategy_dispatch_ns");
+        assert!(ingress.is_some() && dispatch.is_some());
+        if let (Some(ingress), Some(dispatch)) = (ingress, dispatch) {
+            assert_eq!(ingress.observe_slo(true, 0), Some(SloAlert::BreachStarted));
+            assert_eq!(ingress.observe_slo(true, 0), None);
+            assert_eq!(dispatch.observe_slo(false, 0), None);
+            assert_eq!(
+                ingress.observe_slo(false, 0),
+                Some(SloAlert::Recovered { periods: 2 })
+            );
+        }
+        assert!(
+            telemetry
+                .window(RaydiumCandidateKind::OpenBook, "unknown_ns")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn periodic_report_skips_the_slo_for_clock_skew() {
+        let telemetry = LatencyTelemetry::new(8, 1_000, SampleWindowPolicy::Count);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "hw_clock_skew_ns", 250_000);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "strategy_dispatch_ns", 250_000);
+
+        telemetry.emit_periodic_report();
+
+        let skew = telemetry.window(RaydiumCandidateKind::Cpmm, "hw_clock_skew_ns");
+        let dispatch = telemetry.window(RaydiumCandidateKind::Cpmm, "strategy_dispatch_ns");
+        assert!(skew.is_some() && dispatch.is_some());
+        if let (Some(skew), Some(dispatch)) = (skew, dispatch) {
+            assert_eq!(skew.slo_breach_periods.load(Ordering::Relaxed), 0);
+            assert_eq!(dispatch.slo_breach_periods.load(Ordering::Relaxed), 1);
+        }
+    }
+
+    #[test]
+    fn periodic_report_clears_a_breach_once_the_window_empties() {
+        let telemetry = LatencyTelemetry::new(8, 1_000, SampleWindowPolicy::Count);
+        let ingress = telemetry.window(RaydiumCandidateKind::OpenBook, "ingress_to_engine_ns");
+        assert!(ingress.is_some());
+        if let Some(ingress) = ingress {
+            assert_eq!(ingress.observe_slo(true, 0), Some(SloAlert::BreachStarted));
+
+            telemetry.emit_periodic_report();
+
+            assert_eq!(ingress.slo_breach_periods.load(Ordering::Relaxed), 0);
+        }
+    }
+
+    #[test]
+    fn keeps_separate_windows_per_strategy() {
+        let telemetry = LatencyTelemetry::new(8, 1_000_000, SampleWindowPolicy::Count);
+        telemetry.record_for(RaydiumCandidateKind::Cpmm, "strategy_dispatch_ns", 100);
+        telemetry.record_for(RaydiumCandidateKind::OpenBook, "strategy_dispatch_ns", 900);
+        telemetry.record_for(RaydiumCandidateKind::OpenBook, "strategy_dispatch_ns", 700);
+
+        let snapshots = telemetry
+            .snapshot_all()
+            .into_iter()
+            .map(|(strategy, hop, stats)| (strategy, hop, stats.sample_count, stats.max_ns))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            snapshots,
+            vec![
+                ("cpmm", "strategy_dispatch_ns", 1, 100),
+                ("openbook", "strategy_dispatch_ns", 2, 900),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_age_policy_excludes_stale_samples() {
+        let window = AtomicSampleWindow::new(
+            "cpmm",
+            "strategy_dispatch_ns",
+            8,
+            SampleWindowPolicy::MaxAge(Duration::from_nanos(1_000)),
+        );
+        window.record_at(900, 0);
+        window.record_at(20, 1_500);
+        window.record_at(10, 1_800);
+
+        let fresh = window.snapshot_stats_at(2_000);
+        assert_eq!(
+            fresh.map(|(_, _, stats)| (stats.sample_count, stats.max_ns)),
+            Some((2, 20))
+        );
+        assert!(window.snapshot_stats_at(5_000).is_none());
+
+        let count_window =
+            AtomicSampleWindow::new("cpmm", "strategy_dispatch_ns", 8, SampleWindowPolicy::Count);
+        count_window.record_at(900, 0);
+        assert_eq!(
+            count_window
+                .snapshot_stats_at(5_000)
+                .map(|(_, _, stats)| stats.max_ns),
+            Some(900)
+        );
+        assert_eq!(
+            SampleWindowPolicy::from_max_age_ms(0),
+            SampleWindowPolicy::Count
+        );
+    }
 }
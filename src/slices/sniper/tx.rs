@@ -0,0 +1,636 @@
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
+use sof_solana_compat::TxBuilder;
+use solana_sdk::{
+    hash::Hash, instruction::Instruction, program_error::ProgramError, pubkey::Pubkey,
+    signer::Signer, transaction::VersionedTransaction,
+};
+use solana_system_interface::instruction::transfer;
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token::instruction::{close_account, sync_native};
+
+use crate::{
+    adapters::rpc_retry::with_rpc_timeout,
+    app::context::ExecutionContext,
+    domain::{
+        entities::SnipeRuleHot,
+        settings::MAX_COMPUTE_UNIT_LIMIT,
+        value_objects::{WsolCloseTiming, sol_amount::Lamports},
+    },
+    slices::sniper::error::SnipeErrorKind,
+};
+
+/// Largest serialized transaction a validator accepts: a 1280-byte IPv6 packet less headers.
+pub const MAX_TRANSACTION_SIZE: usize = 1_232;
+
+const BASE_COMPUTE_UNITS: u32 = 60_000;
+const PER_INSTRUCTION_COMPUTE_UNITS: u32 = 10_000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WsolAccount {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub address: Pubkey,
+}
+
+impl WsolAccount {
+    pub fn associated(owner: Pubkey, mint: Pubkey, token_program: Pubkey) -> Self {
+        Self {
+            owner,
+            mint,
+            token_program,
+            address: get_associated_token_address_with_program_id(&owner, &mint, &token_program),
+        }
+    }
+}
+
+pub fn wsol_wrap_instructions(
+    wsol: &WsolAccount,
+    wrap_lamports: u64,
+) -> Result<[Instruction; 3], ProgramError> {
+    Ok([
+        create_associated_token_account_idempotent(
+            &wsol.owner,
+            &wsol.owner,
+            &wsol.mint,
+            &wsol.token_program,
+        ),
+        transfer(&wsol.owner, &wsol.address, wrap_lamports),
+        sync_native(&wsol.token_program, &wsol.address)?,
+    ])
+}
+
+/// Instructions that leave at least `wrap_lamports` of wSOL in the account.
+///
+/// `wrapped_lamports` is the balance already in the account, or `None` when it is
+/// unknown or the account does not exist yet, in which case the full amount is wrapped.
+pub fn wsol_top_up_instructions(
+    wsol: &WsolAccount,
+    wrap_lamports: u64,
+    wrapped_lamports: Option<u64>,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let Some(wrapped_lamports) = wrapped_lamports else {
+        return wsol_wrap_instructions(wsol, wrap_lamports).map(Vec::from);
+    };
+
+    match wsol_wrap_shortfall(wrap_lamports, wrapped_lamports) {
+        0 => Ok(Vec::new()),
+        shortfall => Ok(vec![
+            transfer(&wsol.owner, &wsol.address, shortfall),
+            sync_native(&wsol.token_program, &wsol.address)?,
+        ]),
+    }
+}
+
+#[inline(always)]
+pub const fn wsol_wrap_shortfall(wrap_lamports: u64, wrapped_lamports: u64) -> u64 {
+    wrap_lamports.saturating_sub(wrapped_lamports)
+}
+
+/// Raw wSOL balance of the account; `None` when it does not exist or cannot be read.
+pub async fn wrapped_lamports(context: &ExecutionContext, wsol: &WsolAccount) -> Option<u64> {
+    with_rpc_timeout(
+        context.rpc_call_timeout,
+        context.rpc.get_token_account_balance(&wsol.address),
+    )
+    .await
+    .ok()
+    .and_then(|balance| balance.amount.parse::<u64>().ok())
+}
+
+/// Unwraps the WSOL account inside the swap transaction; `None` unless the close timing is inline.
+pub fn wsol_close_instruction(
+    wsol: &WsolAccount,
+    timing: WsolCloseTiming,
+) -> Result<Option<Instruction>, ProgramError> {
+    match timing {
+        WsolCloseTiming::Inline => close_wsol_account(wsol).map(Some),
+        WsolCloseTiming::AfterConfirmation | WsolCloseTiming::Never => Ok(None),
+    }
+}
+
+/// Close instruction for the follow-up transaction sent once the swap confirms.
+pub fn deferred_wsol_close_instruction(
+    wsol: &WsolAccount,
+    timing: WsolCloseTiming,
+) -> Result<Option<Instruction>, ProgramError> {
+    match timing {
+        WsolCloseTiming::AfterConfirmation => close_wsol_account(wsol).map(Some),
+        WsolCloseTiming::Inline | WsolCloseTiming::Never => Ok(None),
+    }
+}
+
+fn close_wsol_account(wsol: &WsolAccount) -> Result<Instruction, ProgramError> {
+    close_account(
+        &wsol.token_program,
+        &wsol.address,
+        &wsol.owner,
+        &wsol.owner,
+        &[&wsol.owner],
+    )
+}
+
+/// Sends the deferred WSOL close through `runtime.rpc_url`, without a tip.
+///
+/// Best effort: a failed close only leaves the rent and leftover WSOL in the
+/// account until a later close succeeds.
+pub async fn close_wsol_account_after_swap(
+    context: &ExecutionContext,
+    wsol: &WsolAccount,
+    token_address: &str,
+    label: &str,
+) {
+    let close_instruction = match deferred_wsol_close_instruction(wsol, context.wsol_close_timing) {
+        Ok(Some(value)) => value,
+        Ok(None) => return,
+        Err(error) => {
+            log::error!(
+                "{} > {} > Deferred close_account failed: {}",
+                label,
+                token_address,
+                error
+            );
+            return;
+        }
+    };
+
+    let close_tx = match context.latest_swap_blockhash().await.and_then(|blockhash| {
+        build_swap_transaction(
+            context,
+            vec![close_instruction],
+            blockhash,
+            context.priority_fees,
+        )
+    }) {
+        Ok(value) => value,
+        Err(error) => {
+            log::error!(
+                "{} > {} > Deferred WSOL close not built: {}",
+                label,
+                token_address,
+                error
+            );
+            return;
+        }
+    };
+
+    match with_rpc_timeout(
+        context.rpc_call_timeout,
+        context.rpc.send_transaction(&close_tx),
+    )
+    .await
+    {
+        Ok(signature) => log::info!(
+            "{} > {} > WSOL close transaction signature: {}",
+            label,
+            token_address,
+            signature
+        ),
+        Err(error) => log::warn!(
+            "{} > {} > Failed to send deferred WSOL close: {}",
+            label,
+            token_address,
+            error
+        ),
+    }
+}
+
+/// SOL input for a matched rule: its snipe height, or the lamports that buy its
+/// `target_token_amount` at the pool's spot price, which the snipe height then caps.
+pub fn swap_input_lamports(
+    rule: SnipeRuleHot,
+    sol_reserve: u64,
+    token_reserve: u64,
+) -> Result<u64, SnipeErrorKind> {
+    let cap = rule.snipe_height().as_lamports();
+    rule.target_token_amount().map_or_else(
+        || Ok(cap.as_u64()),
+        |tokens| {
+            let lamports = lamports_for_token_amount(tokens, sol_reserve, token_reserve)
+                .ok_or(SnipeErrorKind::TargetTokenAmount { tokens })?;
+            if lamports > cap.as_u64() {
+                return Err(SnipeErrorKind::TargetTokenAmountOverCap {
+                    tokens,
+                    cost: Lamports::new(lamports),
+                    cap,
+                });
+            }
+            Ok(lamports)
+        },
+    )
+}
+
+/// Inverse of the handlers' min-amount-out price math, rounded up so the spot-price output
+/// covers `tokens`. `None` when a reserve is empty, the pool holds fewer tokens, or the
+/// result does not fit a `u64`.
+pub fn lamports_for_token_amount(tokens: u64, sol_reserve: u64, token_reserve: u64) -> Option<u64> {
+    if sol_reserve == 0 || tokens >= token_reserve {
+        return None;
+    }
+
+    let lamports = u128::from(tokens)
+        .checked_mul(u128::from(sol_reserve))?
+        .div_ceil(u128::from(token_reserve));
+    u64::try_from(lamports)
+        .ok()
+        .filter(|lamports| *lamports > 0)
+}
+
+/// Rejects a zero `min_amount_out` unless unprotected swaps are explicitly allowed.
+#[inline(always)]
+pub const fn ensure_min_amount_out(
+    min_amount_out: u64,
+    allow_zero: bool,
+) -> Result<u64, SnipeErrorKind> {
+    if min_amount_out == 0 && !allow_zero {
+        return Err(SnipeErrorKind::ZeroMinAmountOut);
+    }
+
+    Ok(min_amount_out)
+}
+
+/// Backstop behind every fee adjustment: the signed compute unit price never exceeds
+/// `ceiling` (`runtime.max_compute_unit_price`), whatever produced `micro_lamports`.
+pub fn capped_compute_unit_price(micro_lamports: u64, ceiling: u64) -> u64 {
+    if micro_lamports <= ceiling {
+        return micro_lamports;
+    }
+
+    log::warn!(
+        "Compute unit price {} micro-lamports exceeds runtime.max_compute_unit_price; clamping to {}",
+        micro_lamports,
+        ceiling
+    );
+    ceiling
+}
+
+/// Compute budget for a swap transaction carrying `instruction_count` non-budget instructions.
+///
+/// Scales with the WSOL wrap/close, ATA and tip instructions so optional extras
+/// neither starve the swap nor pay priority fees on unused units.
+#[inline(always)]
+pub fn compute_unit_limit_for(instruction_count: usize) -> u32 {
+    let instruction_count = u32::try_from(instruction_count).unwrap_or(u32::MAX);
+    PER_INSTRUCTION_COMPUTE_UNITS
+        .saturating_mul(instruction_count)
+        .saturating_add(BASE_COMPUTE_UNITS)
+        .min(MAX_COMPUTE_UNIT_LIMIT)
+}
+
+pub fn build_swap_transaction(
+    context: &ExecutionContext,
+    instructions: Vec<Instruction>,
+    blockhash: Hash,
+    priority_fees: u64,
+) -> Result<VersionedTransaction, String> {
+    let compute_unit_limit = context
+        .compute_unit_limit
+        .unwrap_or_else(|| compute_unit_limit_for(instructions.len()));
+    let signer_refs: [&dyn Signer; 1] = [context.keypair.as_ref()];
+    TxBuilder::new(context.keypair.pubkey())
+        .with_compute_unit_limit(compute_unit_limit)
+        .with_priority_fee_micro_lamports(capped_compute_unit_price(
+            priority_fees,
+            context.max_compute_unit_price,
+        ))
+        .add_instructions(instructions)
+        .build_and_sign(blockhash.to_bytes(), &signer_refs)
+        .map_err(|error| format!("failed to build/sign swap transaction: {error}"))
+}
+
+/// Position of the first instruction invoking `program_id` in the signed transaction. This is
+/// the index confirmation errors report, counting the compute budget instructions the builder
+/// prepends.
+pub fn compiled_instruction_index(
+    transaction: &VersionedTransaction,
+    program_id: &Pubkey,
+) -> Option<usize> {
+    let account_keys = transaction.message.static_account_keys();
+    transaction
+        .message
+        .instructions()
+        .iter()
+        .position(|instruction| {
+            account_keys.get(usize::from(instruction.program_id_index)) == Some(program_id)
+        })
+}
+
+/// Serialized size of `transaction`, checked before send so an oversized account list fails
+/// with its size instead of an opaque RPC rejection.
+pub fn ensure_transaction_fits(
+    transaction: &VersionedTransaction,
+) -> Result<usize, SnipeErrorKind> {
+    let size = bincode::serialized_size(transaction)
+        .map_err(|error| SnipeErrorKind::BuildTransaction {
+            detail: format!("failed to serialize swap transaction: {error}"),
+        })
+        .map(|size| usize::try_from(size).unwrap_or(usize::MAX))?;
+    if size > MAX_TRANSACTION_SIZE {
+        return Err(SnipeErrorKind::TransactionTooLarge {
+            size,
+            limit: MAX_TRANSACTION_SIZE,
+        });
+    }
+
+    Ok(size)
+}
+
+/// Debug-only dump of the signed wire transaction (base64) and its message (JSON) for offline decoding.
+pub fn log_transaction_dump(label: &str, token_address: &str, transaction: &VersionedTransaction) {
+    if !log::log_enabled!(log::Level::Debug) {
+        return;
+    }
+
+    match encode_transaction_base64(transaction) {
+        Ok(encoded) => log::debug!(
+            "{} > {} > Signed transaction (base64): {}",
+            label,
+            token_address,
+            encoded
+        ),
+        Err(error) => log::debug!(
+            "{} > {} > Failed to serialize transaction: {}",
+            label,
+            token_address,
+            error
+        ),
+    }
+    match serde_json::to_string(&transaction.message) {
+        Ok(message) => log::debug!(
+            "{} > {} > Transaction message: {}",
+            label,
+            token_address,
+            message
+        ),
+        Err(error) => log::debug!(
+            "{} > {} > Failed to serialize message: {}",
+            label,
+            token_address,
+            error
+        ),
+    }
+}
+
+fn encode_transaction_base64(transaction: &VersionedTransaction) -> Result<String, bincode::Error> {
+    bincode::serialize(transaction).map(|bytes| BASE64_STANDARD.encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
+    use solana_client::nonblocking::rpc_client::RpcClient;
+    use solana_compute_budget_interface::ComputeBudgetInstruction;
+    use solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        message::Message,
+        pubkey::Pubkey,
+        signature::{Keypair, Signature},
+        transaction::{Transaction, VersionedTransaction},
+    };
+
+    use super::{
+        MAX_TRANSACTION_SIZE, WsolAccount, build_swap_transaction, capped_compute_unit_price,
+        compiled_instruction_index, compute_unit_limit_for, deferred_wsol_close_instruction,
+        encode_transaction_base64, ensure_min_amount_out, ensure_transaction_fits,
+        lamports_for_token_amount, swap_input_lamports, wsol_close_instruction,
+        wsol_top_up_instructions, wsol_wrap_instructions, wsol_wrap_shortfall,
+    };
+    use crate::{
+        adapters::tx_submitters::FakeSubmitter,
+        app::context::ExecutionContext,
+        domain::{
+            entities::SnipeRuleHot,
+            settings::MAX_COMPUTE_UNIT_LIMIT,
+            value_objects::{
+                RuleFeeMultiplier, RuleSlippageBps, RuleSolAmount, WsolCloseTiming,
+                sol_amount::Lamports,
+            },
+        },
+        slices::sniper::error::SnipeErrorKind,
+    };
+
+    #[test]
+    fn derives_sol_input_from_a_target_token_amount() {
+        // 1M tokens from a 10 SOL / 1B token pool costs 0.01 SOL at spot.
+        assert_eq!(
+            lamports_for_token_amount(1_000_000, 10_000_000_000, 1_000_000_000),
+            Some(10_000_000)
+        );
+        // Rounds up so the spot output never falls short of the target.
+        assert_eq!(lamports_for_token_amount(1, 1, 3), Some(1));
+        assert_eq!(lamports_for_token_amount(3, 5, 3), None);
+        assert_eq!(lamports_for_token_amount(1, 0, 1_000), None);
+        assert_eq!(
+            lamports_for_token_amount(u64::MAX - 1, u64::MAX, u64::MAX),
+            Some(u64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn caps_a_target_token_amount_at_the_snipe_height() {
+        let slippage = RuleSlippageBps::from_pct_str("1");
+        assert!(slippage.is_ok());
+        let Ok(slippage) = slippage else {
+            return;
+        };
+        let rule = SnipeRuleHot::new(
+            RuleSolAmount::new(Lamports::new(10_000_000)),
+            RuleSolAmount::new(Lamports::new(0)),
+            slippage,
+        );
+
+        assert_eq!(
+            swap_input_lamports(rule, 10_000_000_000, 1_000_000_000).ok(),
+            Some(10_000_000)
+        );
+        let tokens = rule.with_target_token_amount(Some(1_000_000));
+        assert_eq!(
+            swap_input_lamports(tokens, 10_000_000_000, 1_000_000_000).ok(),
+            Some(10_000_000)
+        );
+        assert!(matches!(
+            swap_input_lamports(tokens, 20_000_000_000, 1_000_000_000),
+            Err(SnipeErrorKind::TargetTokenAmountOverCap {
+                tokens: 1_000_000,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn places_wsol_close_according_to_timing() {
+        let wsol =
+            WsolAccount::associated(Pubkey::new_unique(), Pubkey::new_unique(), spl_token::id());
+
+        for (timing, inline, deferred) in [
+            (WsolCloseTiming::Inline, true, false),
+            (WsolCloseTiming::AfterConfirmation, false, true),
+            (WsolCloseTiming::Never, false, false),
+        ] {
+            assert!(matches!(
+                wsol_close_instruction(&wsol, timing),
+                Ok(close) if close.is_some() == inline
+            ));
+            assert!(matches!(
+                deferred_wsol_close_instruction(&wsol, timing),
+                Ok(close) if close.is_some() == deferred
+            ));
+        }
+        assert!(wsol_wrap_instructions(&wsol, 1_000).is_ok());
+    }
+
+    #[test]
+    fn wraps_only_the_wsol_shortfall() {
+        assert_eq!(wsol_wrap_shortfall(1_000, 0), 1_000);
+        assert_eq!(wsol_wrap_shortfall(1_000, 400), 600);
+        assert_eq!(wsol_wrap_shortfall(1_000, 1_000), 0);
+        assert_eq!(wsol_wrap_shortfall(1_000, 5_000), 0);
+
+        let wsol =
+            WsolAccount::associated(Pubkey::new_unique(), Pubkey::new_unique(), spl_token::id());
+        let instruction_count = |wrapped| {
+            wsol_top_up_instructions(&wsol, 1_000, wrapped).map(|instructions| instructions.len())
+        };
+        assert_eq!(instruction_count(None).ok(), Some(3));
+        assert_eq!(instruction_count(Some(400)).ok(), Some(2));
+        assert_eq!(instruction_count(Some(1_000)).ok(), Some(0));
+    }
+
+    #[test]
+    fn zero_min_amount_out_aborts_unless_allowed() {
+        assert!(matches!(
+            ensure_min_amount_out(0, false),
+            Err(SnipeErrorKind::ZeroMinAmountOut)
+        ));
+        assert!(matches!(ensure_min_amount_out(0, true), Ok(0)));
+        assert!(matches!(ensure_min_amount_out(42, false), Ok(42)));
+    }
+
+    #[test]
+    fn clamps_compute_unit_prices_above_the_ceiling() {
+        assert_eq!(capped_compute_unit_price(5_000, 2_500), 2_500);
+        assert_eq!(capped_compute_unit_price(2_500, 2_500), 2_500);
+        assert_eq!(capped_compute_unit_price(1_000, 2_500), 1_000);
+    }
+
+    #[test]
+    fn signs_a_multiplied_rule_price_at_the_ceiling() {
+        let mut context = ExecutionContext::new(
+            Arc::new(RpcClient::new("http://127.0.0.1:1".to_owned())),
+            Arc::new(Keypair::new()),
+            Arc::new(FakeSubmitter::default()),
+        );
+        context.priority_fees = 1_000;
+        context.max_compute_unit_price = 2_500;
+        let multiplier = RuleFeeMultiplier::from_decimal_str("3.5");
+        assert!(multiplier.is_ok());
+        let Ok(multiplier) = multiplier else {
+            return;
+        };
+
+        let transaction = build_swap_transaction(
+            &context,
+            Vec::new(),
+            Hash::new_unique(),
+            context.rule_priority_fees(multiplier),
+        );
+
+        assert!(transaction.is_ok());
+        if let Ok(transaction) = transaction {
+            let signed_data = |price| {
+                let data = ComputeBudgetInstruction::set_compute_unit_price(price).data;
+                transaction
+                    .message
+                    .instructions()
+                    .iter()
+                    .any(|instruction| instruction.data == data)
+            };
+            assert!(signed_data(2_500));
+            assert!(!signed_data(3_500));
+        }
+    }
+
+    #[test]
+    fn base64_dump_round_trips_with_signatures() {
+        let payer = Pubkey::new_unique();
+        let mut transaction =
+            VersionedTransaction::from(Transaction::new_unsigned(Message::new(&[], Some(&payer))));
+        transaction.signatures = vec![Signature::from([3_u8; 64])];
+
+        let decoded = encode_transaction_base64(&transaction)
+            .ok()
+            .and_then(|encoded| BASE64_STANDARD.decode(encoded).ok())
+            .and_then(|bytes| bincode::deserialize::<VersionedTransaction>(&bytes).ok());
+
+        assert_eq!(decoded, Some(transaction));
+    }
+
+    #[test]
+    fn compute_unit_limit_scales_with_instruction_count() {
+        assert_eq!(compute_unit_limit_for(6), 120_000);
+        assert_eq!(compute_unit_limit_for(7), 130_000);
+        assert_eq!(compute_unit_limit_for(8), 140_000);
+        assert_eq!(compute_unit_limit_for(usize::MAX), MAX_COMPUTE_UNIT_LIMIT);
+    }
+
+    #[test]
+    fn indexes_the_swap_after_the_compute_budget_instructions() {
+        let payer = Pubkey::new_unique();
+        let swap_program = Pubkey::new_unique();
+        let instructions = [
+            Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]),
+            Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]),
+            Instruction::new_with_bytes(swap_program, &[], vec![]),
+        ];
+        let transaction = VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+            &instructions,
+            Some(&payer),
+        )));
+
+        assert_eq!(
+            compiled_instruction_index(&transaction, &swap_program),
+            Some(2)
+        );
+        assert_eq!(
+            compiled_instruction_index(&transaction, &Pubkey::new_unique()),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_transactions_over_the_packet_limit() {
+        let payer = Pubkey::new_unique();
+        let transaction_with_accounts = |accounts: usize| {
+            let instruction = Instruction::new_with_bytes(
+                Pubkey::new_unique(),
+                &[],
+                (0..accounts)
+                    .map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false))
+                    .collect(),
+            );
+            let mut transaction = VersionedTransaction::from(Transaction::new_unsigned(
+                Message::new(&[instruction], Some(&payer)),
+            ));
+            transaction.signatures = vec![Signature::from([3_u8; 64])];
+            transaction
+        };
+
+        let fits = ensure_transaction_fits(&transaction_with_accounts(20));
+        assert!(matches!(fits, Ok(size) if size <= MAX_TRANSACTION_SIZE));
+
+        // 40 extra keys at 32 bytes each cannot fit in one packet.
+        assert!(matches!(
+            ensure_transaction_fits(&transaction_with_accounts(40)),
+            Err(SnipeErrorKind::TransactionTooLarge {
+                size,
+                limit: MAX_TRANSACTION_SIZE,
+            }) if size > MAX_TRANSACTION_SIZE
+        ));
+    }
+}
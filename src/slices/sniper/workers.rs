@@ -0,0 +1,132 @@
+use std::{future::Future, sync::Arc};
+
+use tokio::{
+    runtime::Handle,
+    sync::{Mutex, mpsc},
+    task::JoinSet,
+};
+
+/// Fixed set of worker tasks draining a bounded queue.
+///
+/// At most `workers` items run at once; `submit` waits once `queue_capacity` items
+/// are queued behind busy workers, so concurrency and backlog both stay bounded.
+pub struct WorkerPool<T> {
+    sender: mpsc::Sender<T>,
+    workers: JoinSet<()>,
+}
+
+impl<T> WorkerPool<T>
+where
+    T: Send + 'static,
+{
+    pub fn spawn<F, Fut>(
+        workers: usize,
+        queue_capacity: usize,
+        runtime: Option<&Handle>,
+        work: F,
+    ) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel(queue_capacity.max(1));
+        let receiver = Arc::new(Mutex::new(receiver));
+        let work = Arc::new(work);
+        let mut join_set = JoinSet::new();
+
+        for _ in 0..workers.max(1) {
+            let receiver = Arc::clone(&receiver);
+            let work = Arc::clone(&work);
+            let task = async move {
+                loop {
+                    let next = receiver.lock().await.recv().await;
+                    let Some(item) = next else {
+                        return;
+                    };
+                    work(item).await;
+                }
+            };
+            match runtime {
+                Some(handle) => {
+                    join_set.spawn_on(task, handle);
+                }
+                None => {
+                    join_set.spawn(task);
+                }
+            }
+        }
+
+        Self {
+            sender,
+            workers: join_set,
+        }
+    }
+
+    /// Queues `item`, waiting for room; returns `false` once every worker has exited.
+    pub async fn submit(&self, item: T) -> bool {
+        self.sender.send(item).await.is_ok()
+    }
+
+    /// Stops accepting work and waits for queued items to finish.
+    pub async fn shutdown(self) {
+        let Self {
+            sender,
+            mut workers,
+        } = self;
+        drop(sender);
+        while workers.join_next().await.is_some() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::{mpsc, oneshot};
+
+    use super::WorkerPool;
+
+    #[tokio::test]
+    async fn runs_at_most_n_items_concurrently() {
+        let (started_tx, mut started_rx) = mpsc::unbounded_channel();
+        let pool = WorkerPool::spawn(
+            2,
+            1,
+            None,
+            move |(id, gate): (u8, oneshot::Receiver<()>)| {
+                let started_tx = started_tx.clone();
+                async move {
+                    let _sent = started_tx.send(id);
+                    let _released = gate.await;
+                }
+            },
+        );
+
+        let mut gates = Vec::new();
+        for id in 0..3_u8 {
+            let (release, gate) = oneshot::channel();
+            gates.push(release);
+            assert!(pool.submit((id, gate)).await);
+        }
+
+        let mut started = vec![started_rx.recv().await, started_rx.recv().await];
+        started.sort();
+        assert_eq!(started, vec![Some(0), Some(1)]);
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), started_rx.recv())
+                .await
+                .is_err()
+        );
+
+        let mut gates = gates.into_iter();
+        if let Some(release) = gates.next() {
+            let _released = release.send(());
+        }
+        assert_eq!(started_rx.recv().await, Some(2));
+
+        for release in gates {
+            let _released = release.send(());
+        }
+        pool.shutdown().await;
+    }
+}
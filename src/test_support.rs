@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+/// A fresh file path in the system temp directory; the test creates and removes the file.
+pub fn temp_path(prefix: &str, extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "{}_{}.{}",
+        prefix,
+        crate::domain::events::unix_timestamp_now_ns(),
+        extension
+    ))
+}
@@ -2,14 +2,13 @@ use std::{error::Error, str::FromStr, sync::Arc, time::Duration};
 
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
 use slotstrike::{
+    adapters::tx_submitters::FakeSubmitter,
     app::context::ExecutionContext,
     domain::{
         aggregates::RuleBook,
         entities::SnipeRule,
         events::{IngressMetadata, IngressSource},
-        value_objects::{
-            RuleAddress, RuleSlippageBps, RuleSolAmount, TxSubmissionMode, sol_amount::Lamports,
-        },
+        value_objects::{RuleAddress, RuleSlippageBps, RuleSolAmount, sol_amount::Lamports},
     },
     slices::sniper::{cpmm, openbook},
 };
@@ -30,7 +29,7 @@ async fn live_raydium_replay_builds_swap_without_submission() -> Result<(), Box<
     let mint = std::env::var("SLOTSTRIKE_LIVE_MINT")?;
 
     let signature = Signature::from_str(&signature)?;
-    let rpc = Arc::new(RpcClient::new(rpc_url.clone()));
+    let rpc = Arc::new(RpcClient::new(rpc_url));
     let creation_tx = rpc
         .get_transaction_with_config(
             &signature,
@@ -46,19 +45,12 @@ async fn live_raydium_replay_builds_swap_without_submission() -> Result<(), Box<
             .ok_or_else(|| std::io::Error::other("failed to decode historical transaction"))?,
     );
 
-    let context = Arc::new(ExecutionContext {
-        priority_fees: 1,
-        rpc,
-        keypair: Arc::new(Keypair::new()),
-        dry_run: true,
-        tx_submission_mode: TxSubmissionMode::Direct,
-        jito_url: Arc::new(rpc_url),
-        sof_tx_client: None,
-        sof_tx_plan: None,
-        sof_tx_uses_jito: false,
-        sof_tx_blockhash_adapter: None,
-        require_local_blockhash: false,
-    });
+    let context = Arc::new(
+        ExecutionContext::builder(rpc, Arc::new(Keypair::new()))
+            .with_priority_fees(1, 100)
+            .with_rpc_call_timeout(Some(Duration::from_secs(10)))
+            .build(|_context| Arc::new(FakeSubmitter::default()))?,
+    );
     let rulebook = Arc::new(RuleBook::new(vec![build_mint_rule(&mint)?], Vec::new()));
     let ingress = IngressMetadata::from_receive_clock(
         IngressSource::Grpc,
@@ -69,7 +61,7 @@ async fn live_raydium_replay_builds_swap_without_submission() -> Result<(), Box<
         match candidate_kind.as_str() {
             "cpmm" => {
                 cpmm::handle_cpmm_candidate_structured(context, rulebook, transaction, ingress)
-                    .await
+                    .await?;
             }
             "openbook" => {
                 openbook::handle_openbook_candidate_structured(
@@ -78,7 +70,7 @@ async fn live_raydium_replay_builds_swap_without_submission() -> Result<(), Box<
                     transaction,
                     ingress,
                 )
-                .await
+                .await?;
             }
             value => {
                 return Err(std::io::Error::other(format!(
@@ -0,0 +1,62 @@
+use std::{sync::Arc, time::Duration};
+
+use slotstrike::{
+    adapters::scripted_ingress::ScriptedIngress,
+    domain::{
+        events::{
+            IngressMetadata, IngressSource, RaydiumCandidateEvent, RaydiumCandidateKind,
+            SniperInputEvent,
+        },
+        value_objects::EventQueueDropPolicy,
+    },
+    slices::sniper::{
+        queue::{QueueCloseReason, QueuePushOutcome, SniperEventQueue},
+        telemetry::LatencyTelemetry,
+    },
+};
+use solana_sdk::{
+    message::Message,
+    pubkey::Pubkey,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+fn candidate_event(received_timestamp_ns: u64) -> SniperInputEvent {
+    let payer = Pubkey::new_unique();
+    let transaction =
+        VersionedTransaction::from(Transaction::new_unsigned(Message::new(&[], Some(&payer))));
+
+    SniperInputEvent::RaydiumCandidate(RaydiumCandidateEvent {
+        kind: RaydiumCandidateKind::OpenBook,
+        transaction: Arc::new(transaction),
+        ingress: IngressMetadata::from_receive_clock(
+            IngressSource::Websocket,
+            received_timestamp_ns,
+        ),
+    })
+}
+
+#[tokio::test]
+async fn scripted_ingress_reports_backpressure_drops() {
+    let queue = Arc::new(SniperEventQueue::new(
+        1,
+        EventQueueDropPolicy::DropNewest,
+        Arc::new(LatencyTelemetry::disabled()),
+    ));
+
+    let replay = ScriptedIngress::new(vec![candidate_event(1), candidate_event(2)])
+        .with_interval(Duration::from_millis(1))
+        .spawn(Arc::clone(&queue))
+        .await;
+
+    assert!(replay.is_ok());
+    if let Ok(outcomes) = replay {
+        assert_eq!(
+            outcomes,
+            vec![QueuePushOutcome::Enqueued, QueuePushOutcome::DroppedNewest]
+        );
+    }
+    assert_eq!(queue.dropped_events(), 1);
+    assert!(queue.recv().await.is_some());
+    assert!(queue.recv().await.is_none());
+    assert_eq!(queue.close_reason(), Some(QueueCloseReason::Shutdown));
+}
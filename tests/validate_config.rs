@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use slotstrike::app::config_validation::validate_config;
+
+#[test]
+fn known_good_config_validates() {
+    let keypair_path = temp_path("validate_config_keypair", "json");
+    assert!(std::fs::write(&keypair_path, "[]").is_ok());
+    let config_path = temp_path("validate_config_good", "toml");
+    let write_result = std::fs::write(
+        &config_path,
+        format!(
+            r#"
+[runtime]
+keypair_path = "{}"
+rpc_url = "https://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[[rules]]
+kind = "mint"
+address = "So11111111111111111111111111111111111111112"
+snipe_height_sol = "0.01"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+"#,
+            keypair_path.display()
+        ),
+    );
+    assert!(write_result.is_ok());
+
+    let report = validate_config(&config_args(&config_path));
+    assert_eq!(report.problems, Vec::<String>::new());
+    assert!(report.is_valid());
+    assert_eq!(report.mint_rules, 1);
+
+    assert!(std::fs::remove_file(&config_path).is_ok());
+    assert!(std::fs::remove_file(&keypair_path).is_ok());
+}
+
+#[test]
+fn known_bad_config_reports_problems() {
+    let config_path = temp_path("validate_config_bad", "toml");
+    let write_result = std::fs::write(
+        &config_path,
+        r#"
+[runtime]
+keypair_path = "/nonexistent/slotstrike/keypair.json"
+rpc_url = "wss://rpc.example"
+wss_url = "wss://wss.example"
+priority_fees = 1000
+tx_submission_mode = "direct"
+
+[[rules]]
+kind = "mint"
+address = "not-a-pubkey"
+snipe_height_sol = "0.01"
+tip_budget_sol = "0.001"
+slippage_pct = "1"
+"#,
+    );
+    assert!(write_result.is_ok());
+
+    let report = validate_config(&config_args(&config_path));
+    assert!(!report.is_valid());
+    assert!(
+        report
+            .problems
+            .iter()
+            .any(|problem| problem.contains("runtime.keypair_path"))
+    );
+    assert!(
+        report
+            .problems
+            .iter()
+            .any(|problem| problem.contains("runtime.rpc_url"))
+    );
+    assert!(
+        report
+            .problems
+            .iter()
+            .any(|problem| problem.contains("Invalid address"))
+    );
+
+    assert!(std::fs::remove_file(&config_path).is_ok());
+}
+
+fn config_args(config_path: &std::path::Path) -> Vec<String> {
+    vec![
+        "--validate-config".to_owned(),
+        "--config".to_owned(),
+        config_path.to_string_lossy().into_owned(),
+    ]
+}
+
+fn temp_path(prefix: &str, extension: &str) -> PathBuf {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    std::env::temp_dir().join(format!("{}_{}.{}", prefix, nanos, extension))
+}